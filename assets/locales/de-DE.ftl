@@ -0,0 +1,249 @@
+## Players
+
+player-1 = Spieler 1
+player-2 = Spieler 2
+
+## Pause menu
+
+menu-paused = Pausiert
+menu-press-key = Taste drücken (Esc bricht ab)
+replay-banner = Wiederholung
+setting-on = An
+setting-off = Aus
+setting-percent = { $value } %
+setting-auto = Automatisch
+
+setting-export-matches = Spiele exportieren
+setting-palette = Farbpalette
+setting-colour-p1 = Schlägerfarbe (S1)
+setting-colour-p2 = Schlägerfarbe (S2)
+palette-classic = Klassisch
+palette-red-green-safe = Rot/Grün-freundlich
+palette-blue-yellow-safe = Blau/Gelb-freundlich
+colour-palette = Aus der Palette
+colour-red = Rot
+colour-orange = Orange
+colour-yellow = Gelb
+colour-green = Grün
+colour-cyan = Cyan
+colour-blue = Blau
+colour-purple = Lila
+colour-pink = Rosa
+colour-white = Weiß
+chroma-key-off = Aus
+chroma-key-green = Grün
+chroma-key-magenta = Magenta
+setting-paddle-patterns = Schlägermuster
+setting-high-contrast = Hoher Kontrast
+setting-chroma-key = Chroma-Key-Hintergrund
+setting-large-ball = Großer Ball
+setting-slow-ball = Langsamer Ball
+setting-large-paddle-p1 = Großer Schläger (S1)
+setting-large-paddle-p2 = Großer Schläger (S2)
+setting-trajectory-line = Flugbahn (Training)
+setting-magnet = Magnethilfe
+magnet-gentle = Sanft
+magnet-medium = Mittel
+magnet-strong = Stark
+magnet-flag = Magnethilfe an: { $strength }
+magnet-ranked = Magnethilfe aus, solange Ergebnisse geteilt werden
+setting-barriers-1p = Barrieren (1S)
+setting-barriers-2p = Barrieren (2S)
+setting-barriers-survival = Barrieren (Überleben)
+setting-stamina = Ausdauer
+setting-reduced-motion = Weniger Bewegung
+setting-visual-cues = Visuelle Tonhinweise
+setting-game-speed = Spieltempo
+setting-save-slow-motion = Zeitlupe bei Rettungen
+slow-motion-light = Leicht
+slow-motion-medium = Mittel
+slow-motion-heavy = Stark
+setting-controls = Steuerung
+setting-player1-up = Spieler 1 hoch
+setting-player1-down = Spieler 1 runter
+setting-player1-smash = Spieler 1 Schmetterball
+setting-player1-tilt-up = Spieler 1 nach oben neigen
+setting-player1-tilt-down = Spieler 1 nach unten neigen
+setting-player1-barrier = Spieler 1 Barriere
+setting-player2-up = Spieler 2 hoch
+setting-player2-down = Spieler 2 runter
+setting-player2-smash = Spieler 2 Schmetterball
+setting-player2-tilt-up = Spieler 2 nach oben neigen
+setting-player2-tilt-down = Spieler 2 nach unten neigen
+setting-player2-barrier = Spieler 2 Barriere
+setting-wheel-paddle = Mausrad bewegt
+setting-wheel-notch = Mausrad-Schritt
+setting-restart-key = Match neu starten (halten)
+setting-language = Sprache
+setting-window-mode = Fenstermodus
+setting-vsync = VSync
+setting-fps-cap = Bildratenbegrenzung
+setting-ui-scale = UI-Skalierung
+setting-ai-difficulty = KI-Schwierigkeit
+setting-goal-replay = Tor-Wiederholungen
+setting-speed-meter = Ballgeschwindigkeit
+setting-minimal-hud = Minimale Anzeige
+setting-serve-style = Aufschläge
+setting-keep-playing-unfocused = Ohne Fokus weiterspielen
+setting-handicap-head-start-p1 = Vorsprung (S1)
+setting-handicap-size-p1 = Handicap Schlägergröße (S1)
+setting-handicap-speed-p1 = Handicap Schlägertempo (S1)
+setting-handicap-head-start-p2 = Vorsprung (S2)
+setting-handicap-size-p2 = Handicap Schlägergröße (S2)
+setting-handicap-speed-p2 = Handicap Schlägertempo (S2)
+setting-keep-lights-on = Licht anlassen
+setting-court-size = Spielfeldgröße
+setting-share-scores = Punkte online teilen
+setting-share-stats = Anonyme Statistiken teilen
+serve-straight = Gerade
+serve-random = Zufälliger Winkel
+serve-lob = Lob
+serve-power = Kraftvoll
+court-small = Klein
+court-standard = Standard
+court-large = Groß
+unit-px-per-second = px/s
+unit-kmh = km/h
+unit-mph = mph
+replay-full-screen = Vollbild
+replay-corner = In der Ecke
+setting-paddle-skin-p1 = Schläger-Design (S1)
+setting-paddle-skin-p2 = Schläger-Design (S2)
+setting-ball-skin = Ball-Design
+setting-arena-theme = Arena-Thema
+setting-follow-camera = Ball folgen
+setting-pause-blur = Unschärfe bei Pause
+controls-standard = WASD + Pfeiltasten
+controls-ijkl = WASD + IJKL
+controls-mirrored = Gespiegelt
+controls-numpad = WASD + Ziffernblock
+controls-left-hand = Linke Hand
+controls-custom = Benutzerdefiniert
+wheel-notch = { $pixels } px pro Raste
+display-mode-windowed = Fenster
+display-mode-borderless = Randlos
+display-mode-exclusive = Exklusives Vollbild
+ai-easy = Leicht
+ai-normal = Normal
+ai-hard = Schwer
+
+## Screen reader announcements
+
+announce-paused = Pausiert
+announce-resumed = Fortgesetzt
+announce-point = { $player } punktet, { $mine } zu { $theirs }
+announce-match-won = { $player } gewinnt das Spiel
+
+## Notifications
+
+toast-match-saved = Spiel gespeichert unter { $path }
+toast-match-export-failed = Spiel konnte nicht exportiert werden
+toast-screenshot-saved = Screenshot gespeichert unter { $path }
+toast-screenshot-failed = Screenshot konnte nicht gespeichert werden
+toast-survival-run = { $seconds } s überlebt
+toast-survival-best = Neuer Rekord: { $seconds } s!
+toast-coop-lives = Verbleibende Leben: { $lives }
+toast-coop-over = Keine Leben mehr! Teampunkte: { $points }
+leaderboard-title = Bestenliste
+leaderboard-survival = Längstes Überleben
+leaderboard-win-streak = Längste Siegesserie gegen den Computer
+leaderboard-entry-seconds = { $rank }. { $name } — { $seconds } s
+leaderboard-entry-wins = { $rank }. { $name } — { $wins }
+leaderboard-empty = Noch keine Einträge
+leaderboard-opt-in = Zum Mitmachen im Menü „Punkte online teilen“ einschalten
+leaderboard-offline = Offline: zuletzt geladene Ranglisten
+leaderboard-pending = Noch nicht gesendete Punkte: { $count }
+leaderboard-hint = Tab: zurück
+telemetry-title = Anonyme Statistiken, die noch gesendet werden
+telemetry-opt-in = Es wird nichts gesammelt. Schalte Anonyme Statistiken teilen im Einstellungsmenü ein, um mitzumachen
+telemetry-empty = Noch nichts
+telemetry-more = … und { $count } weitere
+telemetry-hint = I: zurück
+presence-1p = Gegen den Computer
+presence-2p = Zwei Spieler
+presence-ai = Schaut dem Computer zu
+presence-survival = Überleben
+presence-coop = Koop gegen den Computer
+presence-playing = Im Lauf
+presence-playing-score = Im Match, { $score }
+presence-paused = Pausiert
+presence-paused-score = Pausiert, { $score }
+presence-replay = Sieht eine Wiederholung
+presence-replay-score = Sieht eine Wiederholung, { $score }
+presence-intermission = Zwischen zwei Matches
+presence-loading = Lädt
+twitch-vote-open = Chat-Abstimmung offen! Tippt 1, 2 oder 3
+twitch-vote = Chat-Abstimmung, noch { $seconds } s: 1, 2 oder 3 tippen
+twitch-option = { $number }. { $mutator }: { $votes }
+twitch-picked = Der Chat hat { $mutator } gewählt!
+twitch-no-votes = Diesmal keine Stimmen aus dem Chat
+twitch-playing = Wahl des Chats: { $mutator }
+twitch-next = Nächste Chat-Abstimmung in { $seconds } s
+restart-hold = Gedrückt halten, um das Match neu zu starten
+toast-match-restarted = Match neu gestartet
+transition-point = { $player } punktet · { $score }
+transition-match = Match { $number }
+loading = Wird geladen … { $percent } %
+splash-engine = Erstellt mit Bevy
+splash-author = Ein Spiel von dpirvoiu
+handicap-head-start = Vorsprung +{ $points }
+handicap-paddle-size = Schläger { $percent }
+handicap-paddle-speed = Tempo { $percent }
+toast-xp-earned = +{ $xp } EP · Stufe { $level } ({ $progress }/{ $needed })
+toast-level-up = Stufenaufstieg! Du bist jetzt Stufe { $level }
+toast-rally-milestone = Ballwechsel mit { $hits } Treffern!
+toast-sudden-death = Sudden Death! Das nächste Tor entscheidet
+hud-rally = Ballwechsel
+
+## Tournament
+
+tournament-title = Turnier
+tournament-entry-hint = Namen eingeben und Enter drücken ({ $min } bis { $max } Spieler)
+tournament-start-hint = Enter in einer leeren Zeile startet das Turnier
+tournament-round = Runde { $round }
+tournament-final = Finale
+tournament-bye = Freilos
+tournament-next = Als Nächstes: { $left } gegen { $right }. Enter zum Spielen
+tournament-versus = { $left } gegen { $right }
+tournament-champion = { $name } gewinnt das Turnier!
+tournament-finish-hint = Enter zum Beenden
+
+## Campaign
+
+stage-rookie = Anfängerplatz
+stage-night = Nachtplatz
+stage-blitz = Blitz
+stage-wall = Die Mauer
+stage-finale = Finale
+campaign-title = Kampagne
+campaign-complete = Geschafft
+campaign-first-to = Wer zuerst { $points } hat
+campaign-locked = Gesperrt
+campaign-progress = { $done } von { $total } Etappen geschafft
+campaign-stage-won = { $stage } geschafft!
+campaign-stage-lost = { $stage } verloren. Noch einmal
+
+## Arena editor
+
+editor-title = Arena-Editor
+editor-help = Klick: Block setzen oder entfernen · [ ]: Torgröße · 1–0: Mutatoren · Enter: Probespiel · Strg+S: Speichern
+editor-goal-size = Torgröße: { $percent } %
+editor-test-play = Probespiel
+editor-edit = Zurück zum Editor
+editor-save = Speichern
+editor-saved = Arena gespeichert unter { $path }
+editor-save-failed = Arena konnte nicht gespeichert werden: { $error }
+mutator-fast-serves = Schnelle Aufschläge
+mutator-wide-opponent = Breiter Gegner
+mutator-heavy-ball = Schwerer Ball
+mutator-light-ball = Leichter Ball
+mutator-ghost-ball = Geisterball
+mutator-mirror = Spiegel
+mutator-lights-out = Licht aus
+mutator-multiball = Mehrere Bälle
+mutator-tiny-paddles = Winzige Schläger
+mutator-gravity = Schwerkraft
+
+## Bricks
+
+bricks-lane-cleared = { $player } ist durchgebrochen! Bonuspunkt
@@ -0,0 +1,249 @@
+## Players
+
+player-1 = Player 1
+player-2 = Player 2
+
+## Pause menu
+
+menu-paused = Paused
+menu-press-key = press a key (Esc cancels)
+replay-banner = Replay
+setting-on = On
+setting-off = Off
+setting-percent = { $value }%
+setting-auto = Auto
+
+setting-export-matches = Export matches
+setting-palette = Palette
+setting-colour-p1 = Paddle colour (P1)
+setting-colour-p2 = Paddle colour (P2)
+palette-classic = Classic
+palette-red-green-safe = Red/green safe
+palette-blue-yellow-safe = Blue/yellow safe
+colour-palette = From palette
+colour-red = Red
+colour-orange = Orange
+colour-yellow = Yellow
+colour-green = Green
+colour-cyan = Cyan
+colour-blue = Blue
+colour-purple = Purple
+colour-pink = Pink
+colour-white = White
+chroma-key-off = Off
+chroma-key-green = Green
+chroma-key-magenta = Magenta
+setting-paddle-patterns = Paddle patterns
+setting-high-contrast = High contrast
+setting-chroma-key = Chroma key background
+setting-large-ball = Large ball
+setting-slow-ball = Slow ball
+setting-large-paddle-p1 = Large paddle (P1)
+setting-large-paddle-p2 = Large paddle (P2)
+setting-trajectory-line = Trajectory line (practice)
+setting-magnet = Magnet assist
+magnet-gentle = Gentle
+magnet-medium = Medium
+magnet-strong = Strong
+magnet-flag = Magnet assist on: { $strength }
+magnet-ranked = Magnet assist off while sharing scores
+setting-barriers-1p = Barriers (1P)
+setting-barriers-2p = Barriers (2P)
+setting-barriers-survival = Barriers (survival)
+setting-stamina = Stamina
+setting-reduced-motion = Reduced motion
+setting-visual-cues = Visual sound cues
+setting-game-speed = Game speed
+setting-save-slow-motion = Save slow motion
+slow-motion-light = Light
+slow-motion-medium = Medium
+slow-motion-heavy = Heavy
+setting-controls = Controls
+setting-player1-up = Player 1 up
+setting-player1-down = Player 1 down
+setting-player1-smash = Player 1 smash
+setting-player1-tilt-up = Player 1 tilt up
+setting-player1-tilt-down = Player 1 tilt down
+setting-player1-barrier = Player 1 barrier
+setting-player2-up = Player 2 up
+setting-player2-down = Player 2 down
+setting-player2-smash = Player 2 smash
+setting-player2-tilt-up = Player 2 tilt up
+setting-player2-tilt-down = Player 2 tilt down
+setting-player2-barrier = Player 2 barrier
+setting-wheel-paddle = Mouse wheel moves
+setting-wheel-notch = Mouse wheel step
+setting-restart-key = Restart match (hold)
+setting-language = Language
+setting-window-mode = Window mode
+setting-vsync = VSync
+setting-fps-cap = Frame rate cap
+setting-ui-scale = UI scale
+setting-ai-difficulty = AI difficulty
+setting-goal-replay = Goal replays
+setting-speed-meter = Ball speed meter
+setting-minimal-hud = Minimal HUD
+setting-serve-style = Serves
+setting-keep-playing-unfocused = Keep playing when unfocused
+setting-handicap-head-start-p1 = Head start (P1)
+setting-handicap-size-p1 = Paddle size handicap (P1)
+setting-handicap-speed-p1 = Paddle speed handicap (P1)
+setting-handicap-head-start-p2 = Head start (P2)
+setting-handicap-size-p2 = Paddle size handicap (P2)
+setting-handicap-speed-p2 = Paddle speed handicap (P2)
+setting-keep-lights-on = Keep lights on
+setting-court-size = Court size
+setting-share-scores = Share scores online
+setting-share-stats = Share anonymous stats
+serve-straight = Straight
+serve-random = Random angle
+serve-lob = Lob
+serve-power = Power
+court-small = Small
+court-standard = Standard
+court-large = Large
+unit-px-per-second = px/s
+unit-kmh = km/h
+unit-mph = mph
+replay-full-screen = Full screen
+replay-corner = In the corner
+setting-paddle-skin-p1 = Paddle skin (P1)
+setting-paddle-skin-p2 = Paddle skin (P2)
+setting-ball-skin = Ball skin
+setting-arena-theme = Arena theme
+setting-follow-camera = Follow the ball
+setting-pause-blur = Blur when paused
+controls-standard = WASD + arrows
+controls-ijkl = WASD + IJKL
+controls-mirrored = Mirrored
+controls-numpad = WASD + numpad
+controls-left-hand = Left hand
+controls-custom = Custom
+wheel-notch = { $pixels } px per notch
+display-mode-windowed = Windowed
+display-mode-borderless = Borderless
+display-mode-exclusive = Exclusive fullscreen
+ai-easy = Easy
+ai-normal = Normal
+ai-hard = Hard
+
+## Screen reader announcements
+
+announce-paused = Paused
+announce-resumed = Resumed
+announce-point = { $player } scores, { $mine } to { $theirs }
+announce-match-won = { $player } wins the match
+
+## Notifications
+
+toast-match-saved = Match saved to { $path }
+toast-match-export-failed = Could not export match
+toast-screenshot-saved = Screenshot saved to { $path }
+toast-screenshot-failed = Could not save screenshot
+toast-survival-run = Survived { $seconds } s
+toast-survival-best = New best: { $seconds } s!
+toast-coop-lives = Lives left: { $lives }
+toast-coop-over = Out of lives! Team score: { $points }
+leaderboard-title = Leaderboard
+leaderboard-survival = Longest survival
+leaderboard-win-streak = Longest win streak against the computer
+leaderboard-entry-seconds = { $rank }. { $name } — { $seconds } s
+leaderboard-entry-wins = { $rank }. { $name } — { $wins }
+leaderboard-empty = No scores yet
+leaderboard-opt-in = Turn on Share scores online in the settings menu to take part
+leaderboard-offline = Offline: showing the last rankings fetched
+leaderboard-pending = Scores waiting to be sent: { $count }
+leaderboard-hint = Tab: back
+telemetry-title = Anonymous stats waiting to be sent
+telemetry-opt-in = Nothing is collected. Turn on Share anonymous stats in the settings menu to take part
+telemetry-empty = Nothing yet
+telemetry-more = …and { $count } more
+telemetry-hint = I: back
+presence-1p = Against the computer
+presence-2p = Two players
+presence-ai = Watching the computer play
+presence-survival = Survival
+presence-coop = Co-op against the computer
+presence-playing = In a run
+presence-playing-score = In match, { $score }
+presence-paused = Paused
+presence-paused-score = Paused, { $score }
+presence-replay = Watching a replay
+presence-replay-score = Watching a replay, { $score }
+presence-intermission = Between matches
+presence-loading = Loading
+twitch-vote-open = Chat vote open! Type 1, 2 or 3
+twitch-vote = Chat vote, { $seconds } s left: type 1, 2 or 3
+twitch-option = { $number }. { $mutator }: { $votes }
+twitch-picked = Chat picked { $mutator }!
+twitch-no-votes = No chat votes this round
+twitch-playing = Chat's pick: { $mutator }
+twitch-next = Next chat vote in { $seconds } s
+restart-hold = Keep holding to restart the match
+toast-match-restarted = Match restarted
+transition-point = { $player } scores · { $score }
+transition-match = Match { $number }
+loading = Loading… { $percent }%
+splash-engine = Made with Bevy
+splash-author = A game by dpirvoiu
+handicap-head-start = Head start +{ $points }
+handicap-paddle-size = Paddle { $percent }
+handicap-paddle-speed = Speed { $percent }
+toast-xp-earned = +{ $xp } XP · Level { $level } ({ $progress }/{ $needed })
+toast-level-up = Level up! You are now level { $level }
+toast-rally-milestone = { $hits }-hit rally!
+toast-sudden-death = Sudden death! Next goal wins
+hud-rally = Rally
+
+## Tournament
+
+tournament-title = Tournament
+tournament-entry-hint = Type a name and press Enter ({ $min } to { $max } players)
+tournament-start-hint = Press Enter on an empty line to start
+tournament-round = Round { $round }
+tournament-final = Final
+tournament-bye = bye
+tournament-next = Next: { $left } vs { $right }. Press Enter to play
+tournament-versus = { $left } vs { $right }
+tournament-champion = { $name } wins the tournament!
+tournament-finish-hint = Press Enter to finish
+
+## Campaign
+
+stage-rookie = Rookie court
+stage-night = Night court
+stage-blitz = Blitz
+stage-wall = The wall
+stage-finale = Finale
+campaign-title = Campaign
+campaign-complete = Complete
+campaign-first-to = First to { $points }
+campaign-locked = Locked
+campaign-progress = { $done } of { $total } stages complete
+campaign-stage-won = { $stage } complete!
+campaign-stage-lost = { $stage } lost. Try again
+
+## Arena editor
+
+editor-title = Arena editor
+editor-help = Click: add or remove a block · [ ]: goal size · 1–0: mutators · Enter: test play · Ctrl+S: save
+editor-goal-size = Goal size: { $percent }%
+editor-test-play = Test play
+editor-edit = Back to editing
+editor-save = Save
+editor-saved = Arena saved to { $path }
+editor-save-failed = Couldn't save the arena: { $error }
+mutator-fast-serves = Fast serves
+mutator-wide-opponent = Wide opponent
+mutator-heavy-ball = Heavy ball
+mutator-light-ball = Light ball
+mutator-ghost-ball = Ghost ball
+mutator-mirror = Mirror
+mutator-lights-out = Lights out
+mutator-multiball = Multiball
+mutator-tiny-paddles = Tiny paddles
+mutator-gravity = Gravity
+
+## Bricks
+
+bricks-lane-cleared = { $player } broke through! Bonus point
@@ -0,0 +1,249 @@
+## Players
+
+player-1 = Jugador 1
+player-2 = Jugador 2
+
+## Pause menu
+
+menu-paused = Pausa
+menu-press-key = pulsa una tecla (Esc cancela)
+replay-banner = Repetición
+setting-on = Sí
+setting-off = No
+setting-percent = { $value } %
+setting-auto = Automática
+
+setting-export-matches = Exportar partidos
+setting-palette = Paleta
+setting-colour-p1 = Color de pala (J1)
+setting-colour-p2 = Color de pala (J2)
+palette-classic = Clásica
+palette-red-green-safe = Apta rojo/verde
+palette-blue-yellow-safe = Apta azul/amarillo
+colour-palette = De la paleta
+colour-red = Rojo
+colour-orange = Naranja
+colour-yellow = Amarillo
+colour-green = Verde
+colour-cyan = Cian
+colour-blue = Azul
+colour-purple = Morado
+colour-pink = Rosa
+colour-white = Blanco
+chroma-key-off = Desactivado
+chroma-key-green = Verde
+chroma-key-magenta = Magenta
+setting-paddle-patterns = Patrones de pala
+setting-high-contrast = Alto contraste
+setting-chroma-key = Fondo de croma
+setting-large-ball = Pelota grande
+setting-slow-ball = Pelota lenta
+setting-large-paddle-p1 = Pala grande (J1)
+setting-large-paddle-p2 = Pala grande (J2)
+setting-trajectory-line = Línea de trayectoria (práctica)
+setting-magnet = Ayuda imán
+magnet-gentle = Suave
+magnet-medium = Media
+magnet-strong = Fuerte
+magnet-flag = Ayuda imán activada: { $strength }
+magnet-ranked = Ayuda imán desactivada al compartir puntuaciones
+setting-barriers-1p = Barreras (1J)
+setting-barriers-2p = Barreras (2J)
+setting-barriers-survival = Barreras (supervivencia)
+setting-stamina = Resistencia
+setting-reduced-motion = Movimiento reducido
+setting-visual-cues = Indicadores visuales de sonido
+setting-game-speed = Velocidad del juego
+setting-save-slow-motion = Cámara lenta en salvadas
+slow-motion-light = Ligera
+slow-motion-medium = Media
+slow-motion-heavy = Fuerte
+setting-controls = Controles
+setting-player1-up = Jugador 1 arriba
+setting-player1-down = Jugador 1 abajo
+setting-player1-smash = Jugador 1 remate
+setting-player1-tilt-up = Jugador 1 inclinar arriba
+setting-player1-tilt-down = Jugador 1 inclinar abajo
+setting-player1-barrier = Jugador 1 barrera
+setting-player2-up = Jugador 2 arriba
+setting-player2-down = Jugador 2 abajo
+setting-player2-smash = Jugador 2 remate
+setting-player2-tilt-up = Jugador 2 inclinar arriba
+setting-player2-tilt-down = Jugador 2 inclinar abajo
+setting-player2-barrier = Jugador 2 barrera
+setting-wheel-paddle = La rueda del ratón mueve
+setting-wheel-notch = Paso de la rueda
+setting-restart-key = Reiniciar partido (mantener)
+setting-language = Idioma
+setting-window-mode = Modo de ventana
+setting-vsync = Sincronización vertical
+setting-fps-cap = Límite de fotogramas
+setting-ui-scale = Escala de la interfaz
+setting-ai-difficulty = Dificultad de la IA
+setting-goal-replay = Repetición de goles
+setting-speed-meter = Velocímetro de la bola
+setting-minimal-hud = Interfaz mínima
+setting-serve-style = Saques
+setting-keep-playing-unfocused = Seguir jugando sin foco
+setting-handicap-head-start-p1 = Ventaja inicial (J1)
+setting-handicap-size-p1 = Hándicap de tamaño de pala (J1)
+setting-handicap-speed-p1 = Hándicap de velocidad de pala (J1)
+setting-handicap-head-start-p2 = Ventaja inicial (J2)
+setting-handicap-size-p2 = Hándicap de tamaño de pala (J2)
+setting-handicap-speed-p2 = Hándicap de velocidad de pala (J2)
+setting-keep-lights-on = Mantener las luces
+setting-court-size = Tamaño de la pista
+setting-share-scores = Compartir puntuaciones en línea
+setting-share-stats = Compartir estadísticas anónimas
+serve-straight = Rectos
+serve-random = Ángulo aleatorio
+serve-lob = Globo
+serve-power = Potentes
+court-small = Pequeña
+court-standard = Estándar
+court-large = Grande
+unit-px-per-second = px/s
+unit-kmh = km/h
+unit-mph = mph
+replay-full-screen = Pantalla completa
+replay-corner = En la esquina
+setting-paddle-skin-p1 = Aspecto de la pala (J1)
+setting-paddle-skin-p2 = Aspecto de la pala (J2)
+setting-ball-skin = Aspecto de la pelota
+setting-arena-theme = Tema de la pista
+setting-follow-camera = Seguir la pelota
+setting-pause-blur = Desenfocar en pausa
+controls-standard = WASD + flechas
+controls-ijkl = WASD + IJKL
+controls-mirrored = Invertidos
+controls-numpad = WASD + teclado numérico
+controls-left-hand = Mano izquierda
+controls-custom = Personalizados
+wheel-notch = { $pixels } px por muesca
+display-mode-windowed = En ventana
+display-mode-borderless = Sin bordes
+display-mode-exclusive = Pantalla completa exclusiva
+ai-easy = Fácil
+ai-normal = Normal
+ai-hard = Difícil
+
+## Screen reader announcements
+
+announce-paused = En pausa
+announce-resumed = Reanudado
+announce-point = { $player } anota, { $mine } a { $theirs }
+announce-match-won = { $player } gana el partido
+
+## Notifications
+
+toast-match-saved = Partido guardado en { $path }
+toast-match-export-failed = No se pudo exportar el partido
+toast-screenshot-saved = Captura guardada en { $path }
+toast-screenshot-failed = No se pudo guardar la captura
+toast-survival-run = Aguantaste { $seconds } s
+toast-survival-best = ¡Nuevo récord: { $seconds } s!
+toast-coop-lives = Vidas restantes: { $lives }
+toast-coop-over = ¡Sin vidas! Puntuación del equipo: { $points }
+leaderboard-title = Clasificación
+leaderboard-survival = Supervivencia más larga
+leaderboard-win-streak = Racha de victorias más larga contra el ordenador
+leaderboard-entry-seconds = { $rank }. { $name } — { $seconds } s
+leaderboard-entry-wins = { $rank }. { $name } — { $wins }
+leaderboard-empty = Aún no hay puntuaciones
+leaderboard-opt-in = Activa Compartir puntuaciones en línea en el menú para participar
+leaderboard-offline = Sin conexión: últimas clasificaciones recibidas
+leaderboard-pending = Puntuaciones pendientes de envío: { $count }
+leaderboard-hint = Tab: volver
+telemetry-title = Estadísticas anónimas pendientes de envío
+telemetry-opt-in = No se recoge nada. Activa Compartir estadísticas anónimas en el menú de ajustes para participar
+telemetry-empty = Nada todavía
+telemetry-more = … y { $count } más
+telemetry-hint = I: volver
+presence-1p = Contra el ordenador
+presence-2p = Dos jugadores
+presence-ai = Viendo jugar al ordenador
+presence-survival = Supervivencia
+presence-coop = Cooperativo contra el ordenador
+presence-playing = En una partida
+presence-playing-score = En partido, { $score }
+presence-paused = En pausa
+presence-paused-score = En pausa, { $score }
+presence-replay = Viendo una repetición
+presence-replay-score = Viendo una repetición, { $score }
+presence-intermission = Entre partidos
+presence-loading = Cargando
+twitch-vote-open = ¡Votación del chat abierta! Escribe 1, 2 o 3
+twitch-vote = Votación del chat, quedan { $seconds } s: escribe 1, 2 o 3
+twitch-option = { $number }. { $mutator }: { $votes }
+twitch-picked = ¡El chat eligió { $mutator }!
+twitch-no-votes = Sin votos del chat esta ronda
+twitch-playing = Elección del chat: { $mutator }
+twitch-next = Próxima votación del chat en { $seconds } s
+restart-hold = Mantén pulsado para reiniciar el partido
+toast-match-restarted = Partido reiniciado
+transition-point = { $player } anota · { $score }
+transition-match = Partido { $number }
+loading = Cargando… { $percent } %
+splash-engine = Hecho con Bevy
+splash-author = Un juego de dpirvoiu
+handicap-head-start = Ventaja +{ $points }
+handicap-paddle-size = Pala { $percent }
+handicap-paddle-speed = Velocidad { $percent }
+toast-xp-earned = +{ $xp } XP · Nivel { $level } ({ $progress }/{ $needed })
+toast-level-up = ¡Subes de nivel! Ahora eres nivel { $level }
+toast-rally-milestone = ¡Peloteo de { $hits } golpes!
+toast-sudden-death = ¡Muerte súbita! El próximo gol gana
+hud-rally = Peloteo
+
+## Tournament
+
+tournament-title = Torneo
+tournament-entry-hint = Escribe un nombre y pulsa Intro (de { $min } a { $max } jugadores)
+tournament-start-hint = Pulsa Intro en una línea vacía para empezar
+tournament-round = Ronda { $round }
+tournament-final = Final
+tournament-bye = pase libre
+tournament-next = Siguiente: { $left } contra { $right }. Pulsa Intro para jugar
+tournament-versus = { $left } contra { $right }
+tournament-champion = ¡{ $name } gana el torneo!
+tournament-finish-hint = Pulsa Intro para terminar
+
+## Campaign
+
+stage-rookie = Pista de novato
+stage-night = Pista nocturna
+stage-blitz = Blitz
+stage-wall = El muro
+stage-finale = Final
+campaign-title = Campaña
+campaign-complete = Completada
+campaign-first-to = Al primero en { $points }
+campaign-locked = Bloqueada
+campaign-progress = { $done } de { $total } etapas completadas
+campaign-stage-won = ¡{ $stage } completada!
+campaign-stage-lost = { $stage } perdida. Inténtalo de nuevo
+
+## Arena editor
+
+editor-title = Editor de pistas
+editor-help = Clic: poner o quitar un bloque · [ ]: tamaño de las porterías · 1–0: mutadores · Intro: probar · Ctrl+S: guardar
+editor-goal-size = Tamaño de las porterías: { $percent } %
+editor-test-play = Probar
+editor-edit = Volver al editor
+editor-save = Guardar
+editor-saved = Pista guardada en { $path }
+editor-save-failed = No se pudo guardar la pista: { $error }
+mutator-fast-serves = Saques rápidos
+mutator-wide-opponent = Rival ancho
+mutator-heavy-ball = Pelota pesada
+mutator-light-ball = Pelota ligera
+mutator-ghost-ball = Pelota fantasma
+mutator-mirror = Espejo
+mutator-lights-out = Apagón
+mutator-multiball = Multibola
+mutator-tiny-paddles = Palas diminutas
+mutator-gravity = Gravedad
+
+## Bricks
+
+bricks-lane-cleared = ¡{ $player } ha abierto un hueco! Punto extra
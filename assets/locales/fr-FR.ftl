@@ -0,0 +1,249 @@
+## Players
+
+player-1 = Joueur 1
+player-2 = Joueur 2
+
+## Pause menu
+
+menu-paused = Pause
+menu-press-key = appuyez sur une touche (Échap pour annuler)
+replay-banner = Ralenti
+setting-on = Activé
+setting-off = Désactivé
+setting-percent = { $value } %
+setting-auto = Auto
+
+setting-export-matches = Exporter les matchs
+setting-palette = Palette
+setting-colour-p1 = Couleur de raquette (J1)
+setting-colour-p2 = Couleur de raquette (J2)
+palette-classic = Classique
+palette-red-green-safe = Adaptée rouge/vert
+palette-blue-yellow-safe = Adaptée bleu/jaune
+colour-palette = Selon la palette
+colour-red = Rouge
+colour-orange = Orange
+colour-yellow = Jaune
+colour-green = Vert
+colour-cyan = Cyan
+colour-blue = Bleu
+colour-purple = Violet
+colour-pink = Rose
+colour-white = Blanc
+chroma-key-off = Désactivé
+chroma-key-green = Vert
+chroma-key-magenta = Magenta
+setting-paddle-patterns = Motifs des raquettes
+setting-high-contrast = Contraste élevé
+setting-chroma-key = Fond pour incrustation
+setting-large-ball = Grande balle
+setting-slow-ball = Balle lente
+setting-large-paddle-p1 = Grande raquette (J1)
+setting-large-paddle-p2 = Grande raquette (J2)
+setting-trajectory-line = Trajectoire (entraînement)
+setting-magnet = Aide aimant
+magnet-gentle = Douce
+magnet-medium = Moyenne
+magnet-strong = Forte
+magnet-flag = Aide aimant activée : { $strength }
+magnet-ranked = Aide aimant désactivée pendant le partage des scores
+setting-barriers-1p = Barrières (1J)
+setting-barriers-2p = Barrières (2J)
+setting-barriers-survival = Barrières (survie)
+setting-stamina = Endurance
+setting-reduced-motion = Animations réduites
+setting-visual-cues = Indicateurs visuels des sons
+setting-game-speed = Vitesse du jeu
+setting-save-slow-motion = Ralenti sur sauvetage
+slow-motion-light = Léger
+slow-motion-medium = Moyen
+slow-motion-heavy = Fort
+setting-controls = Commandes
+setting-player1-up = Joueur 1 haut
+setting-player1-down = Joueur 1 bas
+setting-player1-smash = Joueur 1 smash
+setting-player1-tilt-up = Joueur 1 incliner vers le haut
+setting-player1-tilt-down = Joueur 1 incliner vers le bas
+setting-player1-barrier = Joueur 1 barrière
+setting-player2-up = Joueur 2 haut
+setting-player2-down = Joueur 2 bas
+setting-player2-smash = Joueur 2 smash
+setting-player2-tilt-up = Joueur 2 incliner vers le haut
+setting-player2-tilt-down = Joueur 2 incliner vers le bas
+setting-player2-barrier = Joueur 2 barrière
+setting-wheel-paddle = La molette déplace
+setting-wheel-notch = Pas de la molette
+setting-restart-key = Recommencer le match (maintenir)
+setting-language = Langue
+setting-window-mode = Mode d’affichage
+setting-vsync = Synchro verticale
+setting-fps-cap = Limite d’images par seconde
+setting-ui-scale = Taille de l’interface
+setting-ai-difficulty = Difficulté de l’IA
+setting-goal-replay = Ralenti des buts
+setting-speed-meter = Compteur de vitesse
+setting-minimal-hud = Interface minimale
+setting-serve-style = Services
+setting-keep-playing-unfocused = Continuer hors focus
+setting-handicap-head-start-p1 = Points d’avance (J1)
+setting-handicap-size-p1 = Handicap de taille de raquette (J1)
+setting-handicap-speed-p1 = Handicap de vitesse de raquette (J1)
+setting-handicap-head-start-p2 = Points d’avance (J2)
+setting-handicap-size-p2 = Handicap de taille de raquette (J2)
+setting-handicap-speed-p2 = Handicap de vitesse de raquette (J2)
+setting-keep-lights-on = Garder la lumière
+setting-court-size = Taille du terrain
+setting-share-scores = Partager les scores en ligne
+setting-share-stats = Partager des statistiques anonymes
+serve-straight = Droits
+serve-random = Angle aléatoire
+serve-lob = Lob
+serve-power = Puissants
+court-small = Petit
+court-standard = Standard
+court-large = Grand
+unit-px-per-second = px/s
+unit-kmh = km/h
+unit-mph = mi/h
+replay-full-screen = Plein écran
+replay-corner = Dans le coin
+setting-paddle-skin-p1 = Apparence de la raquette (J1)
+setting-paddle-skin-p2 = Apparence de la raquette (J2)
+setting-ball-skin = Apparence de la balle
+setting-arena-theme = Thème de l'arène
+setting-follow-camera = Suivre la balle
+setting-pause-blur = Flou en pause
+controls-standard = ZQSD + flèches
+controls-ijkl = ZQSD + IJKL
+controls-mirrored = Inversées
+controls-numpad = ZQSD + pavé numérique
+controls-left-hand = Main gauche
+controls-custom = Personnalisées
+wheel-notch = { $pixels } px par cran
+display-mode-windowed = Fenêtré
+display-mode-borderless = Sans bordure
+display-mode-exclusive = Plein écran exclusif
+ai-easy = Facile
+ai-normal = Normale
+ai-hard = Difficile
+
+## Screen reader announcements
+
+announce-paused = Pause
+announce-resumed = Reprise
+announce-point = { $player } marque, { $mine } à { $theirs }
+announce-match-won = { $player } remporte le match
+
+## Notifications
+
+toast-match-saved = Match enregistré dans { $path }
+toast-match-export-failed = Impossible d'exporter le match
+toast-screenshot-saved = Capture d'écran enregistrée dans { $path }
+toast-screenshot-failed = Impossible d'enregistrer la capture d'écran
+toast-survival-run = Tenu { $seconds } s
+toast-survival-best = Nouveau record : { $seconds } s !
+toast-coop-lives = Vies restantes : { $lives }
+toast-coop-over = Plus de vies ! Score de l’équipe : { $points }
+leaderboard-title = Classement
+leaderboard-survival = Plus longue survie
+leaderboard-win-streak = Plus longue série de victoires contre l'ordinateur
+leaderboard-entry-seconds = { $rank }. { $name } — { $seconds } s
+leaderboard-entry-wins = { $rank }. { $name } — { $wins }
+leaderboard-empty = Aucun score pour l'instant
+leaderboard-opt-in = Activez Partager les scores en ligne dans le menu pour participer
+leaderboard-offline = Hors ligne : derniers classements reçus
+leaderboard-pending = Scores en attente d'envoi : { $count }
+leaderboard-hint = Tab : retour
+telemetry-title = Statistiques anonymes en attente d'envoi
+telemetry-opt-in = Rien n'est collecté. Activez Partager des statistiques anonymes dans le menu des réglages pour participer
+telemetry-empty = Rien pour l'instant
+telemetry-more = … et { $count } de plus
+telemetry-hint = I : retour
+presence-1p = Contre l'ordinateur
+presence-2p = Deux joueurs
+presence-ai = Regarde l'ordinateur jouer
+presence-survival = Survie
+presence-coop = En coopération contre l'ordinateur
+presence-playing = En pleine partie
+presence-playing-score = En match, { $score }
+presence-paused = En pause
+presence-paused-score = En pause, { $score }
+presence-replay = Regarde un ralenti
+presence-replay-score = Regarde un ralenti, { $score }
+presence-intermission = Entre deux matchs
+presence-loading = Chargement
+twitch-vote-open = Vote du chat ouvert ! Tapez 1, 2 ou 3
+twitch-vote = Vote du chat, encore { $seconds } s : tapez 1, 2 ou 3
+twitch-option = { $number }. { $mutator } : { $votes }
+twitch-picked = Le chat a choisi { $mutator } !
+twitch-no-votes = Aucun vote du chat cette fois
+twitch-playing = Choix du chat : { $mutator }
+twitch-next = Prochain vote du chat dans { $seconds } s
+restart-hold = Maintenez pour recommencer le match
+toast-match-restarted = Match recommencé
+transition-point = { $player } marque · { $score }
+transition-match = Match { $number }
+loading = Chargement… { $percent } %
+splash-engine = Fait avec Bevy
+splash-author = Un jeu de dpirvoiu
+handicap-head-start = Avance +{ $points }
+handicap-paddle-size = Raquette { $percent }
+handicap-paddle-speed = Vitesse { $percent }
+toast-xp-earned = +{ $xp } XP · Niveau { $level } ({ $progress }/{ $needed })
+toast-level-up = Niveau supérieur ! Vous êtes niveau { $level }
+toast-rally-milestone = Échange de { $hits } frappes !
+toast-sudden-death = Mort subite ! Le prochain but l’emporte
+hud-rally = Échange
+
+## Tournament
+
+tournament-title = Tournoi
+tournament-entry-hint = Tapez un nom et appuyez sur Entrée ({ $min } à { $max } joueurs)
+tournament-start-hint = Appuyez sur Entrée sur une ligne vide pour commencer
+tournament-round = Tour { $round }
+tournament-final = Finale
+tournament-bye = exempt
+tournament-next = Prochain match : { $left } contre { $right }. Appuyez sur Entrée
+tournament-versus = { $left } contre { $right }
+tournament-champion = { $name } remporte le tournoi !
+tournament-finish-hint = Appuyez sur Entrée pour terminer
+
+## Campaign
+
+stage-rookie = Terrain débutant
+stage-night = Terrain de nuit
+stage-blitz = Blitz
+stage-wall = Le mur
+stage-finale = Finale
+campaign-title = Campagne
+campaign-complete = Terminé
+campaign-first-to = Premier à { $points }
+campaign-locked = Verrouillé
+campaign-progress = { $done } étapes terminées sur { $total }
+campaign-stage-won = { $stage } terminé !
+campaign-stage-lost = { $stage } perdu. Réessayez
+
+## Arena editor
+
+editor-title = Éditeur d'arène
+editor-help = Clic : ajouter ou retirer un bloc · [ ] : taille des buts · 1–0 : mutateurs · Entrée : essayer · Ctrl+S : enregistrer
+editor-goal-size = Taille des buts : { $percent } %
+editor-test-play = Essayer
+editor-edit = Retour à l'édition
+editor-save = Enregistrer
+editor-saved = Arène enregistrée dans { $path }
+editor-save-failed = Impossible d'enregistrer l'arène : { $error }
+mutator-fast-serves = Services rapides
+mutator-wide-opponent = Adversaire élargi
+mutator-heavy-ball = Balle lourde
+mutator-light-ball = Balle légère
+mutator-ghost-ball = Balle fantôme
+mutator-mirror = Miroir
+mutator-lights-out = Extinction des feux
+mutator-multiball = Multi-balles
+mutator-tiny-paddles = Raquettes minuscules
+mutator-gravity = Gravité
+
+## Bricks
+
+bricks-lane-cleared = { $player } a percé le mur ! Point bonus
@@ -0,0 +1,249 @@
+## Players
+
+player-1 = Игрок 1
+player-2 = Игрок 2
+
+## Pause menu
+
+menu-paused = Пауза
+menu-press-key = нажмите клавишу (Esc — отмена)
+replay-banner = Повтор
+setting-on = Вкл.
+setting-off = Выкл.
+setting-percent = { $value }%
+setting-auto = Авто
+
+setting-export-matches = Экспорт матчей
+setting-palette = Палитра
+setting-colour-p1 = Цвет ракетки (И1)
+setting-colour-p2 = Цвет ракетки (И2)
+palette-classic = Классическая
+palette-red-green-safe = Для красно-зелёной слепоты
+palette-blue-yellow-safe = Для сине-жёлтой слепоты
+colour-palette = Из палитры
+colour-red = Красный
+colour-orange = Оранжевый
+colour-yellow = Жёлтый
+colour-green = Зелёный
+colour-cyan = Голубой
+colour-blue = Синий
+colour-purple = Фиолетовый
+colour-pink = Розовый
+colour-white = Белый
+chroma-key-off = Выкл.
+chroma-key-green = Зелёный
+chroma-key-magenta = Пурпурный
+setting-paddle-patterns = Узоры ракеток
+setting-high-contrast = Высокий контраст
+setting-chroma-key = Фон для хромакея
+setting-large-ball = Большой мяч
+setting-slow-ball = Медленный мяч
+setting-large-paddle-p1 = Большая ракетка (И1)
+setting-large-paddle-p2 = Большая ракетка (И2)
+setting-trajectory-line = Линия траектории (тренировка)
+setting-magnet = Магнит-помощник
+magnet-gentle = Слабый
+magnet-medium = Средний
+magnet-strong = Сильный
+magnet-flag = Магнит-помощник включён: { $strength }
+magnet-ranked = Магнит-помощник выключен, пока результаты публикуются
+setting-barriers-1p = Барьеры (1И)
+setting-barriers-2p = Барьеры (2И)
+setting-barriers-survival = Барьеры (выживание)
+setting-stamina = Выносливость
+setting-reduced-motion = Меньше движения
+setting-visual-cues = Визуальные звуковые подсказки
+setting-game-speed = Скорость игры
+setting-save-slow-motion = Замедление при спасении
+slow-motion-light = Слабое
+slow-motion-medium = Среднее
+slow-motion-heavy = Сильное
+setting-controls = Управление
+setting-player1-up = Игрок 1 вверх
+setting-player1-down = Игрок 1 вниз
+setting-player1-smash = Игрок 1 смэш
+setting-player1-tilt-up = Игрок 1 наклон вверх
+setting-player1-tilt-down = Игрок 1 наклон вниз
+setting-player1-barrier = Игрок 1 барьер
+setting-player2-up = Игрок 2 вверх
+setting-player2-down = Игрок 2 вниз
+setting-player2-smash = Игрок 2 смэш
+setting-player2-tilt-up = Игрок 2 наклон вверх
+setting-player2-tilt-down = Игрок 2 наклон вниз
+setting-player2-barrier = Игрок 2 барьер
+setting-wheel-paddle = Колесо мыши двигает
+setting-wheel-notch = Шаг колеса мыши
+setting-restart-key = Перезапуск матча (удерживать)
+setting-language = Язык
+setting-window-mode = Режим окна
+setting-vsync = Вертикальная синхронизация
+setting-fps-cap = Ограничение кадров
+setting-ui-scale = Масштаб интерфейса
+setting-ai-difficulty = Сложность ИИ
+setting-goal-replay = Повтор голов
+setting-speed-meter = Спидометр мяча
+setting-minimal-hud = Минимальный интерфейс
+setting-serve-style = Подачи
+setting-keep-playing-unfocused = Играть без фокуса окна
+setting-handicap-head-start-p1 = Фора в очках (И1)
+setting-handicap-size-p1 = Гандикап размера ракетки (И1)
+setting-handicap-speed-p1 = Гандикап скорости ракетки (И1)
+setting-handicap-head-start-p2 = Фора в очках (И2)
+setting-handicap-size-p2 = Гандикап размера ракетки (И2)
+setting-handicap-speed-p2 = Гандикап скорости ракетки (И2)
+setting-keep-lights-on = Не выключать свет
+setting-court-size = Размер поля
+setting-share-scores = Отправлять результаты в сеть
+setting-share-stats = Отправлять анонимную статистику
+serve-straight = Прямые
+serve-random = Случайный угол
+serve-lob = Свеча
+serve-power = Силовые
+court-small = Маленькое
+court-standard = Стандартное
+court-large = Большое
+unit-px-per-second = пикс/с
+unit-kmh = км/ч
+unit-mph = миль/ч
+replay-full-screen = Во весь экран
+replay-corner = В углу
+setting-paddle-skin-p1 = Облик ракетки (И1)
+setting-paddle-skin-p2 = Облик ракетки (И2)
+setting-ball-skin = Облик мяча
+setting-arena-theme = Тема арены
+setting-follow-camera = Следить за мячом
+setting-pause-blur = Размытие на паузе
+controls-standard = WASD + стрелки
+controls-ijkl = WASD + IJKL
+controls-mirrored = Зеркально
+controls-numpad = WASD + цифровой блок
+controls-left-hand = Левая рука
+controls-custom = Своё
+wheel-notch = { $pixels } пикс. за щелчок
+display-mode-windowed = В окне
+display-mode-borderless = Без рамки
+display-mode-exclusive = Эксклюзивный полноэкранный
+ai-easy = Лёгкая
+ai-normal = Обычная
+ai-hard = Сложная
+
+## Screen reader announcements
+
+announce-paused = Пауза
+announce-resumed = Продолжение
+announce-point = { $player } забивает, { $mine } : { $theirs }
+announce-match-won = { $player } выигрывает матч
+
+## Notifications
+
+toast-match-saved = Матч сохранён в { $path }
+toast-match-export-failed = Не удалось экспортировать матч
+toast-screenshot-saved = Снимок экрана сохранён в { $path }
+toast-screenshot-failed = Не удалось сохранить снимок экрана
+toast-survival-run = Продержались { $seconds } с
+toast-survival-best = Новый рекорд: { $seconds } с!
+toast-coop-lives = Осталось жизней: { $lives }
+toast-coop-over = Жизни кончились! Счёт команды: { $points }
+leaderboard-title = Таблица рекордов
+leaderboard-survival = Самое долгое выживание
+leaderboard-win-streak = Самая длинная серия побед над компьютером
+leaderboard-entry-seconds = { $rank }. { $name } — { $seconds } с
+leaderboard-entry-wins = { $rank }. { $name } — { $wins }
+leaderboard-empty = Пока нет результатов
+leaderboard-opt-in = Включите «Отправлять результаты в сеть» в меню, чтобы участвовать
+leaderboard-offline = Нет связи: показаны последние загруженные результаты
+leaderboard-pending = Ожидают отправки: { $count }
+leaderboard-hint = Tab: назад
+telemetry-title = Анонимная статистика, ожидающая отправки
+telemetry-opt-in = Ничего не собирается. Включите «Отправлять анонимную статистику» в настройках, чтобы участвовать
+telemetry-empty = Пока ничего
+telemetry-more = …и ещё { $count }
+telemetry-hint = I: назад
+presence-1p = Против компьютера
+presence-2p = Два игрока
+presence-ai = Смотрит игру компьютера
+presence-survival = Выживание
+presence-coop = Вместе против компьютера
+presence-playing = В забеге
+presence-playing-score = В матче, { $score }
+presence-paused = Пауза
+presence-paused-score = Пауза, { $score }
+presence-replay = Смотрит повтор
+presence-replay-score = Смотрит повтор, { $score }
+presence-intermission = Между матчами
+presence-loading = Загрузка
+twitch-vote-open = Голосование чата открыто! Пишите 1, 2 или 3
+twitch-vote = Голосование чата, осталось { $seconds } с: пишите 1, 2 или 3
+twitch-option = { $number }. { $mutator }: { $votes }
+twitch-picked = Чат выбрал: { $mutator }!
+twitch-no-votes = В этом раунде чат не голосовал
+twitch-playing = Выбор чата: { $mutator }
+twitch-next = Следующее голосование чата через { $seconds } с
+restart-hold = Удерживайте, чтобы начать матч заново
+toast-match-restarted = Матч начат заново
+transition-point = { $player } забивает · { $score }
+transition-match = Матч { $number }
+loading = Загрузка… { $percent }%
+splash-engine = Сделано на Bevy
+splash-author = Игра от dpirvoiu
+handicap-head-start = Фора +{ $points }
+handicap-paddle-size = Ракетка { $percent }
+handicap-paddle-speed = Скорость { $percent }
+toast-xp-earned = +{ $xp } опыта · Уровень { $level } ({ $progress }/{ $needed })
+toast-level-up = Новый уровень: { $level }!
+toast-rally-milestone = Розыгрыш из { $hits } ударов!
+toast-sudden-death = Внезапная смерть! Следующий гол решает
+hud-rally = Розыгрыш
+
+## Tournament
+
+tournament-title = Турнир
+tournament-entry-hint = Введите имя и нажмите Enter (от { $min } до { $max } игроков)
+tournament-start-hint = Нажмите Enter на пустой строке, чтобы начать
+tournament-round = Раунд { $round }
+tournament-final = Финал
+tournament-bye = без соперника
+tournament-next = Далее: { $left } против { $right }. Нажмите Enter
+tournament-versus = { $left } против { $right }
+tournament-champion = { $name } побеждает в турнире!
+tournament-finish-hint = Нажмите Enter, чтобы закончить
+
+## Campaign
+
+stage-rookie = Корт новичка
+stage-night = Ночной корт
+stage-blitz = Блиц
+stage-wall = Стена
+stage-finale = Финал
+campaign-title = Кампания
+campaign-complete = Пройдено
+campaign-first-to = До { $points } очков
+campaign-locked = Закрыто
+campaign-progress = Пройдено этапов: { $done } из { $total }
+campaign-stage-won = { $stage }: пройдено!
+campaign-stage-lost = { $stage }: поражение. Попробуйте ещё раз
+
+## Arena editor
+
+editor-title = Редактор арены
+editor-help = Щелчок: поставить или убрать блок · [ ]: размер ворот · 1–0: модификаторы · Enter: проба · Ctrl+S: сохранить
+editor-goal-size = Размер ворот: { $percent }%
+editor-test-play = Проба
+editor-edit = Вернуться к редактору
+editor-save = Сохранить
+editor-saved = Арена сохранена в { $path }
+editor-save-failed = Не удалось сохранить арену: { $error }
+mutator-fast-serves = Быстрые подачи
+mutator-wide-opponent = Широкий соперник
+mutator-heavy-ball = Тяжёлый мяч
+mutator-light-ball = Лёгкий мяч
+mutator-ghost-ball = Мяч-призрак
+mutator-mirror = Зеркало
+mutator-lights-out = Свет погас
+mutator-multiball = Мультимяч
+mutator-tiny-paddles = Крошечные ракетки
+mutator-gravity = Гравитация
+
+## Bricks
+
+bricks-lane-cleared = { $player } пробивает стену! Бонусное очко
@@ -0,0 +1,24 @@
+// Gaussian blur over a 5x5 grid of samples, used behind the pause menu. See `src/blur.rs`.
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+// Spacing between samples, in pixels.
+const RADIUS: f32 = 3.0;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let step = RADIUS / vec2<f32>(textureDimensions(screen_texture));
+    var total = vec4<f32>(0.0);
+    var weights = 0.0;
+    for (var x = -2; x <= 2; x++) {
+        for (var y = -2; y <= 2; y++) {
+            let offset = vec2<f32>(f32(x), f32(y));
+            let weight = exp(-dot(offset, offset) / 4.0);
+            total += textureSample(screen_texture, screen_sampler, in.uv + offset * step) * weight;
+            weights += weight;
+        }
+    }
+    return total / weights;
+}
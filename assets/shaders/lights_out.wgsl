@@ -0,0 +1,23 @@
+// Darkness over the court with soft spotlights cut out of it. See `src/lights_out.rs`.
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+// Share of a spotlight's radius over which its edge fades into the dark.
+const SOFTNESS: f32 = 0.4;
+
+// xy: centre in world space, z: radius. Spots with no radius are unused.
+@group(2) @binding(0) var<uniform> spots: array<vec4<f32>, 6>;
+@group(2) @binding(1) var<uniform> darkness: f32;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    var light = 0.0;
+    for (var i = 0; i < 6; i++) {
+        let spot = spots[i];
+        if spot.z <= 0.0 {
+            continue;
+        }
+        let distance = length(in.world_position.xy - spot.xy);
+        light = max(light, 1.0 - smoothstep(spot.z * (1.0 - SOFTNESS), spot.z, distance));
+    }
+    return vec4<f32>(0.0, 0.0, 0.0, darkness * (1.0 - light));
+}
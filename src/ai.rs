@@ -0,0 +1,216 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Ball, GameState, Paddle, Player,
+    arena::Arena,
+    menu::{AppSettingsExt, SettingRow},
+    paddle_velocity,
+    rng::GameRng,
+    settings::Settings,
+    tuning::{AppConfigExt, GameplayConfig},
+};
+
+/// Who controls the paddles.
+#[derive(
+    Resource, clap::ValueEnum, Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize,
+)]
+pub enum GameMode {
+    /// Player 1 against the computer.
+    #[value(name = "1p")]
+    OnePlayer,
+    #[default]
+    #[value(name = "2p")]
+    TwoPlayer,
+    /// The computer against itself.
+    #[value(name = "ai")]
+    AiVsAi,
+    /// Player 1 alone, against a wall. See `survival`.
+    #[value(name = "survival")]
+    Survival,
+    /// Both players on one side, against the computer. See `coop`.
+    #[value(name = "coop")]
+    Coop,
+}
+
+impl GameMode {
+    fn is_ai(self, player: Player) -> bool {
+        match self {
+            GameMode::OnePlayer | GameMode::Coop => player == Player::Player2,
+            GameMode::TwoPlayer | GameMode::Survival => false,
+            GameMode::AiVsAi => true,
+        }
+    }
+}
+
+/// How well the computer plays. Each level's numbers come from `assets/config/default.ai.json`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum AiDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl AiDifficulty {
+    const ALL: [AiDifficulty; 3] = [AiDifficulty::Easy, AiDifficulty::Normal, AiDifficulty::Hard];
+
+    fn name(self) -> &'static str {
+        match self {
+            AiDifficulty::Easy => "ai-easy",
+            AiDifficulty::Normal => "ai-normal",
+            AiDifficulty::Hard => "ai-hard",
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|d| *d == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AiProfile {
+    /// Fraction of a player's paddle speed.
+    pub speed: f32,
+    /// How far off the ball's centre it may aim, so it doesn't play perfectly.
+    pub aim_error: f32,
+}
+
+#[derive(Asset, Resource, TypePath, Clone, Debug, Deserialize)]
+pub struct AiProfiles {
+    pub easy: AiProfile,
+    pub normal: AiProfile,
+    pub hard: AiProfile,
+}
+
+impl Default for AiProfiles {
+    fn default() -> Self {
+        AiProfiles {
+            easy: AiProfile {
+                speed: 0.6,
+                aim_error: 70.0,
+            },
+            normal: AiProfile {
+                speed: 1.0,
+                aim_error: 40.0,
+            },
+            hard: AiProfile {
+                speed: 1.3,
+                aim_error: 15.0,
+            },
+        }
+    }
+}
+
+impl AiProfiles {
+    fn get(&self, difficulty: AiDifficulty) -> &AiProfile {
+        match difficulty {
+            AiDifficulty::Easy => &self.easy,
+            AiDifficulty::Normal => &self.normal,
+            AiDifficulty::Hard => &self.hard,
+        }
+    }
+}
+
+/// Set by modes that choose the opponent themselves, such as the campaign. `None` uses the
+/// difficulty from the settings.
+#[derive(Resource, Default)]
+pub struct OpponentDifficulty(pub Option<AiDifficulty>);
+
+/// A paddle steered by the computer instead of the keyboard.
+#[derive(Component, Default)]
+pub struct AiPaddle {
+    /// Re-rolled every time the ball turns towards this paddle.
+    aim_error: f32,
+    ball_incoming: bool,
+}
+
+#[cfg(feature = "dev")]
+impl AiPaddle {
+    /// For the AI telemetry panel.
+    pub fn aim_error(&self) -> f32 {
+        self.aim_error
+    }
+
+    pub fn ball_incoming(&self) -> bool {
+        self.ball_incoming
+    }
+}
+
+pub struct AiPlugin;
+
+impl Plugin for AiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameMode>();
+        app.init_resource::<OpponentDifficulty>();
+        app.add_config::<AiProfiles>("config/default.ai.json");
+        app.add_setting_row(SettingRow {
+            label: "setting-ai-difficulty",
+            value: |s, l| l.text(s.ai_difficulty.name()),
+            change: |s, step| s.ai_difficulty = s.ai_difficulty.cycle(step),
+        });
+        app.add_systems(
+            Update,
+            (
+                assign_ai,
+                steer_ai_paddles.run_if(in_state(GameState::Playing)),
+            )
+                .chain(),
+        );
+    }
+}
+
+fn assign_ai(
+    mut commands: Commands,
+    mode: Res<GameMode>,
+    paddles: Query<(Entity, &Player), Added<Paddle>>,
+) {
+    for (entity, player) in &paddles {
+        if mode.is_ai(*player) {
+            commands.entity(entity).insert(AiPaddle::default());
+        }
+    }
+}
+
+/// Follows the ball while it is coming this way and drifts back to the middle otherwise,
+/// at the profile's share of a player's paddle speed.
+#[allow(clippy::too_many_arguments)]
+fn steer_ai_paddles(
+    balls: Query<(&Transform, &Velocity), With<Ball>>,
+    mut paddles: Query<(&Transform, &mut Velocity, &mut AiPaddle, &Player), Without<Ball>>,
+    arena: Res<Arena>,
+    time: Res<Time>,
+    mut rng: ResMut<GameRng>,
+    settings: Res<Settings>,
+    difficulty: Res<OpponentDifficulty>,
+    profiles: Res<AiProfiles>,
+    config: Res<GameplayConfig>,
+) {
+    let profile = profiles.get(difficulty.0.unwrap_or(settings.ai_difficulty));
+    let Some((ball, velocity)) = balls.iter().next() else {
+        return;
+    };
+    for (paddle, mut movement, mut ai, player) in &mut paddles {
+        let incoming = (ball.translation.x - paddle.translation.x) * velocity.linvel.x < 0.0;
+        if incoming && !ai.ball_incoming {
+            ai.aim_error = rng.gen_range(-profile.aim_error..=profile.aim_error);
+        }
+        ai.ball_incoming = incoming;
+
+        let target = if incoming {
+            ball.translation.y + ai.aim_error
+        } else {
+            0.0
+        };
+        let step = profile.speed
+            * config.paddle_speed
+            * arena.scale
+            * settings.handicaps.get(*player).speed_factor()
+            * time.delta_seconds();
+        let y = paddle.translation.y + (target - paddle.translation.y).clamp(-step, step);
+        movement.linvel = paddle_velocity(&arena, paddle, y, time.delta_seconds());
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::{Ball, Player, PointScored, ai::AiPaddle, arena::Arena, fonts::FontStack, hits};
+
+/// Chances kept for the graphs and averages.
+const HISTORY: usize = 40;
+/// How close to where it is aiming a paddle has to get to count as in position.
+const POSITION_TOLERANCE: f32 = 12.0;
+const BAR_WIDTH: f32 = 5.0;
+const GRAPH_HEIGHT: f32 = 36.0;
+const AIM_COLOUR: Color = Color::srgb(0.95, 0.75, 0.3);
+const REACTION_COLOUR: Color = Color::srgb(0.4, 0.7, 1.0);
+const HIT_COLOUR: Color = Color::srgb(0.3, 0.85, 0.4);
+const MISS_COLOUR: Color = Color::srgb(0.9, 0.3, 0.25);
+
+/// A ball on its way to a computer paddle, from the moment it turned that way.
+struct Chance {
+    player: Player,
+    started: f32,
+    aim_error: f32,
+    in_position: Option<f32>,
+}
+
+/// How a chance ended up.
+#[derive(Clone, Copy)]
+struct Outcome {
+    aim_error: f32,
+    /// Seconds to get in position, if it ever did.
+    reaction: Option<f32>,
+    intercepted: bool,
+}
+
+#[derive(Resource, Default)]
+struct AiTelemetry {
+    open: Vec<Chance>,
+    outcomes: VecDeque<Outcome>,
+}
+
+impl AiTelemetry {
+    fn close(&mut self, player: Player, intercepted: bool) {
+        let Some(index) = self.open.iter().position(|chance| chance.player == player) else {
+            return;
+        };
+        let chance = self.open.swap_remove(index);
+        if self.outcomes.len() == HISTORY {
+            self.outcomes.pop_front();
+        }
+        self.outcomes.push_back(Outcome {
+            aim_error: chance.aim_error.abs(),
+            reaction: chance.in_position.map(|at| at - chance.started),
+            intercepted,
+        });
+    }
+}
+
+/// Which graph a bar belongs to, and its place along it, oldest first.
+#[derive(Component, Clone, Copy)]
+enum GraphBar {
+    AimError(usize),
+    Reaction(usize),
+    Intercept(usize),
+}
+
+#[derive(Component)]
+struct TelemetryPanel;
+
+#[derive(Component)]
+struct TelemetryText;
+
+/// F4 in dev builds: graphs of how the computer has been playing over its last 40
+/// chances, taken from the AI's own state. For each ball sent its way: how far off centre
+/// it chose to aim, how long it took to get in position, and whether it got the ball back,
+/// with the averages and the miss rate alongside. For tuning `config/default.ai.json` on
+/// numbers rather than feel.
+pub struct AiTelemetryPlugin;
+
+impl Plugin for AiTelemetryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AiTelemetry>();
+        app.add_systems(Startup, spawn_panel);
+        app.add_systems(
+            Update,
+            (
+                record_chances.after(hits::detect_hits),
+                toggle_panel,
+                draw_panel,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Hits and goals settle a chance first, so a ball that has just been sent back isn't
+/// mistaken for a new one.
+fn record_chances(
+    time: Res<Time>,
+    arena: Res<Arena>,
+    mut hits: EventReader<hits::BallHitPaddle>,
+    mut points: EventReader<PointScored>,
+    balls: Query<&Transform, With<Ball>>,
+    paddles: Query<(&Transform, &AiPaddle, &Player)>,
+    mut telemetry: ResMut<AiTelemetry>,
+) {
+    for hit in hits.read() {
+        telemetry.close(hit.player, true);
+    }
+    for point in points.read() {
+        telemetry.close(point.conceder, false);
+    }
+
+    let Some(ball) = balls.iter().next() else {
+        return;
+    };
+    let now = time.elapsed_seconds();
+    for (paddle, ai, player) in &paddles {
+        let open = telemetry
+            .open
+            .iter_mut()
+            .find(|chance| chance.player == *player);
+        match open {
+            Some(chance) if ai.ball_incoming() => {
+                let target = ball.translation.y + chance.aim_error;
+                let off = (paddle.translation.y - target).abs();
+                if chance.in_position.is_none() && off <= POSITION_TOLERANCE * arena.scale {
+                    chance.in_position = Some(now);
+                }
+            }
+            Some(_) => {}
+            None if ai.ball_incoming() => telemetry.open.push(Chance {
+                player: *player,
+                started: now,
+                aim_error: ai.aim_error(),
+                in_position: None,
+            }),
+            None => {}
+        }
+    }
+}
+
+fn spawn_panel(mut commands: Commands, fonts: Res<FontStack>) {
+    let graph = |p: &mut ChildBuilder, bar: fn(usize) -> GraphBar| {
+        p.spawn(NodeBundle {
+            style: Style {
+                height: Val::Px(GRAPH_HEIGHT),
+                align_items: AlignItems::End,
+                column_gap: Val::Px(1.0),
+                ..Default::default()
+            },
+            background_color: Color::srgba(1.0, 1.0, 1.0, 0.08).into(),
+            ..Default::default()
+        })
+        .with_children(|p| {
+            for index in 0..HISTORY {
+                p.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(BAR_WIDTH),
+                            height: Val::Percent(0.0),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    bar(index),
+                ));
+            }
+        });
+    };
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(120.0),
+                    left: Val::Px(8.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.6).into(),
+                visibility: Visibility::Hidden,
+                ..Default::default()
+            },
+            TelemetryPanel,
+        ))
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 14.0), TelemetryText));
+            graph(p, GraphBar::AimError);
+            graph(p, GraphBar::Reaction);
+            graph(p, GraphBar::Intercept);
+        });
+}
+
+fn toggle_panel(
+    input: Res<ButtonInput<KeyCode>>,
+    mut panels: Query<&mut Visibility, With<TelemetryPanel>>,
+) {
+    if !input.just_pressed(KeyCode::F4) {
+        return;
+    }
+    for mut visibility in &mut panels {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Visible,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+fn average(values: impl Iterator<Item = f32>) -> Option<f32> {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), value| (sum + value, count + 1));
+    (count > 0).then(|| sum / count as f32)
+}
+
+/// Each graph is scaled to its own largest value. A chance where the paddle never got in
+/// position has no reaction bar.
+fn draw_panel(
+    telemetry: Res<AiTelemetry>,
+    fonts: Res<FontStack>,
+    panels: Query<&Visibility, With<TelemetryPanel>>,
+    mut texts: Query<&mut Text, With<TelemetryText>>,
+    mut bars: Query<(&GraphBar, &mut Style, &mut BackgroundColor)>,
+) {
+    if panels
+        .iter()
+        .all(|visibility| *visibility == Visibility::Hidden)
+    {
+        return;
+    }
+    let outcomes = &telemetry.outcomes;
+    let misses = outcomes
+        .iter()
+        .filter(|outcome| !outcome.intercepted)
+        .count();
+    let aim = average(outcomes.iter().map(|outcome| outcome.aim_error));
+    let reaction = average(outcomes.iter().filter_map(|outcome| outcome.reaction));
+    let lines = format!(
+        "AI over the last {} chances\nAim error: {:.0} px avg\nTime to position: {:.0} ms avg\nIntercepts: {:.0}%, misses {:.0}%",
+        outcomes.len(),
+        aim.unwrap_or_default(),
+        reaction.unwrap_or_default() * 1000.0,
+        100.0 - misses as f32 * 100.0 / outcomes.len().max(1) as f32,
+        misses as f32 * 100.0 / outcomes.len().max(1) as f32,
+    );
+    for mut text in &mut texts {
+        fonts.set(&mut text, &lines);
+    }
+
+    let largest = |value: fn(&Outcome) -> Option<f32>| {
+        outcomes
+            .iter()
+            .filter_map(value)
+            .fold(f32::EPSILON, f32::max)
+    };
+    let aim_error = |outcome: &Outcome| Some(outcome.aim_error);
+    let reaction = |outcome: &Outcome| outcome.reaction;
+    let (largest_aim, largest_reaction) = (largest(aim_error), largest(reaction));
+    for (bar, mut style, mut colour) in &mut bars {
+        let (GraphBar::AimError(index) | GraphBar::Reaction(index) | GraphBar::Intercept(index)) =
+            *bar;
+        let Some(outcome) = outcomes.get(index) else {
+            style.height = Val::Percent(0.0);
+            continue;
+        };
+        let (share, shade) = match bar {
+            GraphBar::AimError(_) => (outcome.aim_error / largest_aim, AIM_COLOUR),
+            GraphBar::Reaction(_) => (
+                outcome.reaction.unwrap_or_default() / largest_reaction,
+                REACTION_COLOUR,
+            ),
+            GraphBar::Intercept(_) if outcome.intercepted => (1.0, HIT_COLOUR),
+            GraphBar::Intercept(_) => (1.0, MISS_COLOUR),
+        };
+        style.height = Val::Percent(share * 100.0);
+        colour.0 = shade;
+    }
+}
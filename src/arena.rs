@@ -0,0 +1,266 @@
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowResized, WindowScaleFactorChanged},
+};
+use bevy_rapier2d::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BALL_RADIUS, Ball, GameEvents, GameState, Paddle, Player, WINDOW_HEIGHT, WINDOW_WIDTH, Wall,
+    court,
+    menu::{AppSettingsExt, SettingRow},
+    rng::GameRng,
+    settings::Settings,
+};
+
+/// Gap between a paddle and the goal line behind it.
+const PADDLE_INSET: f32 = 20.0;
+
+/// How big the court is for the window, chosen in the settings menu. Paddles and the ball
+/// keep their size, so a bigger court has more room in it; the camera zooms out to fit.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum CourtSize {
+    Small,
+    #[default]
+    Standard,
+    Large,
+}
+
+impl CourtSize {
+    const ALL: [CourtSize; 3] = [CourtSize::Small, CourtSize::Standard, CourtSize::Large];
+
+    fn name(self) -> &'static str {
+        match self {
+            CourtSize::Small => "court-small",
+            CourtSize::Standard => "court-standard",
+            CourtSize::Large => "court-large",
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|s| *s == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+
+    fn scale(self) -> f32 {
+        match self {
+            CourtSize::Small => 0.8,
+            CourtSize::Standard => 1.0,
+            CourtSize::Large => 1.25,
+        }
+    }
+}
+
+/// The playing field, centred on the origin. It follows the window, so it can change
+/// size in the middle of a match. The court is always wider than it is tall; in a
+/// portrait window it is turned on its side instead of being squashed.
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct Arena {
+    pub width: f32,
+    pub height: f32,
+    /// The window is taller than it is wide, so the camera is rotated a quarter turn.
+    pub portrait: bool,
+    /// World units per logical pixel of the window, from the court size. Paddle and serve
+    /// speeds are multiplied by it, so crossing the court takes as long at any size.
+    pub scale: f32,
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Arena {
+            width: WINDOW_WIDTH,
+            height: WINDOW_HEIGHT,
+            portrait: false,
+            scale: 1.0,
+        }
+    }
+}
+
+impl Arena {
+    pub fn half_width(&self) -> f32 {
+        self.width / 2.0
+    }
+
+    pub fn half_height(&self) -> f32 {
+        self.height / 2.0
+    }
+
+    /// Horizontal position of a player's paddle.
+    pub fn paddle_x(&self, player: Player) -> f32 {
+        match player {
+            Player::Player1 => -self.half_width() + PADDLE_INSET,
+            Player::Player2 => self.half_width() - PADDLE_INSET,
+        }
+    }
+
+    /// Horizontal position of the goal line `scorer` scores on.
+    pub fn goal_x(&self, scorer: Player) -> f32 {
+        match scorer {
+            Player::Player1 => self.half_width(),
+            Player::Player2 => -self.half_width(),
+        }
+    }
+
+    /// How far the camera is turned, in radians, to fit the court in the window.
+    pub fn view_angle(&self) -> f32 {
+        if self.portrait {
+            std::f32::consts::FRAC_PI_2
+        } else {
+            0.0
+        }
+    }
+
+    /// Keeps something `half_height` tall between the walls.
+    pub fn clamp_y(&self, y: f32, half_height: f32) -> f32 {
+        let limit = (self.half_height() - half_height).max(0.0);
+        y.clamp(-limit, limit)
+    }
+}
+
+pub struct ArenaPlugin;
+
+impl Plugin for ArenaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Arena>();
+        app.add_setting_row(SettingRow {
+            label: "setting-court-size",
+            value: |s, l| l.text(s.court_size.name()),
+            change: |s, step| s.court_size = s.court_size.cycle(step),
+        });
+        app.add_systems(PreUpdate, follow_window);
+        app.add_systems(
+            Update,
+            (
+                fit_walls,
+                fit_goals,
+                fit_paddles,
+                keep_ball_inside,
+                orient_camera,
+            )
+                .run_if(resource_changed::<Arena>),
+        );
+        app.add_systems(
+            Update,
+            catch_escaped_balls
+                .after(keep_ball_inside)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// The arena is measured in logical pixels, like the camera, so moving between monitors
+/// with different scale factors keeps everything the same size on screen. The logical
+/// size can still change when the scale factor does, so both events are watched. The
+/// court size setting then scales it; without a window, as in tests, the default window
+/// size is scaled instead.
+fn follow_window(
+    mut resized: EventReader<WindowResized>,
+    mut rescaled: EventReader<WindowScaleFactorChanged>,
+    settings: Res<Settings>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut arena: ResMut<Arena>,
+) {
+    // Count rather than `is_empty` so both readers are drained.
+    if resized.read().count() + rescaled.read().count() == 0 && !settings.is_changed() {
+        return;
+    }
+    let (width, height) = match windows.get_single() {
+        Ok(window) => (window.width(), window.height()),
+        Err(_) => (WINDOW_WIDTH, WINDOW_HEIGHT),
+    };
+    // A minimised window reports zero size; keep the last real one.
+    if width <= 0.0 || height <= 0.0 {
+        return;
+    }
+    let scale = settings.court_size.scale();
+    arena.set_if_neq(Arena {
+        width: width.max(height) * scale,
+        height: width.min(height) * scale,
+        portrait: height > width,
+        scale,
+    });
+}
+
+pub fn orient_camera(arena: Res<Arena>, mut cameras: Query<&mut Transform, With<Camera2d>>) {
+    for mut transform in &mut cameras {
+        transform.rotation = Quat::from_rotation_z(arena.view_angle());
+    }
+}
+
+fn fit_walls(arena: Res<Arena>, mut walls: Query<(&mut Transform, &mut Collider), With<Wall>>) {
+    for (mut transform, mut collider) in &mut walls {
+        let side = transform.translation.y.signum();
+        (*transform, *collider) = court::wall(arena.half_width(), arena.half_height(), side);
+    }
+}
+
+/// Goals are usually sensors, but some modes close one off, so match on everything with
+/// a collider and a player that isn't a paddle.
+fn fit_goals(
+    arena: Res<Arena>,
+    mut goals: Query<(&mut Transform, &mut Collider, &Player), Without<Paddle>>,
+) {
+    for (mut transform, mut collider, player) in &mut goals {
+        let side = arena.goal_x(*player).signum();
+        (*transform, *collider) = court::goal_line(arena.half_width(), arena.half_height(), side);
+    }
+}
+
+pub fn fit_paddles(arena: Res<Arena>, mut paddles: Query<(&mut Transform, &Player), With<Paddle>>) {
+    for (mut transform, player) in &mut paddles {
+        let half_height = 75.0 * transform.scale.y;
+        transform.translation.x = arena.paddle_x(*player);
+        transform.translation.y = arena.clamp_y(transform.translation.y, half_height);
+    }
+}
+
+/// A shrinking window must not leave the ball outside the walls.
+fn keep_ball_inside(arena: Res<Arena>, mut balls: Query<&mut Transform, With<Ball>>) {
+    for mut transform in &mut balls {
+        let radius = BALL_RADIUS * transform.scale.x;
+        let limit_x = (arena.half_width() - radius).max(0.0);
+        transform.translation.x = transform.translation.x.clamp(-limit_x, limit_x);
+        transform.translation.y = arena.clamp_y(transform.translation.y, radius);
+    }
+}
+
+/// A ball that got through a wall or past a goal without scoring, or was spawned somewhere
+/// it shouldn't be, would never come back. Every ball is served again, to whoever the
+/// coin toss picks, and nobody gets a point. A ball touching a goal is scoring, which
+/// `detect_reset` handles.
+fn catch_escaped_balls(
+    arena: Res<Arena>,
+    balls: Query<(&Transform, &CollidingEntities), With<Ball>>,
+    goals: Query<(), (With<Sensor>, With<Player>)>,
+    mut rng: ResMut<GameRng>,
+    mut game_events: EventWriter<GameEvents>,
+) {
+    let escaped = balls.iter().find_map(|(ball, touching)| {
+        if touching.iter().any(|entity| goals.contains(entity)) {
+            return None;
+        }
+        let position = ball.translation.truncate();
+        // A fast ball can be well inside the goal on the frame it scores, so only one
+        // wholly past the goal's far edge has slipped by.
+        let limit_x =
+            arena.half_width() + 2.0 * court::BOUNDARY_HALF_THICKNESS + BALL_RADIUS * ball.scale.x;
+        let escaped = !position.is_finite()
+            || position.x.abs() > limit_x
+            || position.y.abs() > arena.half_height();
+        escaped.then_some(ball)
+    });
+    let Some(ball) = escaped else {
+        return;
+    };
+    warn!(
+        "ball escaped the court at {:?}, serving again",
+        ball.translation.truncate()
+    );
+    let server = if rng.gen_bool(0.5) {
+        Player::Player1
+    } else {
+        Player::Player2
+    };
+    game_events.send(GameEvents::ResetBall(server));
+}
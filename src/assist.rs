@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Ball, Paddle, Player,
+    arena::Arena,
+    magnet::MagnetStrength,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    mutators::ActiveMutators,
+    settings::Settings,
+};
+
+const LARGE_BALL_SCALE: f32 = 1.5;
+const LARGE_PADDLE_SCALE: f32 = 1.4;
+const SLOW_BALL_MAX_SPEED: f32 = 400.0;
+
+/// Accessibility assists. Paddle size is per player so a stronger player can leave theirs alone.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Assists {
+    pub large_ball: bool,
+    pub slow_ball: bool,
+    pub large_paddle_p1: bool,
+    pub large_paddle_p2: bool,
+    /// Draw where the ball is going in practice modes. See `trajectory`.
+    pub trajectory: bool,
+    /// Pull the ball toward players' paddles. See `magnet`.
+    pub magnet: MagnetStrength,
+}
+
+impl Assists {
+    fn large_paddle(&self, player: Player) -> bool {
+        match player {
+            Player::Player1 => self.large_paddle_p1,
+            Player::Player2 => self.large_paddle_p2,
+        }
+    }
+}
+
+pub struct AssistPlugin;
+
+impl Plugin for AssistPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-large-ball",
+            value: |s, l| on_off(l, s.assist.large_ball),
+            change: |s, _| s.assist.large_ball = !s.assist.large_ball,
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-slow-ball",
+            value: |s, l| on_off(l, s.assist.slow_ball),
+            change: |s, _| s.assist.slow_ball = !s.assist.slow_ball,
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-large-paddle-p1",
+            value: |s, l| on_off(l, s.assist.large_paddle_p1),
+            change: |s, _| s.assist.large_paddle_p1 = !s.assist.large_paddle_p1,
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-large-paddle-p2",
+            value: |s, l| on_off(l, s.assist.large_paddle_p2),
+            change: |s, _| s.assist.large_paddle_p2 = !s.assist.large_paddle_p2,
+        });
+        app.add_systems(
+            Update,
+            (
+                apply_sizes.run_if(
+                    resource_changed::<Settings>.or_else(resource_changed::<ActiveMutators>),
+                ),
+                limit_ball_speed,
+            ),
+        );
+    }
+}
+
+/// The ball's size from the large-ball assist and the ball type in play.
+pub fn ball_scale(settings: &Settings, mutators: &ActiveMutators) -> f32 {
+    let assist = if settings.assist.large_ball {
+        LARGE_BALL_SCALE
+    } else {
+        1.0
+    };
+    assist * mutators.ball_type().scale()
+}
+
+/// Sizes are applied through the transform scale, which Rapier also applies to the colliders.
+fn apply_sizes(
+    settings: Res<Settings>,
+    mutators: Res<ActiveMutators>,
+    mut balls: Query<&mut Transform, (With<Ball>, Without<Paddle>)>,
+    mut paddles: Query<(&mut Transform, &Player), With<Paddle>>,
+) {
+    let ball_scale = ball_scale(&settings, &mutators);
+    for mut ball in &mut balls {
+        ball.scale = Vec3::new(ball_scale, ball_scale, 1.0);
+    }
+
+    // Handicaps change the size on top of the assist, and the assist on top of any mutator.
+    for (mut paddle, player) in &mut paddles {
+        let assist = if settings.assist.large_paddle(*player) {
+            LARGE_PADDLE_SCALE
+        } else {
+            1.0
+        };
+        paddle.scale.y =
+            mutators.paddle_scale() * assist * settings.handicaps.get(*player).size_factor();
+    }
+}
+
+fn limit_ball_speed(
+    settings: Res<Settings>,
+    arena: Res<Arena>,
+    mut balls: Query<&mut Velocity, With<Ball>>,
+) {
+    if !settings.assist.slow_ball {
+        return;
+    }
+    for mut velocity in &mut balls {
+        velocity.linvel = velocity
+            .linvel
+            .clamp_length_max(SLOW_BALL_MAX_SPEED * arena.scale);
+    }
+}
@@ -0,0 +1,112 @@
+use bevy::{asset::LoadState, prelude::*, utils::HashMap};
+
+/// Space around each image in the atlas, so scaled sprites don't pick up their neighbours'
+/// edges.
+const PADDING: u32 = 2;
+
+/// Cosmetic sprite images packed into one texture, so a court full of skinned sprites is
+/// drawn in one batch instead of one per image. That matters most on integrated GPUs and
+/// in the browser.
+///
+/// Plugins [`want`](SpriteAtlas::want) their images by asset path. Once all of them have
+/// loaded they are packed together, and [`get`](SpriteAtlas::get) has the atlas texture
+/// and the section to draw. Until then, and for anything that didn't fit, sprites use
+/// their image on its own as before.
+#[derive(Resource, Default)]
+pub struct SpriteAtlas {
+    wanted: Vec<(String, Handle<Image>)>,
+    /// Whether `wanted` has changed since the last packing.
+    stale: bool,
+    packed: Option<Packed>,
+}
+
+struct Packed {
+    image: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+    sections: HashMap<String, usize>,
+}
+
+impl SpriteAtlas {
+    /// Adds `path` to the next packing.
+    pub fn want(&mut self, path: &str, asset_server: &AssetServer) {
+        if self.wanted.iter().all(|(wanted, _)| wanted != path) {
+            self.wanted
+                .push((path.to_string(), asset_server.load(path.to_string())));
+            self.stale = true;
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Option<(Handle<Image>, TextureAtlas)> {
+        let packed = self.packed.as_ref()?;
+        let index = *packed.sections.get(path)?;
+        Some((
+            packed.image.clone(),
+            TextureAtlas {
+                layout: packed.layout.clone(),
+                index,
+            },
+        ))
+    }
+}
+
+pub struct AtlasPlugin;
+
+impl Plugin for AtlasPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpriteAtlas>();
+        app.add_systems(Update, pack_atlas);
+    }
+}
+
+/// Waits for every wanted image, leaving out any that failed to load.
+pub fn pack_atlas(
+    mut atlas: ResMut<SpriteAtlas>,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    if !atlas.stale {
+        return;
+    }
+    let loading = atlas.wanted.iter().any(|(_, handle)| {
+        !images.contains(handle)
+            && !matches!(
+                asset_server.get_load_state(handle),
+                Some(LoadState::Failed(_))
+            )
+    });
+    if loading {
+        return;
+    }
+
+    let mut builder = TextureAtlasBuilder::default();
+    builder.padding(UVec2::splat(PADDING));
+    let mut packed = Vec::new();
+    for (path, handle) in &atlas.wanted {
+        if let Some(image) = images.get(handle) {
+            builder.add_texture(Some(handle.id()), image);
+            packed.push(path.clone());
+        }
+    }
+    let built = builder.build();
+    atlas.stale = false;
+    let (layout, image) = match built {
+        Ok(built) => built,
+        Err(err) => {
+            warn!("could not pack sprite atlas, drawing images separately: {err}");
+            atlas.packed = None;
+            return;
+        }
+    };
+    // Insertion order is index order.
+    let sections = packed
+        .into_iter()
+        .enumerate()
+        .map(|(index, path)| (path, index))
+        .collect();
+    atlas.packed = Some(Packed {
+        image: images.add(image),
+        layout: layouts.add(layout),
+        sections,
+    });
+}
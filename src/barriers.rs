@@ -0,0 +1,235 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    GameState, Paddle, Player,
+    ai::{AiPaddle, GameMode},
+    menu::{AppSettingsExt, SettingRow, on_off},
+    motion::MotionPreferences,
+    settings::Settings,
+};
+
+const BARRIER_SIZE: Vec2 = Vec2::new(12.0, 120.0);
+/// How far in front of the paddle a barrier goes.
+const BARRIER_DISTANCE: f32 = 180.0;
+const LIFETIME_SECONDS: f32 = 3.0;
+/// The last part of a barrier's life, when it blinks and shrinks away.
+const EXPIRY_SECONDS: f32 = 0.75;
+const COOLDOWN_SECONDS: f32 = 15.0;
+const PREVIEW_ALPHA: f32 = 0.35;
+const UNAVAILABLE_COLOUR: Color = Color::srgba(0.5, 0.5, 0.5, PREVIEW_ALPHA);
+
+/// Which game modes allow barriers. The computer never places any.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Barriers {
+    pub one_player: bool,
+    pub two_player: bool,
+    pub survival: bool,
+}
+
+impl Default for Barriers {
+    fn default() -> Self {
+        Barriers {
+            one_player: true,
+            two_player: true,
+            survival: true,
+        }
+    }
+}
+
+impl Barriers {
+    fn allowed(&self, mode: GameMode) -> bool {
+        match mode {
+            GameMode::OnePlayer => self.one_player,
+            GameMode::TwoPlayer => self.two_player,
+            GameMode::Survival => self.survival,
+            GameMode::AiVsAi | GameMode::Coop => false,
+        }
+    }
+}
+
+/// A player's barrier ability: whether it is recharging, and the preview while the key
+/// is held.
+#[derive(Component)]
+struct BarrierAbility {
+    cooldown: Timer,
+    preview: Option<Entity>,
+}
+
+impl Default for BarrierAbility {
+    fn default() -> Self {
+        let mut cooldown = Timer::from_seconds(COOLDOWN_SECONDS, TimerMode::Once);
+        cooldown.tick(cooldown.duration());
+        BarrierAbility {
+            cooldown,
+            preview: None,
+        }
+    }
+}
+
+/// Where a barrier would go, shown while the key is held.
+#[derive(Component)]
+struct BarrierPreview;
+
+type Preview = (With<BarrierPreview>, Without<Paddle>);
+/// Paddles a player steers. Must stay disjoint from `Preview`, as both touch `Transform`.
+type PlayerPaddle = (With<Paddle>, Without<AiPaddle>);
+
+/// A placed barrier, and how long it has left.
+#[derive(Component)]
+struct Barrier(Timer);
+
+/// Hold the barrier key to see where a short wall would go, in front of your paddle, and
+/// let go to drop it. It blocks shots for a few seconds, then there is a long wait before
+/// the next one. Each game mode can turn barriers off in the settings menu.
+pub struct BarriersPlugin;
+
+impl Plugin for BarriersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-barriers-1p",
+            value: |s, l| on_off(l, s.barriers.one_player),
+            change: |s, _| s.barriers.one_player = !s.barriers.one_player,
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-barriers-2p",
+            value: |s, l| on_off(l, s.barriers.two_player),
+            change: |s, _| s.barriers.two_player = !s.barriers.two_player,
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-barriers-survival",
+            value: |s, l| on_off(l, s.barriers.survival),
+            change: |s, _| s.barriers.survival = !s.barriers.survival,
+        });
+        app.add_systems(
+            Update,
+            (
+                add_ability,
+                place_barriers.run_if(in_state(GameState::Playing).and_then(barriers_allowed)),
+                expire_barriers.run_if(in_state(GameState::Playing)),
+            )
+                .chain(),
+        );
+    }
+}
+
+fn barriers_allowed(settings: Res<Settings>, mode: Res<GameMode>) -> bool {
+    settings.barriers.allowed(*mode)
+}
+
+fn add_ability(mut commands: Commands, paddles: Query<Entity, Added<Paddle>>) {
+    for paddle in &paddles {
+        // The paddle may be gone already; survival despawns Player 2's.
+        commands
+            .entity(paddle)
+            .try_insert(BarrierAbility::default());
+    }
+}
+
+/// Where a barrier would go for a paddle at `paddle`.
+fn barrier_position(paddle: Vec3) -> Vec3 {
+    let into_court = -paddle.x.signum();
+    Vec3::new(paddle.x + into_court * BARRIER_DISTANCE, paddle.y, -0.4)
+}
+
+fn place_barriers(
+    mut commands: Commands,
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    mut paddles: Query<(&Transform, &Player, &mut BarrierAbility), PlayerPaddle>,
+    mut previews: Query<(&mut Transform, &mut Sprite), Preview>,
+) {
+    for (paddle, player, mut ability) in &mut paddles {
+        ability.cooldown.tick(time.delta());
+        let key = settings.bindings.barrier(*player);
+        let position = barrier_position(paddle.translation);
+        let ready = ability.cooldown.finished();
+        let colour = if ready {
+            settings.player_colour(*player)
+        } else {
+            UNAVAILABLE_COLOUR
+        };
+
+        if keys.pressed(key) {
+            match ability
+                .preview
+                .and_then(|preview| previews.get_mut(preview).ok())
+            {
+                Some((mut transform, mut sprite)) => {
+                    transform.translation = position;
+                    sprite.color = colour.with_alpha(PREVIEW_ALPHA);
+                }
+                None => {
+                    let preview = commands
+                        .spawn((
+                            SpriteBundle {
+                                transform: Transform::from_translation(position),
+                                sprite: Sprite {
+                                    color: colour.with_alpha(PREVIEW_ALPHA),
+                                    custom_size: Some(BARRIER_SIZE),
+                                    ..Default::default()
+                                },
+                                ..Default::default()
+                            },
+                            BarrierPreview,
+                        ))
+                        .id();
+                    ability.preview = Some(preview);
+                }
+            }
+            continue;
+        }
+
+        if let Some(preview) = ability.preview.take() {
+            commands.entity(preview).despawn();
+        }
+        if !keys.just_released(key) || !ready {
+            continue;
+        }
+        commands.spawn((
+            SpriteBundle {
+                transform: Transform::from_translation(position),
+                sprite: Sprite {
+                    color: colour,
+                    custom_size: Some(BARRIER_SIZE),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            RigidBody::Fixed,
+            Collider::cuboid(BARRIER_SIZE.x / 2.0, BARRIER_SIZE.y / 2.0),
+            Barrier(Timer::from_seconds(LIFETIME_SECONDS, TimerMode::Once)),
+        ));
+        ability.cooldown.reset();
+    }
+}
+
+/// Near the end a barrier blinks and shrinks to nothing; with reduced motion it just fades.
+fn expire_barriers(
+    mut commands: Commands,
+    time: Res<Time>,
+    motion: Res<MotionPreferences>,
+    mut barriers: Query<(Entity, &mut Barrier, &mut Transform, &mut Sprite)>,
+) {
+    for (entity, mut barrier, mut transform, mut sprite) in &mut barriers {
+        if barrier.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let left = barrier.0.remaining_secs();
+        if left > EXPIRY_SECONDS {
+            continue;
+        }
+        let fraction = left / EXPIRY_SECONDS;
+        if motion.reduced {
+            sprite.color.set_alpha(fraction);
+        } else {
+            let blink = (left * 20.0).sin() > 0.0;
+            sprite.color.set_alpha(if blink { 1.0 } else { 0.4 });
+            transform.scale.y = fraction;
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use bevy::{
+    core_pipeline::{
+        core_2d::graph::{Core2d, Node2d},
+        fullscreen_vertex_shader::fullscreen_shader_vertex_state,
+    },
+    ecs::query::QueryItem,
+    prelude::*,
+    render::{
+        RenderApp,
+        extract_component::{ExtractComponent, ExtractComponentPlugin},
+        render_graph::{
+            NodeRunError, RenderGraphApp, RenderGraphContext, RenderLabel, ViewNode, ViewNodeRunner,
+        },
+        render_resource::{
+            binding_types::{sampler, texture_2d},
+            *,
+        },
+        renderer::{RenderContext, RenderDevice},
+        texture::BevyDefault,
+        view::ViewTarget,
+    },
+};
+
+use crate::{
+    GameState,
+    camera::MainCamera,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    settings::Settings,
+};
+
+/// The blur's strength is set in the shader.
+const SHADER_PATH: &str = "shaders/blur.wgsl";
+
+/// Blurs the court under the camera it is on. Only there while paused with the setting on.
+#[derive(Component, Clone, Copy, ExtractComponent)]
+struct Blur;
+
+/// With the setting on, the court is blurred behind the pause menu. It is a post-process
+/// pass on the main camera, after tonemapping and before the UI is drawn, so the menu
+/// itself stays sharp.
+pub struct BlurPlugin;
+
+impl Plugin for BlurPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-pause-blur",
+            value: |s, l| on_off(l, s.pause_blur),
+            change: |s, _| s.pause_blur = !s.pause_blur,
+        });
+        app.add_plugins(ExtractComponentPlugin::<Blur>::default());
+        app.add_systems(Update, toggle_blur);
+
+        // Headless runs have no renderer.
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<BlurNode>>(Core2d, BlurLabel)
+            .add_render_graph_edges(
+                Core2d,
+                (
+                    Node2d::Tonemapping,
+                    BlurLabel,
+                    Node2d::EndMainPassPostProcessing,
+                ),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app.init_resource::<BlurPipeline>();
+    }
+}
+
+fn toggle_blur(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    state: Res<State<GameState>>,
+    cameras: Query<(Entity, Has<Blur>), MainCamera>,
+) {
+    let wanted = settings.pause_blur && *state.get() == GameState::Paused;
+    for (camera, blurred) in &cameras {
+        if wanted && !blurred {
+            commands.entity(camera).insert(Blur);
+        } else if !wanted && blurred {
+            commands.entity(camera).remove::<Blur>();
+        }
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+struct BlurLabel;
+
+#[derive(Default)]
+struct BlurNode;
+
+impl ViewNode for BlurNode {
+    type ViewQuery = (&'static ViewTarget, &'static Blur);
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        (view_target, _blur): QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let blur_pipeline = world.resource::<BlurPipeline>();
+        // The shader may still be loading.
+        let Some(pipeline) = world
+            .resource::<PipelineCache>()
+            .get_render_pipeline(blur_pipeline.pipeline_id)
+        else {
+            return Ok(());
+        };
+
+        let post_process = view_target.post_process_write();
+        let bind_group = render_context.render_device().create_bind_group(
+            "blur_bind_group",
+            &blur_pipeline.layout,
+            &BindGroupEntries::sequential((post_process.source, &blur_pipeline.sampler)),
+        );
+        let mut pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("blur_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        pass.set_render_pipeline(pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.draw(0..3, 0..1);
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct BlurPipeline {
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    pipeline_id: CachedRenderPipelineId,
+}
+
+impl FromWorld for BlurPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let layout = render_device.create_bind_group_layout(
+            "blur_bind_group_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::FRAGMENT,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                ),
+            ),
+        );
+        let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+        let shader = world.load_asset(SHADER_PATH);
+        let pipeline_id =
+            world
+                .resource_mut::<PipelineCache>()
+                .queue_render_pipeline(RenderPipelineDescriptor {
+                    label: Some("blur_pipeline".into()),
+                    layout: vec![layout.clone()],
+                    vertex: fullscreen_shader_vertex_state(),
+                    fragment: Some(FragmentState {
+                        shader,
+                        shader_defs: vec![],
+                        entry_point: "fragment".into(),
+                        targets: vec![Some(ColorTargetState {
+                            format: TextureFormat::bevy_default(),
+                            blend: None,
+                            write_mask: ColorWrites::ALL,
+                        })],
+                    }),
+                    primitive: PrimitiveState::default(),
+                    depth_stencil: None,
+                    multisample: MultisampleState::default(),
+                    push_constant_ranges: vec![],
+                });
+        BlurPipeline {
+            layout,
+            sampler,
+            pipeline_id,
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    Ball, GameEvents, MatchOver, Player, Score,
+    arena::Arena,
+    hits::BallHitPaddle,
+    layout::OBSTACLES,
+    locale::Locale,
+    mutators::{ActiveMutators, BallType},
+    toast::Toast,
+};
+
+/// The brick wall is this many lanes high and columns deep.
+const LANES: usize = 12;
+const COLUMNS: usize = 3;
+const BRICK_WIDTH: f32 = 28.0;
+/// Space between bricks, so lanes show.
+const GAP: f32 = 4.0;
+/// Bricks in the middle column take two hits.
+const MIDDLE_HIT_POINTS: u32 = 2;
+const BONUS_POINTS: i32 = 1;
+
+#[derive(Component)]
+struct Brick {
+    lane: usize,
+    column: usize,
+    hit_points: u32,
+}
+
+/// Whoever touched the ball last, and so gets credit for the bricks it breaks. Nobody
+/// does for a serve that hasn't been returned yet.
+#[derive(Resource, Default)]
+struct LastHit(Option<Player>);
+
+/// `--bricks`: a wall of bricks across the middle of the court. Each hit chips a brick
+/// away; clearing a whole lane through the wall is worth a bonus point to whoever hit
+/// the ball last, and leaves a lane open for straight shots. The wall is rebuilt for
+/// every match.
+pub struct BricksPlugin;
+
+impl Plugin for BricksPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LastHit>();
+        app.add_systems(Startup, build_wall);
+        app.add_systems(
+            Update,
+            (
+                track_last_hit,
+                break_bricks,
+                rebuild_wall,
+                fit_bricks.run_if(resource_changed::<Arena>),
+            )
+                .chain(),
+        );
+    }
+}
+
+fn build_wall(mut commands: Commands, arena: Res<Arena>) {
+    spawn_wall(&mut commands, &arena);
+}
+
+fn spawn_wall(commands: &mut Commands, arena: &Arena) {
+    for lane in 0..LANES {
+        for column in 0..COLUMNS {
+            let hit_points = if column == COLUMNS / 2 {
+                MIDDLE_HIT_POINTS
+            } else {
+                1
+            };
+            let brick = Brick {
+                lane,
+                column,
+                hit_points,
+            };
+            let (transform, size) = brick_layout(&brick, arena);
+            commands.spawn((
+                SpriteBundle {
+                    transform,
+                    sprite: Sprite {
+                        color: brick_colour(hit_points),
+                        custom_size: Some(size),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                RigidBody::Fixed,
+                Collider::cuboid(size.x / 2.0, size.y / 2.0),
+                CollisionGroups::new(OBSTACLES, Group::ALL),
+                brick,
+            ));
+        }
+    }
+}
+
+/// Where a brick goes in the current arena, and its size.
+fn brick_layout(brick: &Brick, arena: &Arena) -> (Transform, Vec2) {
+    let lane_height = arena.height / LANES as f32;
+    let x = (brick.column as f32 - (COLUMNS - 1) as f32 / 2.0) * (BRICK_WIDTH + GAP);
+    let y = -arena.half_height() + (brick.lane as f32 + 0.5) * lane_height;
+    let size = Vec2::new(BRICK_WIDTH, lane_height - GAP);
+    (Transform::from_xyz(x, y, -0.5), size)
+}
+
+fn brick_colour(hit_points: u32) -> Color {
+    if hit_points > 1 {
+        Color::srgb(0.85, 0.55, 0.2)
+    } else {
+        Color::srgb(0.85, 0.8, 0.45)
+    }
+}
+
+fn track_last_hit(
+    mut hits: EventReader<BallHitPaddle>,
+    mut events: EventReader<GameEvents>,
+    mut last_hit: ResMut<LastHit>,
+) {
+    if events
+        .read()
+        .any(|event| matches!(event, GameEvents::ResetBall(_)))
+    {
+        last_hit.0 = None;
+    }
+    if let Some(hit) = hits.read().last() {
+        last_hit.0 = Some(hit.player);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn break_bricks(
+    mut commands: Commands,
+    mut collisions: EventReader<CollisionEvent>,
+    balls: Query<(), With<Ball>>,
+    mut bricks: Query<(Entity, &mut Brick, &mut Sprite)>,
+    last_hit: Res<LastHit>,
+    mutators: Res<ActiveMutators>,
+    locale: Res<Locale>,
+    mut score: ResMut<Score>,
+    mut toasts: EventWriter<Toast>,
+) {
+    for collision in collisions.read() {
+        let CollisionEvent::Started(a, b, _) = collision else {
+            continue;
+        };
+        let brick = match (balls.contains(*a), balls.contains(*b)) {
+            (true, _) => *b,
+            (_, true) => *a,
+            _ => continue,
+        };
+        let Ok((entity, mut brick, mut sprite)) = bricks.get_mut(brick) else {
+            continue;
+        };
+        brick.hit_points = if mutators.ball_type() == BallType::Heavy {
+            0
+        } else {
+            brick.hit_points.saturating_sub(1)
+        };
+        if brick.hit_points > 0 {
+            sprite.color = brick_colour(brick.hit_points);
+            continue;
+        }
+        commands.entity(entity).despawn();
+
+        // Broken bricks stay in the query until the despawns are applied, so go by hit
+        // points rather than by what is left.
+        let lane = brick.lane;
+        let standing = bricks
+            .iter()
+            .filter(|(_, other, _)| other.lane == lane && other.hit_points > 0)
+            .count();
+        let Some(player) = last_hit.0.filter(|_| standing == 0) else {
+            continue;
+        };
+        *score.0.entry(player).or_insert(0) += BONUS_POINTS;
+        toasts.send(Toast(locale.format(
+            "bricks-lane-cleared",
+            &[("player", locale.player(player).into())],
+        )));
+    }
+}
+
+fn rebuild_wall(
+    mut commands: Commands,
+    mut events: EventReader<MatchOver>,
+    arena: Res<Arena>,
+    bricks: Query<Entity, With<Brick>>,
+) {
+    if events.read().last().is_none() {
+        return;
+    }
+    for brick in &bricks {
+        commands.entity(brick).despawn();
+    }
+    spawn_wall(&mut commands, &arena);
+}
+
+fn fit_bricks(
+    arena: Res<Arena>,
+    mut bricks: Query<(&Brick, &mut Transform, &mut Sprite, &mut Collider)>,
+) {
+    for (brick, mut transform, mut sprite, mut collider) in &mut bricks {
+        let (fitted, size) = brick_layout(brick, &arena);
+        *transform = fitted;
+        sprite.custom_size = Some(size);
+        *collider = Collider::cuboid(size.x / 2.0, size.y / 2.0);
+    }
+}
@@ -0,0 +1,225 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use std::f32::consts::PI;
+
+use crate::{
+    Ball, Paddle,
+    arena::Arena,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    motion::{MotionPreferences, motion_allowed},
+    settings::Settings,
+    sound::SoundEvent,
+};
+
+/// Ball speed, in pixels per second, from which a bounce shakes the screen.
+const HARD_IMPACT_SPEED: f32 = 600.0;
+const MAX_SHAKE: f32 = 10.0;
+/// Share of a zoom's time spent zooming in; the rest eases back out.
+const ZOOM_ATTACK: f32 = 0.15;
+/// How far the follow camera leans towards the ball, as a share of the ball's distance
+/// from the centre, and the most it ever moves.
+const FOLLOW_SHARE: f32 = 0.1;
+const MAX_FOLLOW_PAN: f32 = 48.0;
+const FOLLOW_ZOOM: f32 = 1.05;
+/// How quickly the follow camera catches up, per second.
+const FOLLOW_RATE: f32 = 3.0;
+const PADDLE_HALF_WIDTH: f32 = 5.0;
+
+/// A short camera move. Anything that wants one sends this event; only this module moves
+/// the camera.
+#[derive(Event, Clone, Copy, Debug)]
+pub enum CameraEffect {
+    /// Zoom in by `amount` (0.1 is 10% closer), then ease back over `seconds`.
+    Zoom { amount: f32, seconds: f32 },
+    /// Jolt the view by up to `strength` pixels, settling over `seconds`.
+    Shake { strength: f32, seconds: f32 },
+}
+
+impl CameraEffect {
+    fn seconds(&self) -> f32 {
+        match *self {
+            CameraEffect::Zoom { seconds, .. } | CameraEffect::Shake { seconds, .. } => seconds,
+        }
+    }
+}
+
+/// Filter for the main game camera, as opposed to the one drawing corner replays.
+pub type MainCamera = With<CameraRig>;
+
+/// The effects playing on a camera. Effects overlap: zooms take the strongest, shakes add up.
+/// They play on top of where the follow camera is looking.
+#[derive(Component)]
+pub struct CameraRig {
+    effects: Vec<(CameraEffect, Timer)>,
+    focus: Vec2,
+    focus_zoom: f32,
+}
+
+impl Default for CameraRig {
+    fn default() -> Self {
+        CameraRig {
+            effects: Vec::new(),
+            focus: Vec2::ZERO,
+            focus_zoom: 1.0,
+        }
+    }
+}
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CameraEffect>();
+        app.add_setting_row(SettingRow {
+            label: "setting-follow-camera",
+            value: |s, l| on_off(l, s.follow_camera),
+            change: |s, _| s.follow_camera = !s.follow_camera,
+        });
+        app.add_systems(
+            Update,
+            (
+                effects_from_sounds.run_if(motion_allowed),
+                queue_effects,
+                follow_ball,
+                apply_effects,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// A brief zoom on every goal, and a shake on bounces at high speed.
+fn effects_from_sounds(
+    mut sounds: EventReader<SoundEvent>,
+    balls: Query<&Velocity, With<Ball>>,
+    mut effects: EventWriter<CameraEffect>,
+) {
+    let speed = balls
+        .iter()
+        .map(|velocity| velocity.linvel.length())
+        .fold(0.0, f32::max);
+    for sound in sounds.read() {
+        match sound {
+            SoundEvent::Goal { .. } => {
+                effects.send(CameraEffect::Zoom {
+                    amount: 0.08,
+                    seconds: 0.6,
+                });
+            }
+            SoundEvent::WallBounce { .. } | SoundEvent::PaddleHit { .. }
+                if speed >= HARD_IMPACT_SPEED =>
+            {
+                effects.send(CameraEffect::Shake {
+                    strength: (speed / HARD_IMPACT_SPEED * 3.0).min(MAX_SHAKE),
+                    seconds: 0.25,
+                });
+            }
+            SoundEvent::WallBounce { .. }
+            | SoundEvent::PaddleHit { .. }
+            | SoundEvent::Fanfare { .. } => {}
+        }
+    }
+}
+
+fn queue_effects(mut events: EventReader<CameraEffect>, mut rigs: Query<&mut CameraRig>) {
+    for effect in events.read() {
+        for mut rig in &mut rigs {
+            let timer = Timer::from_seconds(effect.seconds(), TimerMode::Once);
+            rig.effects.push((*effect, timer));
+        }
+    }
+}
+
+/// Leans the view a little towards the ball and zooms in slightly, but never so far that
+/// a paddle leaves the screen. Off, or with reduced motion, it drifts back to the centre.
+fn follow_ball(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    motion: Res<MotionPreferences>,
+    arena: Res<Arena>,
+    balls: Query<&Transform, With<Ball>>,
+    paddles: Query<&Transform, With<Paddle>>,
+    mut rigs: Query<&mut CameraRig>,
+) {
+    let following = settings.follow_camera && !motion.reduced;
+    let ball = balls.iter().next().map(|b| b.translation.truncate());
+    let (target, target_zoom) = match ball {
+        Some(ball) if following => (
+            (ball * FOLLOW_SHARE).clamp_length_max(MAX_FOLLOW_PAN),
+            FOLLOW_ZOOM,
+        ),
+        _ => (Vec2::ZERO, 1.0),
+    };
+
+    // The box around both paddles has to stay in view.
+    let (mut low, mut high) = (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN));
+    for paddle in &paddles {
+        let half_size = Vec2::new(PADDLE_HALF_WIDTH, 75.0 * paddle.scale.y);
+        low = low.min(paddle.translation.truncate() - half_size);
+        high = high.max(paddle.translation.truncate() + half_size);
+    }
+    let half_view = Vec2::new(arena.half_width(), arena.half_height());
+    let (low, high) = if low.x > high.x {
+        (-half_view, half_view)
+    } else {
+        (low, high)
+    };
+    let blend = 1.0 - (-FOLLOW_RATE * time.delta_seconds()).exp();
+
+    for mut rig in &mut rigs {
+        rig.focus_zoom += (target_zoom - rig.focus_zoom) * blend;
+        rig.focus = rig.focus.lerp(target, blend);
+
+        // Hard limits after smoothing, so a paddle is never cut off even while catching up.
+        let fits = (2.0 * half_view / (high - low)).min_element();
+        rig.focus_zoom = rig.focus_zoom.clamp(1.0, fits.max(1.0));
+        let visible = half_view / rig.focus_zoom;
+        let min = (high - visible).min(Vec2::ZERO);
+        let max = (low + visible).max(Vec2::ZERO);
+        rig.focus = rig.focus.clamp(min, max);
+    }
+}
+
+/// Rebuilds the camera's offset and zoom from the effects still playing, so the view is
+/// back exactly at rest once they have all finished. Reduced motion cuts effects short.
+/// At rest the court fills the window whatever its size setting.
+fn apply_effects(
+    time: Res<Time>,
+    motion: Res<MotionPreferences>,
+    arena: Res<Arena>,
+    mut rigs: Query<(&mut CameraRig, &mut Transform, &mut OrthographicProjection)>,
+) {
+    for (mut rig, mut transform, mut projection) in &mut rigs {
+        if motion.reduced {
+            rig.effects.clear();
+        }
+        let mut zoom: f32 = 0.0;
+        let mut offset = Vec2::ZERO;
+        for (effect, timer) in &mut rig.effects {
+            timer.tick(time.delta());
+            let progress = timer.fraction();
+            match *effect {
+                CameraEffect::Zoom { amount, .. } => {
+                    let envelope = if progress < ZOOM_ATTACK {
+                        (progress / ZOOM_ATTACK * PI / 2.0).sin()
+                    } else {
+                        ((1.0 - progress) / (1.0 - ZOOM_ATTACK)).powi(2)
+                    };
+                    zoom = zoom.max(amount * envelope);
+                }
+                CameraEffect::Shake { strength, .. } => {
+                    // Deterministic wobble rather than random numbers, so shakes never
+                    // change what the seeded gameplay generator produces.
+                    let t = time.elapsed_seconds();
+                    let wobble = Vec2::new((t * 71.0).sin(), (t * 59.0).cos());
+                    offset += wobble * strength * (1.0 - progress).powi(2);
+                }
+            }
+        }
+        rig.effects.retain(|(_, timer)| !timer.finished());
+
+        transform.translation.x = rig.focus.x + offset.x;
+        transform.translation.y = rig.focus.y + offset.y;
+        projection.scale = arena.scale / (rig.focus_zoom * (1.0 + zoom));
+    }
+}
@@ -0,0 +1,287 @@
+use bevy::{color::palettes::css, prelude::*};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::{
+    GameEvents, GameState, MatchOver, MatchRules, Player,
+    ai::{AiDifficulty, GameMode, OpponentDifficulty},
+    fonts::FontStack,
+    locale::Locale,
+    mutators::{ActiveMutators, Mutator},
+    narration::Announcement,
+    settings::Settings,
+    storage::Storage,
+    theme::ThemeConfig,
+    toast::Toast,
+};
+
+const PROGRESS_KEY: &str = "campaign";
+
+/// One stage of the campaign: who you play, on what court, under which rules.
+struct Stage {
+    /// Stable id for saved progress, and the message id of the stage's name.
+    id: &'static str,
+    opponent: AiDifficulty,
+    target_score: i32,
+    background: Srgba,
+    court_lines: Srgba,
+    mutators: &'static [Mutator],
+}
+
+const STAGES: [Stage; 5] = [
+    Stage {
+        id: "stage-rookie",
+        opponent: AiDifficulty::Easy,
+        target_score: 5,
+        background: Srgba::rgb(0.17, 0.17, 0.18),
+        court_lines: Srgba::new(1.0, 1.0, 1.0, 0.3),
+        mutators: &[],
+    },
+    Stage {
+        id: "stage-night",
+        opponent: AiDifficulty::Normal,
+        target_score: 7,
+        background: Srgba::rgb(0.04, 0.06, 0.16),
+        court_lines: Srgba::new(0.6, 0.7, 1.0, 0.4),
+        mutators: &[],
+    },
+    Stage {
+        id: "stage-blitz",
+        opponent: AiDifficulty::Normal,
+        target_score: 7,
+        background: Srgba::rgb(0.2, 0.05, 0.05),
+        court_lines: Srgba::new(1.0, 0.6, 0.3, 0.4),
+        mutators: &[Mutator::FastServes],
+    },
+    Stage {
+        id: "stage-wall",
+        opponent: AiDifficulty::Hard,
+        target_score: 9,
+        background: Srgba::rgb(0.03, 0.15, 0.08),
+        court_lines: Srgba::new(0.6, 1.0, 0.6, 0.4),
+        mutators: &[Mutator::WideOpponent],
+    },
+    Stage {
+        id: "stage-finale",
+        opponent: AiDifficulty::Hard,
+        target_score: 11,
+        background: css::BLACK,
+        court_lines: Srgba::new(0.8, 0.5, 1.0, 0.5),
+        mutators: &[Mutator::FastServes, Mutator::WideOpponent],
+    },
+];
+
+/// Stages beaten so far, by id. Saved after every win.
+#[derive(Resource, Default, Serialize, Deserialize)]
+struct Progress {
+    completed: HashSet<String>,
+}
+
+impl Progress {
+    fn is_complete(&self, stage: usize) -> bool {
+        self.completed.contains(STAGES[stage].id)
+    }
+
+    /// The first stage is always open; each later one opens when the one before is beaten.
+    fn is_unlocked(&self, stage: usize) -> bool {
+        stage == 0 || self.is_complete(stage - 1)
+    }
+}
+
+/// The stage being played, or `None` on the stage select screen.
+#[derive(Resource, Default)]
+struct Campaign {
+    selected: usize,
+    playing: Option<usize>,
+}
+
+#[derive(Component)]
+struct CampaignRoot;
+
+#[derive(Component)]
+struct CampaignText;
+
+/// `--campaign`: Player 1 against the computer through a run of stages, each with its own
+/// court colours, opponent and rule changes. Beating a stage opens the next.
+pub struct CampaignPlugin;
+
+impl Plugin for CampaignPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameMode::OnePlayer);
+        app.init_resource::<Progress>();
+        app.init_resource::<Campaign>();
+        app.add_systems(Startup, (spawn_screen, open_stage_select));
+        app.add_systems(Update, (finish_stage, select_stage, draw_screen).chain());
+    }
+}
+
+fn spawn_screen(mut commands: Commands, fonts: Res<FontStack>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.85).into(),
+                z_index: ZIndex::Global(5),
+                ..Default::default()
+            },
+            CampaignRoot,
+        ))
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 32.), CampaignText));
+        });
+}
+
+fn open_stage_select(
+    storage: Res<Storage>,
+    mut progress: ResMut<Progress>,
+    mut campaign: ResMut<Campaign>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    if let Some(saved) = storage.load(PROGRESS_KEY) {
+        *progress = saved;
+    }
+    // Start on the furthest open stage.
+    campaign.selected = (0..STAGES.len())
+        .rev()
+        .find(|stage| progress.is_unlocked(*stage))
+        .unwrap_or(0);
+    next.set(GameState::Intermission);
+}
+
+/// Up and down pick a stage, Enter plays it if it is open.
+#[allow(clippy::too_many_arguments)]
+fn select_stage(
+    keys: Res<ButtonInput<KeyCode>>,
+    progress: Res<Progress>,
+    mut campaign: ResMut<Campaign>,
+    mut rules: ResMut<MatchRules>,
+    mut opponent: ResMut<OpponentDifficulty>,
+    mut theme: ResMut<ThemeConfig>,
+    mut mutators: ResMut<ActiveMutators>,
+    mut game_events: EventWriter<GameEvents>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    if campaign.playing.is_some() {
+        return;
+    }
+    if keys.any_just_pressed([KeyCode::ArrowUp, KeyCode::KeyW]) {
+        campaign.selected = campaign.selected.saturating_sub(1);
+    }
+    if keys.any_just_pressed([KeyCode::ArrowDown, KeyCode::KeyS]) {
+        campaign.selected = (campaign.selected + 1).min(STAGES.len() - 1);
+    }
+    if !keys.just_pressed(KeyCode::Enter) || !progress.is_unlocked(campaign.selected) {
+        return;
+    }
+
+    let stage = &STAGES[campaign.selected];
+    campaign.playing = Some(campaign.selected);
+    rules.target_score = stage.target_score;
+    opponent.0 = Some(stage.opponent);
+    theme.background = stage.background.into();
+    theme.court_lines = stage.court_lines.into();
+    mutators.0 = stage.mutators.to_vec();
+    game_events.send(GameEvents::ResetBall(Player::Player1));
+    next.set(GameState::Playing);
+}
+
+/// Player 1 winning completes the stage; either way it's back to stage select.
+#[allow(clippy::too_many_arguments)]
+fn finish_stage(
+    mut events: EventReader<MatchOver>,
+    storage: Res<Storage>,
+    locale: Res<Locale>,
+    mut progress: ResMut<Progress>,
+    mut campaign: ResMut<Campaign>,
+    mut settings: ResMut<Settings>,
+    mut mutators: ResMut<ActiveMutators>,
+    mut next: ResMut<NextState<GameState>>,
+    mut toasts: EventWriter<Toast>,
+    mut announcements: EventWriter<Announcement>,
+) {
+    let Some(MatchOver(winner)) = events.read().last() else {
+        return;
+    };
+    let Some(stage) = campaign.playing.take() else {
+        return;
+    };
+    // Has the assists size the paddles again, undoing any mutator.
+    mutators.0.clear();
+    settings.set_changed();
+    let message = if *winner == Player::Player1 {
+        progress.completed.insert(STAGES[stage].id.to_string());
+        storage.save(PROGRESS_KEY, &*progress);
+        campaign.selected = (stage + 1).min(STAGES.len() - 1);
+        "campaign-stage-won"
+    } else {
+        "campaign-stage-lost"
+    };
+    let message = locale.format(message, &[("stage", locale.text(STAGES[stage].id).into())]);
+    announcements.send(Announcement(message.clone()));
+    toasts.send(Toast(message));
+    next.set(GameState::Intermission);
+}
+
+fn draw_screen(
+    campaign: Res<Campaign>,
+    progress: Res<Progress>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    mut roots: Query<&mut Visibility, With<CampaignRoot>>,
+    mut texts: Query<&mut Text, With<CampaignText>>,
+) {
+    if !(campaign.is_changed() || progress.is_changed() || locale.is_changed()) {
+        return;
+    }
+    for mut visibility in &mut roots {
+        *visibility = if campaign.playing.is_some() {
+            Visibility::Hidden
+        } else {
+            Visibility::Visible
+        };
+    }
+
+    let mut lines = vec![locale.text("campaign-title"), String::new()];
+    for (index, stage) in STAGES.iter().enumerate() {
+        let cursor = if index == campaign.selected {
+            "> "
+        } else {
+            "  "
+        };
+        let status = if progress.is_complete(index) {
+            locale.text("campaign-complete")
+        } else if progress.is_unlocked(index) {
+            locale.format(
+                "campaign-first-to",
+                &[("points", stage.target_score.into())],
+            )
+        } else {
+            locale.text("campaign-locked")
+        };
+        lines.push(format!(
+            "{cursor}{}. {}: {status}",
+            index + 1,
+            locale.text(stage.id)
+        ));
+    }
+    lines.push(String::new());
+    lines.push(locale.format(
+        "campaign-progress",
+        &[
+            ("done", progress.completed.len().into()),
+            ("total", STAGES.len().into()),
+        ],
+    ));
+    let value = lines.join("\n");
+    for mut text in &mut texts {
+        fonts.set(&mut text, &value);
+    }
+}
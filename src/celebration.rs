@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    MatchOver, Player, arena::Arena, motion::MotionPreferences, pool::Pool, settings::Settings,
+    sound::SoundEvent,
+};
+
+/// Most particles alive at once, across the whole celebration.
+const MAX_PARTICLES: usize = 480;
+const CONFETTI_PER_CANNON: usize = 90;
+const SPARKS_PER_FIREWORK: usize = 60;
+/// Seconds after the win that each firework goes off.
+const FIREWORKS: [f32; 4] = [0.3, 0.8, 1.2, 1.7];
+const CONFETTI_SECONDS: f32 = 3.0;
+const SPARK_SECONDS: f32 = 1.1;
+/// Pulls confetti and sparks down, in pixels per second squared.
+const GRAVITY: f32 = 420.0;
+/// How quickly particles slow down in the air, per second. Confetti flutters, so more.
+const CONFETTI_DRAG: f32 = 1.6;
+const SPARK_DRAG: f32 = 0.9;
+/// In front of the court and the balls, under the UI.
+const PARTICLE_Z: f32 = 40.0;
+
+/// A bit of confetti or a firework spark.
+#[derive(Component)]
+struct Particle {
+    velocity: Vec2,
+    spin: f32,
+    drag: f32,
+    life: Timer,
+}
+
+/// The fireworks still to go off for the win being celebrated.
+#[derive(Resource, Default)]
+struct Celebration {
+    winner: Option<Player>,
+    elapsed: f32,
+    fireworks: Vec<f32>,
+}
+
+/// Winning a match sets off a fanfare, two confetti cannons from the corners of the
+/// winner's half and a few fireworks over it, all in the winner's colour, over the
+/// end-of-match toasts. With reduced motion there is only the fanfare.
+pub struct CelebrationPlugin;
+
+impl Plugin for CelebrationPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Pool::<Particle>::new(MAX_PARTICLES));
+        app.init_resource::<Celebration>();
+        app.add_systems(
+            Update,
+            (celebrate, launch_fireworks, move_particles).chain(),
+        );
+    }
+}
+
+/// Shades of the winner's colour, some lighter and some darker, so a burst has depth.
+fn shade(colour: Color, rng: &mut impl Rng) -> Color {
+    let amount = rng.gen_range(-0.3..0.5);
+    if amount > 0.0 {
+        colour.mix(&Color::WHITE, amount)
+    } else {
+        colour.mix(&Color::BLACK, -amount)
+    }
+}
+
+/// The celebration is decoration, so it uses its own randomness and leaves the seeded
+/// gameplay generator alone.
+#[allow(clippy::too_many_arguments)]
+fn celebrate(
+    mut commands: Commands,
+    mut events: EventReader<MatchOver>,
+    motion: Res<MotionPreferences>,
+    settings: Res<Settings>,
+    arena: Res<Arena>,
+    mut celebration: ResMut<Celebration>,
+    mut pool: ResMut<Pool<Particle>>,
+    mut sounds: EventWriter<SoundEvent>,
+) {
+    let Some(MatchOver(winner)) = events.read().last() else {
+        return;
+    };
+    sounds.send(SoundEvent::Fanfare { winner: *winner });
+    if motion.reduced {
+        return;
+    }
+    *celebration = Celebration {
+        winner: Some(*winner),
+        elapsed: 0.0,
+        fireworks: FIREWORKS.to_vec(),
+    };
+
+    let colour = settings.player_colour(*winner);
+    let rng = &mut rand::thread_rng();
+    // The outer corner of the winner's half, and the middle of the court.
+    let side = -arena.goal_x(*winner).signum();
+    for x in [side * arena.half_width(), 0.0] {
+        let origin = Vec2::new(x, -arena.half_height());
+        // Up and in towards the winner's half.
+        let towards = if x == 0.0 { side } else { -side };
+        for _ in 0..CONFETTI_PER_CANNON {
+            let angle = rng.gen_range(0.15..0.55) * towards;
+            let speed = rng.gen_range(500.0..900.0) * arena.scale;
+            let velocity = Vec2::new(angle.sin(), angle.cos()) * speed;
+            spawn_particle(
+                &mut commands,
+                &mut pool,
+                Particle {
+                    velocity,
+                    spin: rng.gen_range(-12.0..12.0),
+                    drag: CONFETTI_DRAG,
+                    life: Timer::from_seconds(
+                        rng.gen_range(0.7..1.0) * CONFETTI_SECONDS,
+                        TimerMode::Once,
+                    ),
+                },
+                origin,
+                Vec2::new(8.0, 4.0),
+                shade(colour, rng),
+            );
+        }
+    }
+}
+
+fn launch_fireworks(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    arena: Res<Arena>,
+    mut celebration: ResMut<Celebration>,
+    mut pool: ResMut<Pool<Particle>>,
+) {
+    let Some(winner) = celebration.winner else {
+        return;
+    };
+    celebration.elapsed += time.delta_seconds();
+    let elapsed = celebration.elapsed;
+    let due = celebration
+        .fireworks
+        .iter()
+        .filter(|at| **at <= elapsed)
+        .count();
+    if due == 0 {
+        return;
+    }
+    celebration.fireworks.retain(|at| *at > elapsed);
+    if celebration.fireworks.is_empty() {
+        celebration.winner = None;
+    }
+
+    let colour = settings.player_colour(winner);
+    let rng = &mut rand::thread_rng();
+    let side = -arena.goal_x(winner).signum();
+    for _ in 0..due {
+        // Somewhere over the top half of the winner's side.
+        let centre = Vec2::new(
+            side * rng.gen_range(0.15..0.8) * arena.half_width(),
+            rng.gen_range(0.1..0.6) * arena.half_height(),
+        );
+        for spark in 0..SPARKS_PER_FIREWORK {
+            let angle = spark as f32 / SPARKS_PER_FIREWORK as f32 * std::f32::consts::TAU
+                + rng.gen_range(-0.05..0.05);
+            let speed = rng.gen_range(180.0..320.0) * arena.scale;
+            spawn_particle(
+                &mut commands,
+                &mut pool,
+                Particle {
+                    velocity: Vec2::from_angle(angle) * speed,
+                    spin: 0.0,
+                    drag: SPARK_DRAG,
+                    life: Timer::from_seconds(SPARK_SECONDS, TimerMode::Once),
+                },
+                centre,
+                Vec2::splat(5.0),
+                shade(colour, rng),
+            );
+        }
+    }
+}
+
+fn spawn_particle(
+    commands: &mut Commands,
+    pool: &mut Pool<Particle>,
+    particle: Particle,
+    position: Vec2,
+    size: Vec2,
+    colour: Color,
+) {
+    pool.take(
+        commands,
+        (
+            SpriteBundle {
+                transform: Transform::from_translation(position.extend(PARTICLE_Z)),
+                sprite: Sprite {
+                    color: colour,
+                    custom_size: Some(size),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            particle,
+        ),
+    );
+}
+
+/// Particles fade out over the last third of their life.
+fn move_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pool: ResMut<Pool<Particle>>,
+    mut particles: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut particle, mut transform, mut sprite) in &mut particles {
+        if particle.life.tick(time.delta()).finished() {
+            pool.give_back(&mut commands, entity);
+            continue;
+        }
+        let drag = (-particle.drag * delta).exp();
+        particle.velocity = particle.velocity * drag - Vec2::Y * GRAVITY * delta;
+        transform.translation += (particle.velocity * delta).extend(0.0);
+        transform.rotate_z(particle.spin * delta);
+        let left = 1.0 - particle.life.fraction();
+        sprite.color.set_alpha((left * 3.0).min(1.0));
+    }
+}
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+use clap::Parser;
+use std::path::PathBuf;
+
+use crate::{WINNING_SCORE, ai::GameMode};
+
+/// Launch options. Anything given here wins over the settings file, for this run only.
+#[derive(Parser, Resource, Clone, Debug)]
+#[command(version, about = "Pong, built with Bevy and Rapier")]
+pub struct Cli {
+    /// Who controls the paddles. `survival` is Player 1 alone against a wall.
+    #[arg(long, value_enum, default_value_t = GameMode::TwoPlayer)]
+    pub mode: GameMode,
+    /// Points needed to win a match.
+    #[arg(long, default_value_t = WINNING_SCORE, value_parser = clap::value_parser!(i32).range(1..))]
+    pub target_score: i32,
+    /// Seed for all gameplay randomness, to replay a session.
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Start in borderless fullscreen.
+    #[arg(long)]
+    pub fullscreen: bool,
+    /// Read settings from this JSON file instead of the saved ones. It is never written to.
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+    /// Play a knockout tournament for 4 to 8 players, or carry on an unfinished one.
+    #[arg(long, conflicts_with = "campaign")]
+    pub tournament: bool,
+    /// Play the single-player campaign against the computer.
+    #[arg(long, conflicts_with = "mode")]
+    pub campaign: bool,
+    /// Put a wall of bricks across the middle of the court.
+    #[arg(long)]
+    pub bricks: bool,
+    /// Play a custom arena saved by the editor.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["campaign", "editor"])]
+    pub arena: Option<PathBuf>,
+    /// Open the arena editor on this file, creating it on the first save.
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 0..=1,
+        default_missing_value = "arenas/custom.ron",
+        conflicts_with_all = ["campaign", "tournament", "headless"]
+    )]
+    pub editor: Option<PathBuf>,
+    /// Custom game rules written in Rhai. Needs the `scripting` feature.
+    #[cfg(feature = "scripting")]
+    #[arg(long, value_name = "PATH")]
+    pub script: Option<PathBuf>,
+    /// Address of an online leaderboard server for survival times and win streaks. Needs
+    /// the `leaderboard` feature, and Share scores turned on in the settings menu.
+    #[cfg(all(feature = "leaderboard", not(target_arch = "wasm32")))]
+    #[arg(long, value_name = "URL")]
+    pub leaderboard: Option<String>,
+    /// Address of a server for anonymous gameplay stats. Needs the `telemetry` feature, and
+    /// Share anonymous stats turned on in the settings menu.
+    #[cfg(all(feature = "telemetry", not(target_arch = "wasm32")))]
+    #[arg(long, value_name = "URL")]
+    pub telemetry: Option<String>,
+    /// Streamer mode: chat in this Twitch channel votes on the mutators every few minutes.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[arg(long, value_name = "CHANNEL", conflicts_with = "headless")]
+    pub twitch: Option<String>,
+    /// Run without a window, rendering or audio, then print the result as JSON.
+    #[arg(long)]
+    pub headless: bool,
+    /// Frames to simulate in a headless run, at 60 per second. A played-back recording runs
+    /// to its end instead.
+    #[arg(long, default_value_t = 3600, requires = "headless")]
+    pub frames: u32,
+    /// Record the seed, settings and every key press to this file, written on exit.
+    #[arg(long, value_name = "PATH", conflicts_with = "play_inputs")]
+    pub record_inputs: Option<PathBuf>,
+    /// Play back a file made with `--record-inputs` by running the session again, and warn
+    /// where it stops matching. Its seed, mode, target score and settings win over the flags.
+    #[arg(long, value_name = "PATH")]
+    pub play_inputs: Option<PathBuf>,
+    /// Profiling only: fill the court with this many balls and print frame times on exit.
+    #[arg(long, hide = true, value_name = "BALLS", num_args = 0..=1, default_missing_value = "48")]
+    pub stress: Option<u32>,
+}
+
+impl Cli {
+    /// Whether the settings in use differ from the saved ones because of a flag, in which
+    /// case they must not be saved over them.
+    pub fn overrides_settings(&self) -> bool {
+        self.config.is_some() || self.play_inputs.is_some()
+    }
+}
@@ -0,0 +1,265 @@
+use bevy::{
+    ecs::system::RunSystemOnce,
+    input::{
+        ButtonState, InputSystem,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+use bevy_rapier2d::prelude::*;
+
+use crate::{Ball, GameEvents, Player, Score, fonts::FontStack, spawn_ball};
+
+const HISTORY_LINES: usize = 12;
+
+/// A command typed into the debug console. `run` gets the words after the name and
+/// returns the line to print, or an error message.
+#[derive(Clone, Copy)]
+pub struct ConsoleCommand {
+    pub name: &'static str,
+    /// Shown by `help`, e.g. `set_score <p1> <p2>`.
+    pub usage: &'static str,
+    pub run: fn(&mut World, &[&str]) -> Result<String, String>,
+}
+
+#[derive(Resource, Default)]
+struct ConsoleCommands(Vec<ConsoleCommand>);
+
+/// Lets any plugin add commands to the debug console.
+pub trait AppConsoleExt {
+    fn add_console_command(&mut self, command: ConsoleCommand) -> &mut Self;
+}
+
+impl AppConsoleExt for App {
+    fn add_console_command(&mut self, command: ConsoleCommand) -> &mut Self {
+        self.init_resource::<ConsoleCommands>();
+        self.world_mut()
+            .resource_mut::<ConsoleCommands>()
+            .0
+            .push(command);
+        self
+    }
+}
+
+#[derive(Resource, Default)]
+struct Console {
+    open: bool,
+    input: String,
+    submitted: Vec<String>,
+    history: Vec<String>,
+}
+
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Console>();
+        app.init_resource::<ConsoleCommands>();
+        app.add_console_command(ConsoleCommand {
+            name: "help",
+            usage: "help",
+            run: help,
+        });
+        app.add_console_command(ConsoleCommand {
+            name: "spawn_ball",
+            usage: "spawn_ball",
+            run: |world, _| {
+                world.run_system_once(spawn_ball);
+                Ok("spawned a ball".into())
+            },
+        });
+        app.add_console_command(ConsoleCommand {
+            name: "set_score",
+            usage: "set_score <p1> <p2>",
+            run: set_score,
+        });
+        app.add_console_command(ConsoleCommand {
+            name: "set_speed",
+            usage: "set_speed <speed>",
+            run: set_speed,
+        });
+        app.add_console_command(ConsoleCommand {
+            name: "reset",
+            usage: "reset",
+            run: |world, _| {
+                set_score(world, &["0", "0"])?;
+                world.send_event(GameEvents::ResetBall(Player::Player1));
+                Ok("match reset".into())
+            },
+        });
+        app.add_systems(Startup, spawn_console);
+        app.add_systems(
+            PreUpdate,
+            (read_console_keys, swallow_game_keys)
+                .chain()
+                .after(InputSystem),
+        );
+        app.add_systems(Update, (run_console_commands, draw_console).chain());
+    }
+}
+
+#[derive(Component)]
+struct ConsoleRoot;
+
+#[derive(Component)]
+struct ConsoleText;
+
+fn spawn_console(mut commands: Commands, fonts: Res<FontStack>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::ZERO,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(40.0),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::FlexEnd,
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.85).into(),
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(20),
+                ..Default::default()
+            },
+            ConsoleRoot,
+        ))
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 18.), ConsoleText));
+        });
+}
+
+/// Typing goes through keyboard events rather than `ButtonInput` so it gets the
+/// characters the layout produces. The grave key toggles the console.
+fn read_console_keys(mut keys: EventReader<KeyboardInput>, mut console: ResMut<Console>) {
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed {
+            continue;
+        }
+        if key.key_code == KeyCode::Backquote {
+            console.open = !console.open;
+            continue;
+        }
+        if !console.open {
+            continue;
+        }
+        match &key.logical_key {
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                console.submitted.push(line);
+            }
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Escape => console.open = false,
+            Key::Space => console.input.push(' '),
+            Key::Character(text) => console.input.push_str(text),
+            _ => {}
+        }
+    }
+}
+
+/// While the console is open, nothing else should react to the keys typed into it.
+fn swallow_game_keys(console: Res<Console>, mut keys: ResMut<ButtonInput<KeyCode>>) {
+    if console.open {
+        keys.reset_all();
+    }
+}
+
+fn run_console_commands(world: &mut World) {
+    if world.resource::<Console>().submitted.is_empty() {
+        return;
+    }
+    let lines = std::mem::take(&mut world.resource_mut::<Console>().submitted);
+    for line in lines {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((name, args)) = words.split_first() else {
+            continue;
+        };
+        let command = world
+            .resource::<ConsoleCommands>()
+            .0
+            .iter()
+            .find(|c| c.name == *name)
+            .copied();
+        let output = match command {
+            Some(command) => {
+                (command.run)(world, args).unwrap_or_else(|err| format!("error: {err}"))
+            }
+            None => format!("unknown command `{name}`, try `help`"),
+        };
+
+        let mut console = world.resource_mut::<Console>();
+        console.history.push(format!("> {line}"));
+        console.history.extend(output.lines().map(str::to_string));
+    }
+}
+
+fn draw_console(
+    console: Res<Console>,
+    fonts: Res<FontStack>,
+    mut roots: Query<&mut Visibility, With<ConsoleRoot>>,
+    mut texts: Query<&mut Text, With<ConsoleText>>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+    for mut visibility in &mut roots {
+        *visibility = if console.open {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    let start = console.history.len().saturating_sub(HISTORY_LINES);
+    let mut value = console.history[start..].join("\n");
+    if !value.is_empty() {
+        value.push('\n');
+    }
+    value.push_str(&format!("> {}_", console.input));
+    for mut text in &mut texts {
+        fonts.set(&mut text, &value);
+    }
+}
+
+fn help(world: &mut World, _: &[&str]) -> Result<String, String> {
+    let usages: Vec<&str> = world
+        .resource::<ConsoleCommands>()
+        .0
+        .iter()
+        .map(|c| c.usage)
+        .collect();
+    Ok(usages.join("\n"))
+}
+
+fn set_score(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let [p1, p2] = args else {
+        return Err("usage: set_score <p1> <p2>".into());
+    };
+    let p1: i32 = p1.parse().map_err(|_| format!("not a number: {p1}"))?;
+    let p2: i32 = p2.parse().map_err(|_| format!("not a number: {p2}"))?;
+
+    let mut score = world.resource_mut::<Score>();
+    score.0.insert(Player::Player1, p1);
+    score.0.insert(Player::Player2, p2);
+    Ok(format!("score set to {p1} - {p2}"))
+}
+
+/// Keeps the ball's direction and changes only how fast it goes.
+fn set_speed(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let [speed] = args else {
+        return Err("usage: set_speed <speed>".into());
+    };
+    let speed: f32 = speed
+        .parse()
+        .map_err(|_| format!("not a number: {speed}"))?;
+
+    let mut balls = world.query_filtered::<&mut Velocity, With<Ball>>();
+    for mut velocity in balls.iter_mut(world) {
+        let direction = velocity.linvel.try_normalize().unwrap_or(Vec2::X);
+        velocity.linvel = direction * speed;
+    }
+    Ok(format!("ball speed set to {speed}"))
+}
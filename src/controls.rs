@@ -0,0 +1,445 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+};
+use bevy_rapier2d::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    GameState, Paddle, Player,
+    ai::AiPaddle,
+    arena::Arena,
+    coop::Partner,
+    locale::Locale,
+    menu::{AppSettingsExt, KeyRow, SettingRow},
+    mirror::Flip,
+    move_paddle, paddle_velocity,
+    settings::Settings,
+};
+
+/// Distances one wheel notch can move a paddle, in pixels on a standard court.
+const WHEEL_NOTCHES: [u32; 5] = [20, 40, 60, 80, 120];
+/// How quickly a paddle catches up with the wheel, per second. Lower is smoother.
+const WHEEL_SMOOTHING: f32 = 18.0;
+/// Touchpads scroll in pixels rather than notches; this many make one notch.
+const PIXELS_PER_NOTCH: f32 = 50.0;
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaddleKeys {
+    pub up: KeyCode,
+    pub down: KeyCode,
+    /// Held to charge a smash. Missing from settings saved before smashes existed.
+    #[serde(default)]
+    pub smash: Option<KeyCode>,
+    /// Held to tilt the paddle's face up, or down. Missing from older settings too.
+    #[serde(default)]
+    pub tilt: Option<[KeyCode; 2]>,
+    /// Held to place a barrier, which drops when let go. Missing from older settings too.
+    #[serde(default)]
+    pub barrier: Option<KeyCode>,
+}
+
+/// The mouse wheel as a paddle control, for trackballs and spinner-style play.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WheelBinding {
+    /// The paddle it moves, if any. Off by default.
+    pub player: Option<Player>,
+    /// How far one notch moves the paddle, in pixels on a standard court.
+    pub notch: u32,
+}
+
+impl Default for WheelBinding {
+    fn default() -> Self {
+        WheelBinding {
+            player: None,
+            notch: 40,
+        }
+    }
+}
+
+/// Which keys move which paddle.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bindings {
+    pub player1: PaddleKeys,
+    pub player2: PaddleKeys,
+    /// Missing from settings saved before the wheel could move a paddle.
+    #[serde(default)]
+    pub wheel: WheelBinding,
+    /// Held to restart the match. Missing from older settings too.
+    #[serde(default = "default_restart")]
+    pub restart: KeyCode,
+}
+
+fn default_restart() -> KeyCode {
+    KeyCode::F5
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        ControlPreset::Standard.bindings()
+    }
+}
+
+impl Bindings {
+    pub fn keys(&self, player: Player) -> PaddleKeys {
+        match player {
+            Player::Player1 => self.player1,
+            Player::Player2 => self.player2,
+        }
+    }
+
+    fn keys_mut(&mut self, player: Player) -> &mut PaddleKeys {
+        match player {
+            Player::Player1 => &mut self.player1,
+            Player::Player2 => &mut self.player2,
+        }
+    }
+
+    /// Settings saved without a smash key get the standard layout's.
+    pub fn smash(&self, player: Player) -> KeyCode {
+        self.keys(player)
+            .smash
+            .or(ControlPreset::Standard.bindings().keys(player).smash)
+            .unwrap_or(KeyCode::ShiftLeft)
+    }
+
+    /// The tilt-up and tilt-down keys, from the standard layout for older settings.
+    pub fn tilt(&self, player: Player) -> [KeyCode; 2] {
+        self.keys(player)
+            .tilt
+            .or(ControlPreset::Standard.bindings().keys(player).tilt)
+            .unwrap_or([KeyCode::PageUp, KeyCode::PageDown])
+    }
+
+    /// Sets the tilt-up (`0`) or tilt-down (`1`) key, keeping the other.
+    fn bind_tilt(&mut self, player: Player, index: usize, key: KeyCode) {
+        let mut tilt = self.tilt(player);
+        tilt[index] = key;
+        self.keys_mut(player).tilt = Some(tilt);
+    }
+
+    /// The barrier key, from the standard layout for older settings.
+    pub fn barrier(&self, player: Player) -> KeyCode {
+        self.keys(player)
+            .barrier
+            .or(ControlPreset::Standard.bindings().keys(player).barrier)
+            .unwrap_or(KeyCode::End)
+    }
+}
+
+/// Ready-made layouts that overwrite every paddle binding at once. Single keys can be
+/// changed on top of them from the menu, which makes the layout custom.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ControlPreset {
+    /// W/S and arrow keys.
+    Standard,
+    /// W/S and I/K, so both players stay on the letter keys.
+    Ijkl,
+    /// Arrow keys on the left paddle, for players sitting the other way round.
+    Mirrored,
+    /// W/S and numpad 8/5.
+    Numpad,
+    /// Q/A and E/D, both players on the left half of the keyboard.
+    LeftHand,
+}
+
+impl ControlPreset {
+    const ALL: [ControlPreset; 5] = [
+        ControlPreset::Standard,
+        ControlPreset::Ijkl,
+        ControlPreset::Mirrored,
+        ControlPreset::Numpad,
+        ControlPreset::LeftHand,
+    ];
+
+    fn bindings(self) -> Bindings {
+        // The smash key sits beside the movement keys, on the side facing the court, and
+        // the tilt keys on the other side.
+        let keys = |[up, down]: [KeyCode; 2], smash, tilt, barrier| PaddleKeys {
+            up,
+            down,
+            smash: Some(smash),
+            tilt: Some(tilt),
+            barrier: Some(barrier),
+        };
+        use KeyCode::*;
+        let (player1, player2) = match self {
+            ControlPreset::Standard => (
+                keys([KeyW, KeyS], KeyD, [KeyQ, KeyA], KeyE),
+                keys(
+                    [ArrowUp, ArrowDown],
+                    ArrowLeft,
+                    [ShiftRight, ControlRight],
+                    ArrowRight,
+                ),
+            ),
+            ControlPreset::Ijkl => (
+                keys([KeyW, KeyS], KeyD, [KeyQ, KeyA], KeyE),
+                keys([KeyI, KeyK], KeyJ, [KeyO, KeyL], KeyU),
+            ),
+            ControlPreset::Mirrored => (
+                keys(
+                    [ArrowUp, ArrowDown],
+                    ArrowRight,
+                    [ShiftRight, ControlRight],
+                    ArrowLeft,
+                ),
+                keys([KeyW, KeyS], KeyA, [KeyE, KeyD], KeyQ),
+            ),
+            ControlPreset::Numpad => (
+                keys([KeyW, KeyS], KeyD, [KeyQ, KeyA], KeyE),
+                keys([Numpad8, Numpad5], Numpad4, [Numpad9, Numpad6], Numpad7),
+            ),
+            ControlPreset::LeftHand => (
+                keys([KeyQ, KeyA], KeyZ, [Tab, CapsLock], KeyX),
+                keys([KeyE, KeyD], KeyC, [KeyR, KeyF], KeyV),
+            ),
+        };
+        Bindings {
+            player1,
+            player2,
+            wheel: WheelBinding::default(),
+            restart: default_restart(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ControlPreset::Standard => "controls-standard",
+            ControlPreset::Ijkl => "controls-ijkl",
+            ControlPreset::Mirrored => "controls-mirrored",
+            ControlPreset::Numpad => "controls-numpad",
+            ControlPreset::LeftHand => "controls-left-hand",
+        }
+    }
+
+    /// Goes by the movement keys alone, so settings saved without smash keys still match.
+    fn matching(bindings: &Bindings) -> Option<Self> {
+        let movement = |b: &Bindings| [b.player1, b.player2].map(|k| (k.up, k.down));
+        Self::ALL
+            .into_iter()
+            .find(|p| movement(&p.bindings()) == movement(bindings))
+    }
+}
+
+fn preset_name(settings: &Settings, locale: &Locale) -> String {
+    locale.text(
+        ControlPreset::matching(&settings.bindings).map_or("controls-custom", ControlPreset::name),
+    )
+}
+
+fn cycle_preset(settings: &mut Settings, step: i32) {
+    let all = ControlPreset::ALL;
+    let next = match ControlPreset::matching(&settings.bindings) {
+        Some(current) => {
+            let index = all.iter().position(|p| *p == current).unwrap_or(0) as i32;
+            all[(index + step).rem_euclid(all.len() as i32) as usize]
+        }
+        None => ControlPreset::Standard,
+    };
+    // Presets are paddle layouts, so the wheel and the restart key stay as they were.
+    let Bindings { wheel, restart, .. } = settings.bindings;
+    settings.bindings = Bindings {
+        wheel,
+        restart,
+        ..next.bindings()
+    };
+}
+
+/// Menu rows for rebinding every paddle action one key at a time. Together with the
+/// restart row, these are all the keys a player can bind, so the menu's swapping keeps
+/// every action on its own key.
+const PADDLE_ROWS: [KeyRow; 12] = [
+    KeyRow {
+        label: "setting-player1-up",
+        key: |s| s.bindings.player1.up,
+        bind: |s, key| s.bindings.keys_mut(Player::Player1).up = key,
+    },
+    KeyRow {
+        label: "setting-player1-down",
+        key: |s| s.bindings.player1.down,
+        bind: |s, key| s.bindings.keys_mut(Player::Player1).down = key,
+    },
+    KeyRow {
+        label: "setting-player1-smash",
+        key: |s| s.bindings.smash(Player::Player1),
+        bind: |s, key| s.bindings.keys_mut(Player::Player1).smash = Some(key),
+    },
+    KeyRow {
+        label: "setting-player1-tilt-up",
+        key: |s| s.bindings.tilt(Player::Player1)[0],
+        bind: |s, key| s.bindings.bind_tilt(Player::Player1, 0, key),
+    },
+    KeyRow {
+        label: "setting-player1-tilt-down",
+        key: |s| s.bindings.tilt(Player::Player1)[1],
+        bind: |s, key| s.bindings.bind_tilt(Player::Player1, 1, key),
+    },
+    KeyRow {
+        label: "setting-player1-barrier",
+        key: |s| s.bindings.barrier(Player::Player1),
+        bind: |s, key| s.bindings.keys_mut(Player::Player1).barrier = Some(key),
+    },
+    KeyRow {
+        label: "setting-player2-up",
+        key: |s| s.bindings.player2.up,
+        bind: |s, key| s.bindings.keys_mut(Player::Player2).up = key,
+    },
+    KeyRow {
+        label: "setting-player2-down",
+        key: |s| s.bindings.player2.down,
+        bind: |s, key| s.bindings.keys_mut(Player::Player2).down = key,
+    },
+    KeyRow {
+        label: "setting-player2-smash",
+        key: |s| s.bindings.smash(Player::Player2),
+        bind: |s, key| s.bindings.keys_mut(Player::Player2).smash = Some(key),
+    },
+    KeyRow {
+        label: "setting-player2-tilt-up",
+        key: |s| s.bindings.tilt(Player::Player2)[0],
+        bind: |s, key| s.bindings.bind_tilt(Player::Player2, 0, key),
+    },
+    KeyRow {
+        label: "setting-player2-tilt-down",
+        key: |s| s.bindings.tilt(Player::Player2)[1],
+        bind: |s, key| s.bindings.bind_tilt(Player::Player2, 1, key),
+    },
+    KeyRow {
+        label: "setting-player2-barrier",
+        key: |s| s.bindings.barrier(Player::Player2),
+        bind: |s, key| s.bindings.keys_mut(Player::Player2).barrier = Some(key),
+    },
+];
+
+fn wheel_player_name(settings: &Settings, locale: &Locale) -> String {
+    match settings.bindings.wheel.player {
+        Some(player) => locale.player(player),
+        None => locale.text("setting-off"),
+    }
+}
+
+fn cycle_wheel_player(settings: &mut Settings, step: i32) {
+    let all = [None, Some(Player::Player1), Some(Player::Player2)];
+    let wheel = &mut settings.bindings.wheel;
+    let index = all.iter().position(|p| *p == wheel.player).unwrap_or(0) as i32;
+    wheel.player = all[(index + step).rem_euclid(all.len() as i32) as usize];
+}
+
+fn cycle_wheel_notch(settings: &mut Settings, step: i32) {
+    let wheel = &mut settings.bindings.wheel;
+    let index = WHEEL_NOTCHES
+        .iter()
+        .position(|n| *n == wheel.notch)
+        .unwrap_or(1) as i32;
+    wheel.notch = WHEEL_NOTCHES[(index + step).rem_euclid(WHEEL_NOTCHES.len() as i32) as usize];
+}
+
+/// Wheel movement not yet passed on to the paddle, in pixels.
+#[derive(Resource, Default)]
+struct WheelTravel(f32);
+
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-controls",
+            value: preset_name,
+            change: cycle_preset,
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-wheel-paddle",
+            value: wheel_player_name,
+            change: cycle_wheel_player,
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-wheel-notch",
+            value: |s, l| l.format("wheel-notch", &[("pixels", s.bindings.wheel.notch.into())]),
+            change: cycle_wheel_notch,
+        });
+        for row in PADDLE_ROWS {
+            app.add_key_row(row);
+        }
+        app.add_key_row(KeyRow {
+            label: "setting-restart-key",
+            key: |s| s.bindings.restart,
+            bind: |s, key| s.bindings.restart = key,
+        });
+        app.init_resource::<WheelTravel>();
+        app.add_systems(Update, apply_bindings.run_if(resource_changed::<Settings>));
+        app.add_systems(
+            Update,
+            wheel_paddle
+                .after(move_paddle)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// The co-op partner plays for Player 1 on Player 2's keys.
+fn apply_bindings(
+    settings: Res<Settings>,
+    mut paddles: Query<(&mut Paddle, &Player, Has<Partner>)>,
+) {
+    for (mut paddle, player, partner) in &mut paddles {
+        let keys = settings
+            .bindings
+            .keys(if partner { Player::Player2 } else { *player });
+        paddle.move_up = keys.up;
+        paddle.move_down = keys.down;
+    }
+}
+
+type WheelPaddle<'a> = (
+    &'a Transform,
+    &'a mut Velocity,
+    &'a Player,
+    Has<Partner>,
+    Has<AiPaddle>,
+);
+
+/// Each notch adds to how far the paddle has to go, and the paddle eases the rest of the
+/// way there each frame, so a quick flick glides rather than jumps. Keys still work while
+/// the wheel is idle. Like the keys, the wheel swaps direction while the court is mirrored.
+fn wheel_paddle(
+    mut wheel: EventReader<MouseWheel>,
+    settings: Res<Settings>,
+    arena: Res<Arena>,
+    flip: Res<Flip>,
+    time: Res<Time>,
+    mut travel: ResMut<WheelTravel>,
+    mut paddles: Query<WheelPaddle, With<Paddle>>,
+) {
+    let binding = settings.bindings.wheel;
+    let notches: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / PIXELS_PER_NOTCH,
+        })
+        .sum();
+    let Some(wheel_player) = binding.player else {
+        travel.0 = 0.0;
+        return;
+    };
+    let direction = if flip.flipped() { -1.0 } else { 1.0 };
+    travel.0 += notches * binding.notch as f32 * arena.scale * direction;
+    if travel.0.abs() < 0.5 {
+        travel.0 = 0.0;
+        return;
+    }
+
+    let delta = time.delta_seconds();
+    let step = travel.0 * (1.0 - (-WHEEL_SMOOTHING * delta).exp());
+    for (transform, mut velocity, player, partner, ai) in &mut paddles {
+        // The co-op partner is driven by Player 2's controls, as with the keys.
+        let controls = if partner { Player::Player2 } else { *player };
+        if controls != wheel_player || ai {
+            continue;
+        }
+        velocity.linvel = paddle_velocity(&arena, transform, transform.translation.y + step, delta);
+    }
+    travel.0 -= step;
+}
@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    MatchOver, MatchRules, Paddle, Player, PointScored, Score,
+    ai::{AiDifficulty, GameMode, OpponentDifficulty},
+    arena::{Arena, fit_paddles},
+    check_winner,
+    locale::Locale,
+    score,
+    settings::Settings,
+    toast::Toast,
+};
+
+/// Misses the team can afford before the run is over.
+const LIVES: i32 = 5;
+/// The computer moves up a difficulty every this many team points.
+const POINTS_PER_LEVEL: i32 = 5;
+/// How far in front of Player 1's paddle the second player's stands.
+const SECOND_LINE: f32 = 200.0;
+
+/// The second player's paddle. It plays for Player 1's side, on Player 2's keys.
+#[derive(Component)]
+pub struct Partner;
+
+/// `--mode coop`: both players defend the left goal together against the computer, Player 1
+/// at the back and Player 2 on a second line in front. The team shares one score, shown as
+/// Player 1's, and five lives: each of the computer's points, shown as its score, costs one.
+/// The computer starts easy and gets harder every five team points. The run ends when the
+/// lives do, as a match the computer wins.
+pub struct CoopPlugin;
+
+impl Plugin for CoopPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostStartup,
+            (open_ended_match, spawn_partner).run_if(in_coop),
+        );
+        app.add_systems(
+            Update,
+            (
+                fit_partner
+                    .after(fit_paddles)
+                    .run_if(resource_changed::<Arena>),
+                raise_difficulty.run_if(resource_changed::<Score>),
+            )
+                .run_if(in_coop),
+        );
+        app.add_systems(
+            PostUpdate,
+            lose_lives.after(score).before(check_winner).run_if(in_coop),
+        );
+    }
+}
+
+fn in_coop(mode: Res<GameMode>) -> bool {
+    *mode == GameMode::Coop
+}
+
+/// The team's score has no target; only running out of lives ends a run.
+fn open_ended_match(mut rules: ResMut<MatchRules>) {
+    rules.target_score = i32::MAX;
+}
+
+fn partner_x(arena: &Arena) -> f32 {
+    arena.paddle_x(Player::Player1) + SECOND_LINE
+}
+
+fn spawn_partner(mut commands: Commands, settings: Res<Settings>, arena: Res<Arena>) {
+    let keys = settings.bindings.keys(Player::Player2);
+    commands.spawn((
+        SpriteBundle {
+            transform: Transform::from_translation(Vec3::new(partner_x(&arena), 0.0, 0.0)),
+            sprite: Sprite {
+                color: settings.player_colour(Player::Player1),
+                custom_size: Some(Vec2::new(10.0, 150.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        Paddle {
+            move_up: keys.up,
+            move_down: keys.down,
+        },
+        Player::Player1,
+        Partner,
+        RigidBody::KinematicVelocityBased,
+        Velocity::zero(),
+        Collider::cuboid(5.0, 75.0),
+    ));
+}
+
+/// `fit_paddles` puts every Player 1 paddle at the back; this one goes back to its line.
+fn fit_partner(arena: Res<Arena>, mut partners: Query<&mut Transform, With<Partner>>) {
+    for mut transform in &mut partners {
+        transform.translation.x = partner_x(&arena);
+    }
+}
+
+fn raise_difficulty(score: Res<Score>, mut difficulty: ResMut<OpponentDifficulty>) {
+    let points = score.0.get(&Player::Player1).copied().unwrap_or(0);
+    let level = match points / POINTS_PER_LEVEL {
+        0 => AiDifficulty::Easy,
+        1 => AiDifficulty::Normal,
+        _ => AiDifficulty::Hard,
+    };
+    difficulty.0 = Some(level);
+}
+
+/// Runs before `check_winner`, so the end of a run is seen the same frame as any other
+/// match's end.
+fn lose_lives(
+    mut points: EventReader<PointScored>,
+    score: Res<Score>,
+    locale: Res<Locale>,
+    mut match_over: EventWriter<MatchOver>,
+    mut toasts: EventWriter<Toast>,
+) {
+    for point in points.read() {
+        if point.scorer != Player::Player2 {
+            continue;
+        }
+        let lives = LIVES - score.0.get(&Player::Player2).copied().unwrap_or(0);
+        if lives > 0 {
+            toasts.send(Toast(
+                locale.format("toast-coop-lives", &[("lives", lives.into())]),
+            ));
+            continue;
+        }
+        let team = score.0.get(&Player::Player1).copied().unwrap_or(0);
+        toasts.send(Toast(
+            locale.format("toast-coop-over", &[("points", team.into())]),
+        ));
+        match_over.send(MatchOver(Player::Player2));
+    }
+}
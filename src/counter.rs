@@ -0,0 +1,147 @@
+use bevy::{prelude::*, ui::UiSystem};
+
+use crate::motion::MotionPreferences;
+
+const ROLL_SECONDS: f32 = 0.35;
+/// Height of a line of digits, relative to the font size.
+const LINE_HEIGHT: f32 = 1.2;
+
+/// A number shown in a window one line high, which rolls up to a higher value or down to a
+/// lower one like an odometer instead of changing in place. Spawn it with
+/// [`spawn_counter`] and change it with [`RollingCounter::set`].
+#[derive(Component)]
+pub struct RollingCounter {
+    target: i32,
+    shown: i32,
+    /// The value being rolled to, while the reel moves.
+    rolling_to: Option<i32>,
+    roll: Timer,
+    line_height: f32,
+}
+
+impl RollingCounter {
+    pub fn set(&mut self, value: i32) {
+        self.target = value;
+    }
+}
+
+/// The column holding two lines of digits, moved up or down inside the counter's window.
+#[derive(Component)]
+struct Reel;
+
+/// Adds a counter under `parent`. `extra` goes on the counter itself, to find it again.
+pub fn spawn_counter(parent: &mut ChildBuilder, value: i32, style: TextStyle, extra: impl Bundle) {
+    let line_height = style.font_size * LINE_HEIGHT;
+    let line = |value: i32| {
+        TextBundle::from_section(value.to_string(), style.clone())
+            .with_text_justify(JustifyText::Center)
+            .with_style(Style {
+                height: Val::Px(line_height),
+                ..Default::default()
+            })
+    };
+    parent
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    height: Val::Px(line_height),
+                    overflow: Overflow::clip(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            RollingCounter {
+                target: value,
+                shown: value,
+                rolling_to: None,
+                roll: Timer::from_seconds(ROLL_SECONDS, TimerMode::Once),
+                line_height,
+            },
+            extra,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        top: Val::ZERO,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                Reel,
+            ))
+            .with_children(|p| {
+                p.spawn(line(value));
+                p.spawn(line(value));
+            });
+        });
+}
+
+pub struct CounterPlugin;
+
+impl Plugin for CounterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, roll_counters.before(UiSystem::Layout));
+    }
+}
+
+/// Rolling up, the reel shows the old value above the new one and slides up a line; rolling
+/// down it shows the new value above the old and slides down. With reduced motion the
+/// number just changes.
+fn roll_counters(
+    time: Res<Time>,
+    motion: Res<MotionPreferences>,
+    mut counters: Query<(&mut RollingCounter, &Children)>,
+    mut reels: Query<(&mut Style, &Children), With<Reel>>,
+    mut texts: Query<&mut Text>,
+) {
+    for (mut counter, children) in &mut counters {
+        let Some((mut reel, lines)) = children.first().and_then(|c| reels.get_mut(*c).ok()) else {
+            continue;
+        };
+        let mut set_lines = |top: i32, bottom: i32| {
+            for (line, value) in lines.iter().zip([top, bottom]) {
+                if let Ok(mut text) = texts.get_mut(*line) {
+                    text.sections[0].value = value.to_string();
+                }
+            }
+        };
+
+        let Some(to) = counter.rolling_to else {
+            if counter.target == counter.shown {
+                continue;
+            }
+            let (from, to) = (counter.shown, counter.target);
+            if motion.reduced {
+                counter.shown = to;
+                set_lines(to, to);
+                continue;
+            }
+            if to > from {
+                set_lines(from, to);
+                reel.top = Val::ZERO;
+            } else {
+                set_lines(to, from);
+                reel.top = Val::Px(-counter.line_height);
+            }
+            counter.rolling_to = Some(to);
+            counter.roll.reset();
+            continue;
+        };
+
+        let rising = to > counter.shown;
+        let progress = counter.roll.tick(time.delta()).fraction();
+        // Ease out, like a wheel clicking into place.
+        let eased = 1.0 - (1.0 - progress).powi(3);
+        let offset = if rising { eased } else { 1.0 - eased };
+        reel.top = Val::Px(-offset * counter.line_height);
+        if counter.roll.finished() {
+            counter.shown = to;
+            counter.rolling_to = None;
+            set_lines(to, to);
+            reel.top = Val::ZERO;
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::BALL_RADIUS;
+
+/// Half the thickness of the walls and goal lines. They sit outside the court with their
+/// inner faces on its edges, and are thick enough that a ball at several thousand pixels
+/// a second can't step over one between two physics steps, even without CCD.
+pub const BOUNDARY_HALF_THICKNESS: f32 = 30.0;
+
+/// The wall along the top (`side` 1) or bottom (`side` -1) of a court this size. Walls
+/// run on past the goal lines so the corners are closed.
+pub fn wall(half_width: f32, half_height: f32, side: f32) -> (Transform, Collider) {
+    (
+        Transform::from_xyz(0.0, side * (half_height + BOUNDARY_HALF_THICKNESS), 0.0),
+        Collider::cuboid(
+            half_width + 2.0 * BOUNDARY_HALF_THICKNESS,
+            BOUNDARY_HALF_THICKNESS,
+        ),
+    )
+}
+
+/// The goal line at the right (`side` 1) or left (`side` -1) end of a court this size.
+pub fn goal_line(half_width: f32, half_height: f32, side: f32) -> (Transform, Collider) {
+    (
+        Transform::from_xyz(side * (half_width + BOUNDARY_HALF_THICKNESS), 0.0, 0.0),
+        Collider::cuboid(BOUNDARY_HALF_THICKNESS, half_height),
+    )
+}
+
+/// The physics half of a ball. Continuous collision detection keeps it from passing
+/// through paddles and blocks, which are much thinner than the walls.
+pub fn ball_body() -> impl Bundle {
+    (
+        RigidBody::Dynamic,
+        Collider::ball(BALL_RADIUS),
+        Ccd::enabled(),
+    )
+}
@@ -0,0 +1,202 @@
+use bevy::prelude::*;
+
+use crate::{
+    Player, PointScored, arena::Arena, hits::BallHitPaddle, motion::MotionPreferences,
+    settings::Settings,
+};
+
+/// Rows of fans along each wall, and how far in from it.
+const ROW_INSETS: [f32; 2] = [22.0, 48.0];
+const FAN_SPACING: f32 = 24.0;
+const BODY_SIZE: Vec2 = Vec2::new(14.0, 18.0);
+const HEAD_SIZE: f32 = 9.0;
+/// Faint, so the crowd never competes with the ball.
+const FAN_ALPHA: f32 = 0.18;
+const FAN_COLOURS: [Color; 4] = [
+    Color::srgb(0.8, 0.8, 0.85),
+    Color::srgb(0.6, 0.65, 0.75),
+    Color::srgb(0.75, 0.6, 0.55),
+    Color::srgb(0.55, 0.6, 0.5),
+];
+/// A rally this long sets off a wave.
+const WAVE_RALLY: u32 = 10;
+const WAVE_SECONDS: f32 = 3.0;
+/// Width of the wave, as a fraction of the court.
+const WAVE_WIDTH: f32 = 0.08;
+const CHEER_SECONDS: f32 = 2.0;
+const JUMP_HEIGHT: f32 = 10.0;
+const IDLE_BOB: f32 = 1.5;
+
+/// A spectator: where they stand, and a phase so they don't all move in step.
+#[derive(Component)]
+struct Fan {
+    base: Vec3,
+    phase: f32,
+    /// Whose half of the court they sit along.
+    supports: Player,
+    /// Across the court from left to right, from 0 to 1.
+    across: f32,
+}
+
+#[derive(Default)]
+enum Reaction {
+    #[default]
+    Idle,
+    /// One side celebrating a goal.
+    Cheer(Player, Timer),
+    /// A wave running around the court.
+    Wave(Timer),
+}
+
+#[derive(Resource, Default)]
+struct Crowd {
+    reaction: Reaction,
+    /// Paddle hits since the last point.
+    rally: u32,
+}
+
+/// A stylised crowd behind the court. The scorer's half jumps up on a goal and a long rally
+/// sets off a wave; otherwise they bob about. It is decoration only, so reduced motion
+/// and a chroma-key background hide it.
+pub struct CrowdPlugin;
+
+impl Plugin for CrowdPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Crowd>();
+        app.add_systems(
+            Update,
+            (
+                seat_crowd.run_if(resource_changed::<Arena>),
+                (react, animate_crowd).chain(),
+            ),
+        );
+    }
+}
+
+fn seat_crowd(mut commands: Commands, arena: Res<Arena>, fans: Query<Entity, With<Fan>>) {
+    for fan in &fans {
+        commands.entity(fan).despawn_recursive();
+    }
+    let count = (arena.width / FAN_SPACING) as usize;
+    let mut index = 0;
+    for side in [-1.0, 1.0] {
+        for inset in ROW_INSETS {
+            let y = side * (arena.half_height() - inset);
+            // Rows are staggered by half a seat.
+            let offset = if inset == ROW_INSETS[0] { 0.0 } else { 0.5 };
+            for seat in 0..count {
+                let x = -arena.half_width() + (seat as f32 + offset + 0.5) * FAN_SPACING;
+                let base = Vec3::new(x, y, -2.0);
+                // Cheap and stable, so the same seat always looks the same.
+                let hash = (index * 7919 + 13) % 97;
+                index += 1;
+                let colour = FAN_COLOURS[hash % FAN_COLOURS.len()].with_alpha(FAN_ALPHA);
+                commands
+                    .spawn((
+                        SpriteBundle {
+                            transform: Transform::from_translation(base),
+                            sprite: Sprite {
+                                color: colour,
+                                custom_size: Some(BODY_SIZE),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                        Fan {
+                            base,
+                            phase: hash as f32 / 97.0 * std::f32::consts::TAU,
+                            supports: if x < 0.0 {
+                                Player::Player1
+                            } else {
+                                Player::Player2
+                            },
+                            across: (x + arena.half_width()) / arena.width,
+                        },
+                    ))
+                    .with_children(|p| {
+                        p.spawn(SpriteBundle {
+                            transform: Transform::from_xyz(
+                                0.0,
+                                (BODY_SIZE.y + HEAD_SIZE) / 2.0 + 1.0,
+                                0.0,
+                            ),
+                            sprite: Sprite {
+                                color: colour,
+                                custom_size: Some(Vec2::splat(HEAD_SIZE)),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        });
+                    });
+            }
+        }
+    }
+}
+
+/// A goal beats a wave in progress; a wave waits for the crowd to settle after a goal.
+fn react(
+    time: Res<Time>,
+    mut hits: EventReader<BallHitPaddle>,
+    mut points: EventReader<PointScored>,
+    mut crowd: ResMut<Crowd>,
+) {
+    let crowd = &mut *crowd;
+    for _ in hits.read() {
+        crowd.rally += 1;
+        if crowd.rally == WAVE_RALLY && matches!(crowd.reaction, Reaction::Idle) {
+            crowd.reaction = Reaction::Wave(Timer::from_seconds(WAVE_SECONDS, TimerMode::Once));
+        }
+    }
+    for point in points.read() {
+        crowd.rally = 0;
+        crowd.reaction = Reaction::Cheer(
+            point.scorer,
+            Timer::from_seconds(CHEER_SECONDS, TimerMode::Once),
+        );
+    }
+
+    let finished = match &mut crowd.reaction {
+        Reaction::Idle => false,
+        Reaction::Cheer(_, timer) | Reaction::Wave(timer) => timer.tick(time.delta()).finished(),
+    };
+    if finished {
+        crowd.reaction = Reaction::Idle;
+    }
+}
+
+fn animate_crowd(
+    time: Res<Time>,
+    motion: Res<MotionPreferences>,
+    settings: Res<Settings>,
+    crowd: Res<Crowd>,
+    mut fans: Query<(&Fan, &mut Transform, &mut Visibility)>,
+) {
+    let hidden = motion.reduced || settings.chroma_key.colour().is_some();
+    let shown = if hidden {
+        Visibility::Hidden
+    } else {
+        Visibility::Inherited
+    };
+    let now = time.elapsed_seconds();
+    for (fan, mut transform, mut visibility) in &mut fans {
+        visibility.set_if_neq(shown);
+        if hidden {
+            continue;
+        }
+        let idle = (now * 1.5 + fan.phase).sin() * IDLE_BOB;
+        let lift = match &crowd.reaction {
+            Reaction::Idle => 0.0,
+            Reaction::Cheer(scorer, _) if *scorer == fan.supports => {
+                (now * 9.0 + fan.phase).sin().abs() * JUMP_HEIGHT
+            }
+            Reaction::Cheer(..) => 0.0,
+            Reaction::Wave(timer) => {
+                // The wave runs in from just off one end to just off the other.
+                let front = timer.fraction() * (1.0 + 4.0 * WAVE_WIDTH) - 2.0 * WAVE_WIDTH;
+                let distance = (fan.across - front) / WAVE_WIDTH;
+                (-distance * distance).exp() * JUMP_HEIGHT * 1.5
+            }
+        };
+        transform.translation = fan.base + Vec3::Y * (idle + lift);
+    }
+}
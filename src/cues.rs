@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+use crate::{
+    BALL_RADIUS,
+    arena::Arena,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    motion::MotionPreferences,
+    pool::Pool,
+    settings::Settings,
+    sound::SoundEvent,
+};
+
+const CUE_SECONDS: f32 = 0.4;
+/// More than enough for a busy court; any more would just pile up on each other.
+const MAX_CUES: usize = 32;
+
+pub struct CuesPlugin;
+
+impl Plugin for CuesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Pool::<Cue>::new(MAX_CUES));
+        app.add_setting_row(SettingRow {
+            label: "setting-visual-cues",
+            value: |s, l| on_off(l, s.visual_cues),
+            change: |s, _| s.visual_cues = !s.visual_cues,
+        });
+        app.add_systems(
+            Update,
+            (
+                spawn_cues.run_if(|settings: Res<Settings>| settings.visual_cues),
+                fade_cues,
+            ),
+        );
+    }
+}
+
+/// A short-lived marker mirroring a sound.
+#[derive(Component)]
+struct Cue(Timer);
+
+fn spawn_cues(
+    mut commands: Commands,
+    mut sounds: EventReader<SoundEvent>,
+    mut pool: ResMut<Pool<Cue>>,
+    settings: Res<Settings>,
+    arena: Res<Arena>,
+) {
+    for sound in sounds.read() {
+        let (position, size, colour, rotation) = match *sound {
+            // A bar along the wall where the ball bounced.
+            SoundEvent::WallBounce { position } => (
+                Vec2::new(position.x, arena.half_height() * position.y.signum()),
+                Vec2::new(120.0, 16.0),
+                Color::WHITE,
+                0.0,
+            ),
+            // A diamond just in front of the paddle.
+            SoundEvent::PaddleHit { player, position } => (
+                position - Vec2::X * position.x.signum() * (BALL_RADIUS + 20.0),
+                Vec2::splat(16.0),
+                settings.player_colour(player),
+                std::f32::consts::FRAC_PI_4,
+            ),
+            // The whole goal line lights up.
+            SoundEvent::Goal { scorer } => (
+                Vec2::new(arena.goal_x(scorer), 0.0),
+                Vec2::new(24.0, arena.height),
+                settings.player_colour(scorer),
+                0.0,
+            ),
+            // A band across the top of the court.
+            SoundEvent::Fanfare { winner } => (
+                Vec2::new(0.0, arena.half_height()),
+                Vec2::new(arena.width, 24.0),
+                settings.player_colour(winner),
+                0.0,
+            ),
+        };
+
+        pool.take(
+            &mut commands,
+            (
+                SpriteBundle {
+                    transform: Transform::from_translation(position.extend(2.0))
+                        .with_rotation(Quat::from_rotation_z(rotation)),
+                    sprite: Sprite {
+                        color: colour,
+                        custom_size: Some(size),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                Cue(Timer::from_seconds(CUE_SECONDS, TimerMode::Once)),
+            ),
+        );
+    }
+}
+
+fn fade_cues(
+    mut commands: Commands,
+    mut pool: ResMut<Pool<Cue>>,
+    mut cues: Query<(Entity, &mut Cue, &mut Sprite)>,
+    motion: Res<MotionPreferences>,
+    time: Res<Time>,
+) {
+    for (entity, mut cue, mut sprite) in &mut cues {
+        if cue.0.tick(time.delta()).finished() {
+            pool.give_back(&mut commands, entity);
+            continue;
+        }
+        // With reduced motion the cue stays solid and simply disappears, instead of flashing out.
+        if !motion.reduced {
+            sprite.color.set_alpha(cue.0.fraction_remaining());
+        }
+    }
+}
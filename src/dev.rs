@@ -0,0 +1,22 @@
+use bevy::{input::common_conditions::input_toggle_active, prelude::*};
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+
+use crate::{Ball, Paddle, Player, Wall, ai_telemetry::AiTelemetryPlugin};
+
+/// Maintainer tools, only built with `--features dev`. F1 shows a world inspector for
+/// tuning ball velocity, restitution and paddle transforms while the game runs, and F4
+/// graphs how the computer opponent is playing.
+pub struct DevPlugin;
+
+impl Plugin for DevPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Ball>();
+        app.register_type::<Paddle>();
+        app.register_type::<Player>();
+        app.register_type::<Wall>();
+        app.add_plugins(
+            WorldInspectorPlugin::new().run_if(input_toggle_active(false, KeyCode::F1)),
+        );
+        app.add_plugins(AiTelemetryPlugin);
+    }
+}
@@ -0,0 +1,120 @@
+use bevy::{
+    diagnostic::{
+        Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, EntityCountDiagnosticsPlugin,
+        FrameTimeDiagnosticsPlugin, RegisterDiagnostic,
+    },
+    prelude::*,
+    utils::Instant,
+};
+use bevy_rapier2d::prelude::*;
+
+use crate::fonts::FontStack;
+
+/// Wall-clock time Rapier spent in its simulation step, in milliseconds.
+pub const PHYSICS_STEP_TIME: DiagnosticPath = DiagnosticPath::const_new("physics/step_time");
+/// Number of rigid bodies in the world.
+pub const PHYSICS_BODIES: DiagnosticPath = DiagnosticPath::const_new("physics/bodies");
+
+pub struct DiagnosticsOverlayPlugin;
+
+impl Plugin for DiagnosticsOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((FrameTimeDiagnosticsPlugin, EntityCountDiagnosticsPlugin));
+        app.register_diagnostic(Diagnostic::new(PHYSICS_STEP_TIME).with_suffix("ms"));
+        app.register_diagnostic(Diagnostic::new(PHYSICS_BODIES));
+        app.init_resource::<StepStarted>();
+        app.add_systems(
+            PostUpdate,
+            (
+                start_step_timer.before(PhysicsSet::StepSimulation),
+                (stop_step_timer, count_bodies).after(PhysicsSet::StepSimulation),
+            ),
+        );
+        app.add_systems(Startup, spawn_overlay);
+        app.add_systems(Update, (toggle_overlay, update_overlay).chain());
+    }
+}
+
+#[derive(Resource, Default)]
+struct StepStarted(Option<Instant>);
+
+fn start_step_timer(mut started: ResMut<StepStarted>) {
+    started.0 = Some(Instant::now());
+}
+
+fn stop_step_timer(mut started: ResMut<StepStarted>, mut diagnostics: Diagnostics) {
+    if let Some(start) = started.0.take() {
+        diagnostics.add_measurement(&PHYSICS_STEP_TIME, || {
+            start.elapsed().as_secs_f64() * 1000.0
+        });
+    }
+}
+
+fn count_bodies(bodies: Query<(), With<RigidBody>>, mut diagnostics: Diagnostics) {
+    diagnostics.add_measurement(&PHYSICS_BODIES, || bodies.iter().count() as f64);
+}
+
+#[derive(Component)]
+struct DiagnosticsText;
+
+fn spawn_overlay(mut commands: Commands, fonts: Res<FontStack>) {
+    commands.spawn((
+        TextBundle {
+            visibility: Visibility::Hidden,
+            ..fonts
+                .text_bundle("", 16.)
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(8.0),
+                    right: Val::Px(8.0),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..Default::default()
+                })
+                .with_background_color(Color::srgba(0.0, 0.0, 0.0, 0.6))
+        },
+        DiagnosticsText,
+    ));
+}
+
+/// F3, as in most games.
+fn toggle_overlay(
+    input: Res<ButtonInput<KeyCode>>,
+    mut overlays: Query<&mut Visibility, With<DiagnosticsText>>,
+) {
+    if !input.just_pressed(KeyCode::F3) {
+        return;
+    }
+    for mut visibility in &mut overlays {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Visible,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+fn update_overlay(
+    store: Res<DiagnosticsStore>,
+    fonts: Res<FontStack>,
+    mut overlays: Query<(&mut Text, &Visibility), With<DiagnosticsText>>,
+) {
+    let value = |path: &DiagnosticPath| {
+        store
+            .get(path)
+            .and_then(Diagnostic::smoothed)
+            .unwrap_or_default()
+    };
+    for (mut text, visibility) in &mut overlays {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        let lines = format!(
+            "FPS: {:.0}\nFrame: {:.2} ms\nEntities: {:.0}\nPhysics step: {:.2} ms\nBodies: {:.0}",
+            value(&FrameTimeDiagnosticsPlugin::FPS),
+            value(&FrameTimeDiagnosticsPlugin::FRAME_TIME),
+            value(&EntityCountDiagnosticsPlugin::ENTITY_COUNT),
+            value(&PHYSICS_STEP_TIME),
+            value(&PHYSICS_BODIES),
+        );
+        fonts.set(&mut text, &lines);
+    }
+}
@@ -0,0 +1,211 @@
+use bevy::{
+    prelude::*,
+    window::{PresentMode, PrimaryWindow, WindowMode},
+};
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
+
+use crate::{
+    WINDOW_HEIGHT,
+    arena::Arena,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    settings::Settings,
+};
+
+const FPS_CAPS: [Option<u32>; 6] = [None, Some(30), Some(60), Some(120), Some(144), Some(240)];
+/// Manual UI scales, in percent. `None` follows the window size.
+const UI_SCALES: [Option<u32>; 7] = [
+    None,
+    Some(50),
+    Some(75),
+    Some(100),
+    Some(125),
+    Some(150),
+    Some(200),
+];
+
+/// Frame pacing, for high-refresh monitors and laptops on battery.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FrameRate {
+    pub vsync: bool,
+    /// Frames per second the game is held to on top of (or instead of) vsync.
+    pub cap: Option<u32>,
+}
+
+impl Default for FrameRate {
+    fn default() -> Self {
+        FrameRate {
+            vsync: true,
+            cap: None,
+        }
+    }
+}
+
+/// Steps through a list of choices for a menu row, wrapping at either end.
+fn cycle(choices: &[Option<u32>], current: Option<u32>, step: i32) -> Option<u32> {
+    let index = choices.iter().position(|c| *c == current).unwrap_or(0) as i32;
+    choices[(index + step).rem_euclid(choices.len() as i32) as usize]
+}
+
+/// How the game occupies the screen.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum DisplayMode {
+    #[default]
+    Windowed,
+    /// A window without decorations covering the whole monitor. Alt-tabs instantly.
+    Borderless,
+    /// Takes over the monitor's video mode.
+    Exclusive,
+}
+
+impl DisplayMode {
+    const ALL: [DisplayMode; 3] = [
+        DisplayMode::Windowed,
+        DisplayMode::Borderless,
+        DisplayMode::Exclusive,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            DisplayMode::Windowed => "display-mode-windowed",
+            DisplayMode::Borderless => "display-mode-borderless",
+            DisplayMode::Exclusive => "display-mode-exclusive",
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|m| *m == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+
+    fn window_mode(self) -> WindowMode {
+        match self {
+            DisplayMode::Windowed => WindowMode::Windowed,
+            DisplayMode::Borderless => WindowMode::BorderlessFullscreen,
+            DisplayMode::Exclusive => WindowMode::Fullscreen,
+        }
+    }
+}
+
+pub struct DisplayPlugin;
+
+impl Plugin for DisplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-window-mode",
+            value: |s, l| l.text(s.display_mode.name()),
+            change: |s, step| s.display_mode = s.display_mode.cycle(step),
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-vsync",
+            value: |s, l| on_off(l, s.frame_rate.vsync),
+            change: |s, _| s.frame_rate.vsync = !s.frame_rate.vsync,
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-fps-cap",
+            value: |s, l| match s.frame_rate.cap {
+                Some(cap) => cap.to_string(),
+                None => l.text("setting-off"),
+            },
+            change: |s, step| s.frame_rate.cap = cycle(&FPS_CAPS, s.frame_rate.cap, step),
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-ui-scale",
+            value: |s, l| match s.ui_scale {
+                Some(percent) => l.format("setting-percent", &[("value", percent.into())]),
+                None => l.text("setting-auto"),
+            },
+            change: |s, step| s.ui_scale = cycle(&UI_SCALES, s.ui_scale, step),
+        });
+        app.add_systems(
+            Update,
+            (
+                toggle_fullscreen,
+                (apply_window_mode, apply_present_mode).run_if(resource_changed::<Settings>),
+            )
+                .chain(),
+        );
+        // The browser paces frames itself, and sleeping would block its event loop.
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Last, limit_frame_rate);
+        app.add_systems(
+            Update,
+            fit_ui_scale.run_if(resource_changed::<Arena>.or_else(resource_changed::<Settings>)),
+        );
+    }
+}
+
+/// F11 or Alt+Enter switches between a window and borderless fullscreen, or back to a
+/// window from exclusive mode. The arena follows the resulting resize on its own.
+fn toggle_fullscreen(input: Res<ButtonInput<KeyCode>>, mut settings: ResMut<Settings>) {
+    let alt_enter = input.any_pressed([KeyCode::AltLeft, KeyCode::AltRight])
+        && input.just_pressed(KeyCode::Enter);
+    if alt_enter || input.just_pressed(KeyCode::F11) {
+        settings.display_mode = match settings.display_mode {
+            DisplayMode::Windowed => DisplayMode::Borderless,
+            DisplayMode::Borderless | DisplayMode::Exclusive => DisplayMode::Windowed,
+        };
+    }
+}
+
+fn apply_window_mode(
+    settings: Res<Settings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    let mode = settings.display_mode.window_mode();
+    if window.mode != mode {
+        window.mode = mode;
+    }
+}
+
+fn apply_present_mode(
+    settings: Res<Settings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    let present_mode = if settings.frame_rate.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+}
+
+/// Sleeps off whatever is left of the frame budget at the end of each update.
+#[cfg(not(target_arch = "wasm32"))]
+fn limit_frame_rate(settings: Res<Settings>, mut frame_start: Local<Option<Instant>>) {
+    let Some(cap) = settings.frame_rate.cap else {
+        *frame_start = None;
+        return;
+    };
+    let budget = Duration::from_secs_f64(1.0 / f64::from(cap.max(1)));
+    if let Some(start) = *frame_start {
+        let elapsed = start.elapsed();
+        if elapsed < budget {
+            std::thread::sleep(budget - elapsed);
+        }
+    }
+    *frame_start = Some(Instant::now());
+}
+
+/// The UI is laid out for a 720 pixel tall window; shrink it on anything smaller, such
+/// as a phone, so the scoreboard and menu still fit. The player's own scale wins.
+/// Bevy already multiplies by the monitor's scale factor, so this is on top of that.
+fn fit_ui_scale(arena: Res<Arena>, settings: Res<Settings>, mut ui_scale: ResMut<UiScale>) {
+    let scale = match settings.ui_scale {
+        Some(percent) => percent as f32 / 100.0,
+        None => (arena.height / arena.scale / WINDOW_HEIGHT).clamp(0.5, 1.0),
+    };
+    if ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+}
@@ -0,0 +1,328 @@
+use bevy::{color::palettes::css::YELLOW, prelude::*, window::PrimaryWindow};
+use std::path::PathBuf;
+
+use crate::{
+    GameEvents, GameState, Player,
+    arena::Arena,
+    camera::MainCamera,
+    fonts::FontStack,
+    layout::{ArenaLayout, GRID_CELL, cell_at, cell_centre},
+    locale::{Locale, LocalizedText},
+    menu::on_off,
+    mutators::Mutator,
+    storage::UserFiles,
+    toast::Toast,
+};
+
+const GOAL_SIZE_STEP: f32 = 0.125;
+const GRID_COLOUR: Color = Color::srgba(1.0, 1.0, 1.0, 0.08);
+const BUTTON_COLOUR: Color = Color::srgb(0.25, 0.25, 0.3);
+const BUTTON_HOVER_COLOUR: Color = Color::srgb(0.35, 0.35, 0.42);
+
+/// Where the arena is saved.
+#[derive(Resource)]
+struct EditorFile(PathBuf);
+
+#[derive(Event, Clone, Copy, PartialEq, Eq)]
+enum EditorAction {
+    /// Play the arena as it stands, or go back to editing it.
+    ToggleTestPlay,
+    Save,
+}
+
+#[derive(Component)]
+struct EditorButton(EditorAction);
+
+#[derive(Component)]
+struct EditorStatus;
+
+/// `--editor`: place blocks on a grid, narrow the goals and pick mutators, try the arena
+/// out, then save it as RON for `--arena`. The court is frozen while editing.
+pub struct EditorPlugin {
+    pub path: PathBuf,
+}
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(EditorFile(self.path.clone()));
+        app.add_event::<EditorAction>();
+        app.add_systems(Startup, (spawn_panel, start_editing));
+        app.add_systems(
+            Update,
+            (
+                (edit_layout, draw_grid).run_if(in_state(GameState::Intermission)),
+                (press_buttons, shortcuts, perform_actions).chain(),
+                draw_panel,
+            ),
+        );
+    }
+}
+
+fn start_editing(mut next: ResMut<NextState<GameState>>) {
+    next.set(GameState::Intermission);
+}
+
+fn spawn_panel(mut commands: Commands, fonts: Res<FontStack>) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(12.0),
+                left: Val::Px(12.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            z_index: ZIndex::Global(5),
+            ..Default::default()
+        })
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 18.), EditorStatus));
+            p.spawn(NodeBundle {
+                style: Style {
+                    column_gap: Val::Px(8.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .with_children(|p| {
+                for (action, label) in [
+                    (EditorAction::ToggleTestPlay, "editor-test-play"),
+                    (EditorAction::Save, "editor-save"),
+                ] {
+                    p.spawn((
+                        ButtonBundle {
+                            style: Style {
+                                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                                ..Default::default()
+                            },
+                            background_color: BUTTON_COLOUR.into(),
+                            ..Default::default()
+                        },
+                        EditorButton(action),
+                    ))
+                    .with_children(|p| {
+                        p.spawn((fonts.text_bundle("", 20.), LocalizedText(label)));
+                    });
+                }
+            });
+        });
+}
+
+/// Clicking a cell adds or removes a block, `[` and `]` narrow or widen the goals, and the
+/// number keys switch mutators.
+fn edit_layout(
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform), MainCamera>,
+    buttons: Query<&Interaction, With<EditorButton>>,
+    arena: Res<Arena>,
+    mut layout: ResMut<ArenaLayout>,
+) {
+    if keys.just_pressed(KeyCode::BracketLeft) {
+        layout.goal_size = (layout.goal_size - GOAL_SIZE_STEP).max(ArenaLayout::MIN_GOAL_SIZE);
+    }
+    if keys.just_pressed(KeyCode::BracketRight) {
+        layout.goal_size = (layout.goal_size + GOAL_SIZE_STEP).min(1.0);
+    }
+    let digits = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+        KeyCode::Digit7,
+        KeyCode::Digit8,
+        KeyCode::Digit9,
+        KeyCode::Digit0,
+    ];
+    for (key, mutator) in digits.into_iter().zip(Mutator::ALL) {
+        if keys.just_pressed(key) {
+            layout.toggle_mutator(mutator);
+        }
+    }
+
+    if !mouse.just_pressed(MouseButton::Left) || buttons.iter().any(|i| *i != Interaction::None) {
+        return;
+    }
+    let Some(point) = cursor_position(&windows, &cameras) else {
+        return;
+    };
+    if point.x.abs() < arena.half_width() && point.y.abs() < arena.half_height() {
+        layout.toggle_obstacle(cell_at(point));
+    }
+}
+
+fn cursor_position(
+    windows: &Query<&Window, With<PrimaryWindow>>,
+    cameras: &Query<(&Camera, &GlobalTransform), MainCamera>,
+) -> Option<Vec2> {
+    let cursor = windows.get_single().ok()?.cursor_position()?;
+    let (camera, camera_transform) = cameras.get_single().ok()?;
+    camera.viewport_to_world_2d(camera_transform, cursor)
+}
+
+fn draw_grid(
+    mut gizmos: Gizmos,
+    arena: Res<Arena>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform), MainCamera>,
+) {
+    let (half_width, half_height) = (arena.half_width(), arena.half_height());
+    let columns = (half_width / GRID_CELL).ceil() as i32;
+    let rows = (half_height / GRID_CELL).ceil() as i32;
+    for column in -columns..=columns {
+        let x = column as f32 * GRID_CELL;
+        gizmos.line_2d(
+            Vec2::new(x, -half_height),
+            Vec2::new(x, half_height),
+            GRID_COLOUR,
+        );
+    }
+    for row in -rows..=rows {
+        let y = row as f32 * GRID_CELL;
+        gizmos.line_2d(
+            Vec2::new(-half_width, y),
+            Vec2::new(half_width, y),
+            GRID_COLOUR,
+        );
+    }
+
+    if let Some(point) = cursor_position(&windows, &cameras) {
+        gizmos.rect_2d(
+            cell_centre(cell_at(point)),
+            0.0,
+            Vec2::splat(GRID_CELL),
+            YELLOW,
+        );
+    }
+}
+
+fn press_buttons(
+    mut buttons: Query<(&Interaction, &EditorButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut actions: EventWriter<EditorAction>,
+) {
+    for (interaction, EditorButton(action), mut background) in &mut buttons {
+        background.0 = match interaction {
+            Interaction::None => BUTTON_COLOUR,
+            Interaction::Hovered | Interaction::Pressed => BUTTON_HOVER_COLOUR,
+        };
+        if *interaction == Interaction::Pressed {
+            actions.send(*action);
+        }
+    }
+}
+
+/// Enter and Tab switch between editing and test play, Ctrl+S saves.
+fn shortcuts(keys: Res<ButtonInput<KeyCode>>, mut actions: EventWriter<EditorAction>) {
+    if keys.any_just_pressed([KeyCode::Enter, KeyCode::Tab]) {
+        actions.send(EditorAction::ToggleTestPlay);
+    }
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if ctrl && keys.just_pressed(KeyCode::KeyS) {
+        actions.send(EditorAction::Save);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn perform_actions(
+    mut actions: EventReader<EditorAction>,
+    state: Res<State<GameState>>,
+    layout: Res<ArenaLayout>,
+    file: Res<EditorFile>,
+    files: Res<UserFiles>,
+    locale: Res<Locale>,
+    mut next: ResMut<NextState<GameState>>,
+    mut game_events: EventWriter<GameEvents>,
+    mut toasts: EventWriter<Toast>,
+) {
+    for action in actions.read() {
+        match action {
+            EditorAction::ToggleTestPlay => {
+                // A fresh serve either way, so test play starts from the middle and the
+                // editor shows the court without a ball stuck in a wall.
+                game_events.send(GameEvents::ResetBall(Player::Player1));
+                match state.get() {
+                    GameState::Intermission => next.set(GameState::Playing),
+                    GameState::Playing => next.set(GameState::Intermission),
+                    GameState::Paused
+                    | GameState::Replay
+                    | GameState::Loading
+                    | GameState::Splash => {}
+                }
+            }
+            EditorAction::Save => {
+                let path = file.0.display().to_string();
+                let message = match layout.save(&files, &file.0) {
+                    Ok(()) => {
+                        info!("saved arena to {path}");
+                        locale.format("editor-saved", &[("path", path.into())])
+                    }
+                    Err(err) => {
+                        warn!("failed to save arena to {path}: {err}");
+                        locale.format("editor-save-failed", &[("error", err.to_string().into())])
+                    }
+                };
+                toasts.send(Toast(message));
+            }
+        }
+    }
+}
+
+fn draw_panel(
+    state: Res<State<GameState>>,
+    layout: Res<ArenaLayout>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    mut status: Query<&mut Text, With<EditorStatus>>,
+    buttons: Query<(&EditorButton, &Children)>,
+    mut labels: Query<(&mut Text, &mut LocalizedText), Without<EditorStatus>>,
+) {
+    if !(state.is_changed() || layout.is_changed() || locale.is_changed()) {
+        return;
+    }
+    let editing = *state.get() == GameState::Intermission;
+
+    let mut lines = vec![locale.text("editor-title")];
+    if editing {
+        lines.push(locale.text("editor-help"));
+        lines.push(locale.format(
+            "editor-goal-size",
+            &[(
+                "percent",
+                ((layout.goal_size * 100.0).round() as i32).into(),
+            )],
+        ));
+        for (index, mutator) in Mutator::ALL.into_iter().enumerate() {
+            let active = layout.mutators.contains(&mutator);
+            lines.push(format!(
+                "{}. {}: {}",
+                index + 1,
+                locale.text(mutator.name()),
+                on_off(&locale, active)
+            ));
+        }
+    }
+    let value = lines.join("\n");
+    for mut text in &mut status {
+        fonts.set(&mut text, &value);
+    }
+
+    for (EditorButton(action), children) in &buttons {
+        if *action != EditorAction::ToggleTestPlay {
+            continue;
+        }
+        let id = if editing {
+            "editor-test-play"
+        } else {
+            "editor-edit"
+        };
+        let mut iter = labels.iter_many_mut(children);
+        while let Some((mut text, mut label)) = iter.fetch_next() {
+            label.0 = id;
+            fonts.set(&mut text, &locale.text(id));
+        }
+    }
+}
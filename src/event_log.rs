@@ -0,0 +1,146 @@
+use bevy::{log::BoxedLayer, prelude::*, state::state::StateTransitionEvent};
+
+use crate::{
+    GameEvents, GameState, MatchOver, Paddle, Player, PointScored, Score, check_winner,
+    hits::{BallHitPaddle, BallHitWall},
+    score,
+};
+
+/// Target of every gameplay record. The session log keeps these and nothing else.
+const TARGET: &str = "pong::events";
+
+#[cfg(not(target_arch = "wasm32"))]
+const LOG_DIR: &str = "logs";
+
+/// A tracing layer writing gameplay records to `logs/session-<unix time>.log`, one line
+/// each, for working out afterwards what the physics did. Passed to `LogPlugin`, so it is
+/// set up before the app exists and has nowhere to report errors but stderr.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn session_layer(_app: &mut App) -> Option<BoxedLayer> {
+    use bevy::{
+        log::tracing_subscriber::{Layer, filter::filter_fn, fmt},
+        utils::SystemTime,
+    };
+    use std::{fs::File, path::PathBuf, sync::Mutex};
+
+    let secs = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let path = PathBuf::from(LOG_DIR).join(format!("session-{secs}.log"));
+    let file = std::fs::create_dir_all(LOG_DIR).and_then(|_| File::create(&path));
+    let file = match file {
+        Ok(file) => file,
+        Err(err) => {
+            eprintln!("no session log, could not create {}: {err}", path.display());
+            return None;
+        }
+    };
+    let layer = fmt::layer()
+        .with_ansi(false)
+        .with_writer(Mutex::new(file))
+        .with_filter(filter_fn(|meta| meta.target() == TARGET));
+    Some(Box::new(layer))
+}
+
+/// Browsers have no filesystem; the records still reach the console.
+#[cfg(target_arch = "wasm32")]
+pub fn session_layer(_app: &mut App) -> Option<BoxedLayer> {
+    None
+}
+
+pub struct EventLogPlugin;
+
+impl Plugin for EventLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (log_paddle_hits, log_wall_hits, log_state_changes));
+        app.add_systems(
+            PostUpdate,
+            (
+                log_game_events.after(score),
+                log_match_over.after(check_winner),
+            ),
+        );
+    }
+}
+
+/// Every record carries the game clock, which unlike the wall clock stops while paused.
+fn log_game_events(
+    mut events: EventReader<GameEvents>,
+    mut points: EventReader<PointScored>,
+    score: Res<Score>,
+    time: Res<Time>,
+) {
+    let t = time.elapsed_seconds();
+    for point in points.read() {
+        info!(
+            target: TARGET,
+            t = point.time_secs,
+            player = ?point.scorer,
+            p1 = score.0.get(&Player::Player1).copied().unwrap_or_default(),
+            p2 = score.0.get(&Player::Player2).copied().unwrap_or_default(),
+            rally = point.rally,
+            ball_speed = point.ball_speed,
+            "point scored"
+        );
+    }
+    for GameEvents::ResetBall(player) in events.read() {
+        info!(target: TARGET, t, ?player, "ball reset");
+    }
+}
+
+fn log_paddle_hits(
+    mut hits: EventReader<BallHitPaddle>,
+    paddles: Query<&Transform, With<Paddle>>,
+    time: Res<Time>,
+) {
+    for hit in hits.read() {
+        let Ok(paddle) = paddles.get(hit.paddle) else {
+            continue;
+        };
+        info!(
+            target: TARGET,
+            t = time.elapsed_seconds(),
+            player = ?hit.player,
+            paddle_y = paddle.translation.y,
+            contact = ?hit.contact,
+            velocity = ?hit.relative_velocity,
+            "paddle hit"
+        );
+    }
+}
+
+fn log_wall_hits(mut hits: EventReader<BallHitWall>, time: Res<Time>) {
+    for hit in hits.read() {
+        info!(
+            target: TARGET,
+            t = time.elapsed_seconds(),
+            ball = ?hit.ball,
+            wall = ?hit.wall,
+            contact = ?hit.contact,
+            velocity = ?hit.relative_velocity,
+            "wall bounce"
+        );
+    }
+}
+
+fn log_state_changes(
+    mut transitions: EventReader<StateTransitionEvent<GameState>>,
+    time: Res<Time>,
+) {
+    for transition in transitions.read() {
+        info!(
+            target: TARGET,
+            t = time.elapsed_seconds(),
+            from = ?transition.exited,
+            to = ?transition.entered,
+            "state change"
+        );
+    }
+}
+
+fn log_match_over(mut events: EventReader<MatchOver>, time: Res<Time>) {
+    for MatchOver(winner) in events.read() {
+        info!(target: TARGET, t = time.elapsed_seconds(), ?winner, "match over");
+    }
+}
@@ -0,0 +1,152 @@
+use bevy::{asset::LoadState, prelude::*};
+
+/// Latin, Greek and Cyrillic.
+const MAIN_FONT: &str = "fonts/DejaVuSans.ttf";
+/// Chinese, Japanese and Korean. Not bundled because of its size; a Noto Sans CJK build
+/// dropped at this path is used if it is there.
+const CJK_FONT: &str = "fonts/NotoSansCJK-Regular.otf";
+
+/// The fonts text is rendered with, in fallback order. Bevy picks one font per text
+/// section, so [`FontStack::text`] splits strings into runs and gives each run a font
+/// that has glyphs for it.
+#[derive(Resource)]
+pub struct FontStack {
+    main: Handle<Font>,
+    /// Only when the file is there, so a missing one doesn't show up as a load error.
+    cjk: Option<Handle<Font>>,
+    cjk_loaded: bool,
+}
+
+impl FromWorld for FontStack {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        FontStack {
+            main: asset_server.load(MAIN_FONT),
+            cjk: cjk_font_present().then(|| asset_server.load(CJK_FONT)),
+            cjk_loaded: false,
+        }
+    }
+}
+
+impl FontStack {
+    /// Every font in the stack, for preloading.
+    pub fn handles(&self) -> Vec<UntypedHandle> {
+        std::iter::once(&self.main)
+            .chain(&self.cjk)
+            .map(|font| font.clone().untyped())
+            .collect()
+    }
+
+    /// A `Text` for `value`, with one section per run of characters needing a different font.
+    pub fn text(&self, value: &str, style: TextStyle) -> Text {
+        Text::from_sections(self.sections(value, style))
+    }
+
+    pub fn text_bundle(&self, value: &str, font_size: f32) -> TextBundle {
+        TextBundle {
+            text: self.text(
+                value,
+                TextStyle {
+                    font_size,
+                    ..Default::default()
+                },
+            ),
+            ..Default::default()
+        }
+    }
+
+    /// Replaces the contents of `text`, keeping the style of its first section.
+    pub fn set(&self, text: &mut Text, value: &str) {
+        let style = text
+            .sections
+            .first()
+            .map(|section| section.style.clone())
+            .unwrap_or_default();
+        text.sections = self.sections(value, style);
+    }
+
+    fn sections(&self, value: &str, style: TextStyle) -> Vec<TextSection> {
+        let mut sections: Vec<TextSection> = Vec::new();
+        let mut current: Option<&Handle<Font>> = None;
+
+        for c in value.chars() {
+            // Spaces and punctuation stay with whatever run they're in.
+            let font = match current {
+                Some(font) if c.is_ascii() => font,
+                _ => self.font_for(c),
+            };
+            match sections.last_mut() {
+                Some(section) if current == Some(font) => section.value.push(c),
+                _ => sections.push(TextSection::new(
+                    c.to_string(),
+                    TextStyle {
+                        font: font.clone(),
+                        ..style.clone()
+                    },
+                )),
+            }
+            current = Some(font);
+        }
+
+        if sections.is_empty() {
+            sections.push(TextSection::new(
+                "",
+                TextStyle {
+                    font: self.main.clone(),
+                    ..style
+                },
+            ));
+        }
+        sections
+    }
+
+    fn font_for(&self, c: char) -> &Handle<Font> {
+        match &self.cjk {
+            Some(cjk) if self.cjk_loaded && is_cjk(c) => cjk,
+            _ => &self.main,
+        }
+    }
+}
+
+/// Looks in the assets folder on disk. Android and web builds read their assets from the
+/// app package or the server, so they never have it.
+#[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+fn cjk_font_present() -> bool {
+    bevy::asset::io::file::FileAssetReader::get_base_path()
+        .join("assets")
+        .join(CJK_FONT)
+        .is_file()
+}
+
+#[cfg(any(target_arch = "wasm32", target_os = "android"))]
+fn cjk_font_present() -> bool {
+    false
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3000..=0x9FFF | 0xAC00..=0xD7AF | 0xF900..=0xFAFF | 0xFF00..=0xFFEF
+    )
+}
+
+pub struct FontsPlugin;
+
+impl Plugin for FontsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FontStack>();
+        app.add_systems(
+            PreUpdate,
+            watch_cjk_font.run_if(|fonts: Res<FontStack>| fonts.cjk.is_some() && !fonts.cjk_loaded),
+        );
+    }
+}
+
+fn watch_cjk_font(asset_server: Res<AssetServer>, mut fonts: ResMut<FontStack>) {
+    let Some(cjk) = &fonts.cjk else {
+        return;
+    };
+    if asset_server.load_state(cjk) == LoadState::Loaded {
+        fonts.cjk_loaded = true;
+    }
+}
@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    MatchOver, MatchRules, Player, Score,
+    fonts::FontStack,
+    hud::HudWidget,
+    locale::Locale,
+    menu::{AppSettingsExt, SettingRow},
+    settings::Settings,
+    start_new_match,
+};
+
+const MAX_HEAD_START: i32 = 5;
+/// Paddle size and speed change in steps of this fraction, up to `MAX_STEPS` either way.
+const STEP: f32 = 0.1;
+const MAX_STEPS: i32 = 3;
+
+/// Evens out a match between players of different strength. All zero is no handicap.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Handicap {
+    /// Points on the board at the start of every match.
+    pub head_start: i32,
+    /// Steps of `STEP` bigger, or smaller when negative.
+    pub paddle_size: i32,
+    /// Steps of `STEP` faster, or slower when negative.
+    pub paddle_speed: i32,
+}
+
+impl Handicap {
+    pub fn size_factor(&self) -> f32 {
+        1.0 + self.paddle_size as f32 * STEP
+    }
+
+    pub fn speed_factor(&self) -> f32 {
+        1.0 + self.paddle_speed as f32 * STEP
+    }
+
+    fn summary(&self, locale: &Locale) -> String {
+        let mut parts = Vec::new();
+        if self.head_start > 0 {
+            parts.push(locale.format("handicap-head-start", &[("points", self.head_start.into())]));
+        }
+        if self.paddle_size != 0 {
+            parts.push(locale.format(
+                "handicap-paddle-size",
+                &[("percent", percent(self.paddle_size).into())],
+            ));
+        }
+        if self.paddle_speed != 0 {
+            parts.push(locale.format(
+                "handicap-paddle-speed",
+                &[("percent", percent(self.paddle_speed).into())],
+            ));
+        }
+        parts.join(" · ")
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Handicaps {
+    pub player1: Handicap,
+    pub player2: Handicap,
+}
+
+impl Handicaps {
+    pub fn get(&self, player: Player) -> &Handicap {
+        match player {
+            Player::Player1 => &self.player1,
+            Player::Player2 => &self.player2,
+        }
+    }
+}
+
+fn percent(steps: i32) -> String {
+    format!("{:+}%", steps * (STEP * 100.0) as i32)
+}
+
+fn cycle_steps(steps: i32, step: i32) -> i32 {
+    (steps + MAX_STEPS + step).rem_euclid(2 * MAX_STEPS + 1) - MAX_STEPS
+}
+
+fn cycle_head_start(points: i32, step: i32) -> i32 {
+    (points + step).rem_euclid(MAX_HEAD_START + 1)
+}
+
+fn head_start_value(handicap: &Handicap, locale: &Locale) -> String {
+    match handicap.head_start {
+        0 => locale.text("setting-off"),
+        points => format!("+{points}"),
+    }
+}
+
+fn steps_value(steps: i32, locale: &Locale) -> String {
+    match steps {
+        0 => locale.text("setting-off"),
+        steps => percent(steps),
+    }
+}
+
+/// The handicap line under one player's side of the scoreboard.
+#[derive(Component)]
+struct HandicapLabel(Player);
+
+/// Per-player handicaps, set in the pause menu: a head start of up to five points, and a
+/// bigger or smaller, faster or slower paddle. Head starts are given at the start of each
+/// match; the paddle changes apply straight away. Whatever is set shows under that player's
+/// score.
+pub struct HandicapPlugin;
+
+impl Plugin for HandicapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-handicap-head-start-p1",
+            value: |s, l| head_start_value(&s.handicaps.player1, l),
+            change: |s, step| {
+                s.handicaps.player1.head_start =
+                    cycle_head_start(s.handicaps.player1.head_start, step)
+            },
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-handicap-size-p1",
+            value: |s, l| steps_value(s.handicaps.player1.paddle_size, l),
+            change: |s, step| {
+                s.handicaps.player1.paddle_size = cycle_steps(s.handicaps.player1.paddle_size, step)
+            },
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-handicap-speed-p1",
+            value: |s, l| steps_value(s.handicaps.player1.paddle_speed, l),
+            change: |s, step| {
+                s.handicaps.player1.paddle_speed =
+                    cycle_steps(s.handicaps.player1.paddle_speed, step)
+            },
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-handicap-head-start-p2",
+            value: |s, l| head_start_value(&s.handicaps.player2, l),
+            change: |s, step| {
+                s.handicaps.player2.head_start =
+                    cycle_head_start(s.handicaps.player2.head_start, step)
+            },
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-handicap-size-p2",
+            value: |s, l| steps_value(s.handicaps.player2.paddle_size, l),
+            change: |s, step| {
+                s.handicaps.player2.paddle_size = cycle_steps(s.handicaps.player2.paddle_size, step)
+            },
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-handicap-speed-p2",
+            value: |s, l| steps_value(s.handicaps.player2.paddle_speed, l),
+            change: |s, step| {
+                s.handicaps.player2.paddle_speed =
+                    cycle_steps(s.handicaps.player2.paddle_speed, step)
+            },
+        });
+        app.add_systems(Startup, spawn_labels);
+        app.add_systems(
+            Update,
+            (
+                give_head_starts.after(start_new_match),
+                show_labels
+                    .run_if(resource_changed::<Settings>.or_else(resource_changed::<Locale>)),
+            ),
+        );
+    }
+}
+
+/// On the first frame and after every finished match, once the score has been cleared.
+fn give_head_starts(
+    mut events: EventReader<MatchOver>,
+    mut started: Local<bool>,
+    settings: Res<Settings>,
+    rules: Res<MatchRules>,
+    mut score: ResMut<Score>,
+) {
+    if events.read().last().is_none() && *started {
+        return;
+    }
+    *started = true;
+    apply_head_starts(&settings, &rules, &mut score);
+}
+
+/// Puts each player's head start on a freshly cleared board. A head start never wins the
+/// match on its own.
+pub fn apply_head_starts(settings: &Settings, rules: &MatchRules, score: &mut Score) {
+    for player in [Player::Player1, Player::Player2] {
+        let points = settings
+            .handicaps
+            .get(player)
+            .head_start
+            .min(rules.target_score - 1);
+        if points > 0 {
+            score.0.insert(player, points);
+        }
+    }
+}
+
+fn spawn_labels(mut commands: Commands, fonts: Res<FontStack>) {
+    for player in [Player::Player1, Player::Player2] {
+        let mut style = Style {
+            position_type: PositionType::Absolute,
+            // Just under the scoreboard, on this player's half.
+            top: Val::Percent(21.0),
+            ..Default::default()
+        };
+        match player {
+            Player::Player1 => style.right = Val::Percent(52.0),
+            Player::Player2 => style.left = Val::Percent(52.0),
+        }
+        commands.spawn((
+            fonts.text_bundle("", 16.).with_style(style),
+            HandicapLabel(player),
+            HudWidget,
+        ));
+    }
+}
+
+fn show_labels(
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    mut labels: Query<(&mut Text, &HandicapLabel)>,
+) {
+    for (mut text, HandicapLabel(player)) in &mut labels {
+        let summary = settings.handicaps.get(*player).summary(&locale);
+        fonts.set(&mut text, &summary);
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::{
+    app::{PluginGroupBuilder, PluginsState},
+    audio::AudioLoader,
+    gizmos::GizmoPlugin,
+    input::InputPlugin,
+    log::LogPlugin,
+    prelude::*,
+    render::{texture::ImageLoader, view::screenshot::ScreenshotManager},
+    scene::ScenePlugin,
+    state::app::StatesPlugin,
+    text::FontLoader,
+    time::TimeUpdateStrategy,
+    utils::Duration,
+    window::ExitCondition,
+};
+use bevy_rapier2d::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::{Player, Score, event_log, recording, rng::GameRng, stats::MatchStats};
+
+const FRAME_SECONDS: f32 = 1.0 / 60.0;
+
+/// Everything the game needs to run its systems, with nothing drawn, played or opened:
+/// `MinimalPlugins` and physics, without the renderer, UI, audio or any window, so runs
+/// start quickly and work on machines without a GPU. Rapier comes with the match.
+pub struct HeadlessPlugins;
+
+impl PluginGroup for HeadlessPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add_group(MinimalPlugins)
+            .add(TransformPlugin)
+            .add(HierarchyPlugin)
+            .add(AssetPlugin::default())
+            .add(ScenePlugin)
+            .add(StatesPlugin)
+            // Keys and touches come from nowhere, but systems still read them.
+            .add(InputPlugin)
+            .add(WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                ..Default::default()
+            })
+            .add(LogPlugin {
+                custom_layer: event_log::session_layer,
+                ..Default::default()
+            })
+            .add(HeadlessAssets)
+    }
+}
+
+/// The asset types, loaders and resources the left-out plugins would otherwise register,
+/// so the game's systems that load images, fonts and sounds, draw gizmos, or set the clear
+/// colour and UI scale run as usual with nothing to show them on.
+struct HeadlessAssets;
+
+impl Plugin for HeadlessAssets {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Shader>()
+            .init_asset::<Mesh>()
+            .init_asset::<Image>()
+            .init_asset::<Font>()
+            .init_asset::<AudioSource>()
+            .init_asset::<Pitch>()
+            .init_asset::<TextureAtlasLayout>()
+            .init_asset_loader::<ImageLoader>()
+            .init_asset_loader::<FontLoader>()
+            .init_asset_loader::<AudioLoader>()
+            .init_resource::<ClearColor>()
+            .init_resource::<UiScale>()
+            .init_resource::<ScreenshotManager>()
+            .add_plugins(GizmoPlugin);
+    }
+}
+
+#[derive(Serialize)]
+struct HeadlessReport<'a> {
+    frames: u32,
+    seed: u64,
+    score: &'a HashMap<Player, i32>,
+    stats: &'a MatchStats,
+}
+
+/// Steps `app` a fixed 1/60 s per frame, physics included, so a run gives the same result
+/// on any machine however fast it goes.
+pub fn fixed_frames(app: &mut App) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        FRAME_SECONDS,
+    )));
+    app.insert_resource(TimestepMode::Fixed {
+        dt: FRAME_SECONDS,
+        substeps: 1,
+    });
+}
+
+/// Runs `frames` [fixed frames](fixed_frames), then prints the final score and match
+/// stats as JSON. A `--record-inputs` recording is written then too.
+pub fn run(mut app: App, frames: u32) {
+    fixed_frames(&mut app);
+
+    while app.plugins_state() == PluginsState::Adding {
+        bevy::tasks::tick_global_task_pools_on_main_thread();
+    }
+    app.finish();
+    app.cleanup();
+
+    for _ in 0..frames {
+        app.update();
+    }
+    recording::finish_recording(app.world());
+
+    let world = app.world();
+    let report = HeadlessReport {
+        frames,
+        seed: world.resource::<GameRng>().seed(),
+        score: &world.resource::<Score>().0,
+        stats: world.resource::<MatchStats>(),
+    };
+    match serde_json::to_string_pretty(&report) {
+        Ok(json) => println!("{json}"),
+        Err(err) => eprintln!("failed to write report: {err}"),
+    }
+}
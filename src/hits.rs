@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{Ball, Paddle, Player, Wall};
+
+/// The ball came off a paddle.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct BallHitPaddle {
+    pub ball: Entity,
+    pub paddle: Entity,
+    pub player: Player,
+    /// Where they touched, in world space.
+    pub contact: Vec2,
+    /// The ball's velocity after the bounce, relative to the paddle.
+    pub relative_velocity: Vec2,
+}
+
+/// The ball came off one of the walls.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct BallHitWall {
+    pub ball: Entity,
+    pub wall: Entity,
+    pub contact: Vec2,
+    pub relative_velocity: Vec2,
+}
+
+/// Turns the physics engine's collisions into hit events, so the colour change, sounds,
+/// stats and the rest each get the whole story instead of re-reading collisions.
+pub struct HitsPlugin;
+
+impl Plugin for HitsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BallHitPaddle>();
+        app.add_event::<BallHitWall>();
+        app.add_systems(Update, detect_hits);
+    }
+}
+
+/// Takes the contact point from the physics engine, or the ball's centre if the two have
+/// already come apart.
+pub fn detect_hits(
+    mut collisions: EventReader<CollisionEvent>,
+    rapier: Res<RapierContext>,
+    balls: Query<(&Transform, &Velocity), With<Ball>>,
+    paddles: Query<(&Player, Option<&Velocity>), With<Paddle>>,
+    walls: Query<(), With<Wall>>,
+    mut paddle_hits: EventWriter<BallHitPaddle>,
+    mut wall_hits: EventWriter<BallHitWall>,
+) {
+    for collision in collisions.read() {
+        let CollisionEvent::Started(a, b, _) = collision else {
+            continue;
+        };
+        let (ball, other, (transform, velocity)) = match (balls.get(*a), balls.get(*b)) {
+            (Ok(ball), _) => (*a, *b, ball),
+            (_, Ok(ball)) => (*b, *a, ball),
+            _ => continue,
+        };
+        let contact = rapier
+            .contact_pair(ball, other)
+            .and_then(|pair| {
+                pair.manifolds()
+                    .flat_map(|manifold| manifold.solver_contacts())
+                    .map(|contact| contact.point())
+                    .next()
+            })
+            .unwrap_or(transform.translation.truncate());
+
+        if let Ok((player, paddle_velocity)) = paddles.get(other) {
+            paddle_hits.send(BallHitPaddle {
+                ball,
+                paddle: other,
+                player: *player,
+                contact,
+                relative_velocity: velocity.linvel
+                    - paddle_velocity.map_or(Vec2::ZERO, |v| v.linvel),
+            });
+        } else if walls.contains(other) {
+            wall_hits.send(BallHitWall {
+                ball,
+                wall: other,
+                contact,
+                relative_velocity: velocity.linvel,
+            });
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::{
+    arena::Arena,
+    hits::{self, BallHitPaddle},
+    speed::TimeDips,
+};
+
+/// Ball speed off the paddle, in pixels per second at the standard court size, from which
+/// a hit gets a hitstop.
+const SPEED_THRESHOLD: f32 = 900.0;
+/// Near enough a freeze, without stopping time outright.
+const FACTOR: f32 = 0.05;
+/// About three frames at 60 frames a second, in real time.
+const SECONDS: f32 = 0.05;
+
+/// Game feel: a fast ball coming off a paddle freezes the game for a few frames, so big
+/// hits land with some weight. It is a dip in game time, so the ball, paddles and timers
+/// all hold still while the UI carries on.
+pub struct HitstopPlugin;
+
+impl Plugin for HitstopPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, stop_on_hits.after(hits::detect_hits));
+    }
+}
+
+fn stop_on_hits(
+    mut hits: EventReader<BallHitPaddle>,
+    arena: Res<Arena>,
+    mut dips: ResMut<TimeDips>,
+) {
+    let threshold = SPEED_THRESHOLD * arena.scale;
+    if hits
+        .read()
+        .any(|hit| hit.relative_velocity.length() >= threshold)
+    {
+        dips.dip(FACTOR, SECONDS);
+    }
+}
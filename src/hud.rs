@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use crate::{
+    menu::{AppSettingsExt, SettingRow, on_off},
+    settings::Settings,
+};
+
+/// Part of the in-game HUD other than the score digits themselves. Minimal HUD hides it.
+#[derive(Component)]
+pub struct HudWidget;
+
+/// The minimal HUD leaves only the score: no scoreboard panel and no meters or counters.
+/// For purists, and for streaming with an overlay of your own. F2 toggles it.
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-minimal-hud",
+            value: |s, l| on_off(l, s.minimal_hud),
+            change: |s, _| s.minimal_hud = !s.minimal_hud,
+        });
+        app.add_systems(Update, (toggle_minimal_hud, hide_widgets).chain());
+    }
+}
+
+fn toggle_minimal_hud(input: Res<ButtonInput<KeyCode>>, mut settings: ResMut<Settings>) {
+    if input.just_pressed(KeyCode::F2) {
+        settings.minimal_hud = !settings.minimal_hud;
+    }
+}
+
+/// Goes by `display` rather than visibility, which some widgets manage themselves.
+fn hide_widgets(settings: Res<Settings>, mut widgets: Query<&mut Style, With<HudWidget>>) {
+    let display = if settings.minimal_hud {
+        Display::None
+    } else {
+        Display::Flex
+    };
+    for mut style in &mut widgets {
+        if style.display != display {
+            style.display = display;
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use bevy::{asset::ron, ecs::system::EntityCommands, prelude::*};
+use bevy_rapier2d::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{io, path::Path};
+
+use crate::{
+    Player,
+    arena::Arena,
+    cli::Cli,
+    mods::ModCatalog,
+    mutators::{ActiveMutators, Mutator},
+    settings::Settings,
+    storage::{Storage, UserFiles},
+};
+
+/// Side of one square of the arena grid, in logical pixels.
+pub const GRID_CELL: f32 = 40.0;
+const PIECE_COLOUR: Color = Color::srgb(0.55, 0.55, 0.6);
+/// Collision group of blocks and bricks. Ghost balls pass through it.
+pub const OBSTACLES: Group = Group::GROUP_2;
+/// How far goal posts reach into the court. The ball bounces off them before it can touch
+/// the goal line behind.
+const POST_DEPTH: f32 = 12.0;
+
+/// A custom arena: blocks on a grid, how much of each goal line is open, and the rule
+/// changes to play it with. Made with `--editor` and played with `--arena`.
+#[derive(Resource, Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArenaLayout {
+    /// Grid cells holding a block, counted from the middle of the court: cell `(0, 0)` is
+    /// the one just above and to the right of the centre spot.
+    pub obstacles: Vec<IVec2>,
+    /// Share of each goal line that is open, from [`ArenaLayout::MIN_GOAL_SIZE`] to 1. Posts
+    /// block the rest, split evenly above and below.
+    pub goal_size: f32,
+    pub mutators: Vec<Mutator>,
+    pub movers: Vec<Mover>,
+    pub portals: Vec<Portal>,
+    pub winds: Vec<Wind>,
+}
+
+/// A block that patrols along a path, turning round at each end.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Mover {
+    /// Grid cells to pass through, in order.
+    pub path: Vec<IVec2>,
+    /// In grid cells per second.
+    pub speed: f32,
+}
+
+/// A pair of pads: a ball entering either comes out of the other.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Portal {
+    pub a: IVec2,
+    pub b: IVec2,
+    /// Send the ball back the way it came across the court, rather than keeping its heading.
+    #[serde(default)]
+    pub mirror: bool,
+}
+
+/// A rectangle of grid cells, corners included, that pushes the ball while it is inside.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Wind {
+    pub from: IVec2,
+    pub to: IVec2,
+    /// Acceleration given to the ball, in logical pixels per second squared.
+    pub push: Vec2,
+}
+
+impl Wind {
+    /// The zone's corners in the court, lowest first.
+    pub fn bounds(&self) -> Rect {
+        let (low, high) = (self.from.min(self.to), self.from.max(self.to));
+        Rect::from_corners(low.as_vec2() * GRID_CELL, (high + 1).as_vec2() * GRID_CELL)
+    }
+}
+
+impl Default for ArenaLayout {
+    fn default() -> Self {
+        ArenaLayout {
+            obstacles: Vec::new(),
+            goal_size: 1.0,
+            mutators: Vec::new(),
+            movers: Vec::new(),
+            portals: Vec::new(),
+            winds: Vec::new(),
+        }
+    }
+}
+
+impl ArenaLayout {
+    pub const MIN_GOAL_SIZE: f32 = 0.25;
+
+    /// `None` if there is no file at `path`.
+    pub fn load(files: &Storage, path: &Path) -> io::Result<Option<Self>> {
+        let Some(raw) = files.read_text(&path.to_string_lossy())? else {
+            return Ok(None);
+        };
+        ron::from_str(&raw)
+            .map(Some)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn save(&self, files: &Storage, path: &Path) -> io::Result<()> {
+        let raw = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        files.write_text(&path.to_string_lossy(), &raw)
+    }
+
+    pub fn toggle_obstacle(&mut self, cell: IVec2) {
+        match self.obstacles.iter().position(|c| *c == cell) {
+            Some(index) => {
+                self.obstacles.remove(index);
+            }
+            None => self.obstacles.push(cell),
+        }
+    }
+
+    pub fn toggle_mutator(&mut self, mutator: Mutator) {
+        match self.mutators.iter().position(|m| *m == mutator) {
+            Some(index) => {
+                self.mutators.remove(index);
+            }
+            None => self.mutators.push(mutator),
+        }
+    }
+}
+
+/// The grid cell a point in the court falls in.
+pub fn cell_at(point: Vec2) -> IVec2 {
+    (point / GRID_CELL).floor().as_ivec2()
+}
+
+pub fn cell_centre(cell: IVec2) -> Vec2 {
+    (cell.as_vec2() + 0.5) * GRID_CELL
+}
+
+/// A block or goal post. They are all rebuilt whenever the layout or the arena changes.
+#[derive(Component)]
+struct LayoutPiece;
+
+/// Drives a moving block. It is a velocity-based kinematic body, so Rapier knows how fast
+/// it goes and the ball bounces off it properly instead of being pushed into it.
+#[derive(Component)]
+struct Patrol {
+    path: Vec<Vec2>,
+    speed: f32,
+    /// The waypoint it is heading for.
+    next: usize,
+    forwards: bool,
+}
+
+/// Plays the layout from `--arena`, or the one open in the editor. Without either the
+/// layout is empty and the court is the classic one.
+pub struct LayoutPlugin;
+
+impl Plugin for LayoutPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ArenaLayout>();
+        app.add_systems(Startup, load_layout);
+        app.add_systems(
+            Update,
+            (
+                apply_mutators.run_if(resource_changed::<ArenaLayout>),
+                build_layout
+                    .run_if(resource_changed::<ArenaLayout>.or_else(resource_changed::<Arena>)),
+                patrol,
+            ),
+        );
+    }
+}
+
+fn load_layout(
+    cli: Res<Cli>,
+    mods: Res<ModCatalog>,
+    files: Res<UserFiles>,
+    mut layout: ResMut<ArenaLayout>,
+) {
+    let Some(mut path) = cli.arena.as_ref().or(cli.editor.as_ref()) else {
+        return;
+    };
+    // `--arena` also takes the name of an arena from a mod.
+    let modded = path
+        .to_str()
+        .filter(|_| !path.exists())
+        .and_then(|name| mods.arenas.get(name));
+    if let Some(modded) = modded {
+        path = modded;
+    }
+    match ArenaLayout::load(&files, path) {
+        Ok(Some(loaded)) => *layout = loaded,
+        // A new arena in the editor has no file yet.
+        Ok(None) if cli.editor.is_some() => {}
+        Ok(None) => error!("couldn't load arena {}: no such file", path.display()),
+        Err(err) => error!("couldn't load arena {}: {err}", path.display()),
+    }
+}
+
+fn apply_mutators(
+    layout: Res<ArenaLayout>,
+    mut mutators: ResMut<ActiveMutators>,
+    mut settings: ResMut<Settings>,
+) {
+    if mutators.0 == layout.mutators {
+        return;
+    }
+    mutators.0 = layout.mutators.clone();
+    // Has the assists size the paddles again, undoing a mutator that was switched off.
+    settings.set_changed();
+}
+
+fn build_layout(
+    mut commands: Commands,
+    layout: Res<ArenaLayout>,
+    arena: Res<Arena>,
+    pieces: Query<Entity, With<LayoutPiece>>,
+) {
+    for piece in &pieces {
+        commands.entity(piece).despawn();
+    }
+    for cell in &layout.obstacles {
+        spawn_piece(&mut commands, cell_centre(*cell), Vec2::splat(GRID_CELL))
+            .insert(CollisionGroups::new(OBSTACLES, Group::ALL));
+    }
+    for mover in &layout.movers {
+        let path: Vec<Vec2> = mover.path.iter().map(|cell| cell_centre(*cell)).collect();
+        let Some(start) = path.first() else {
+            continue;
+        };
+        spawn_piece(&mut commands, *start, Vec2::splat(GRID_CELL)).insert((
+            RigidBody::KinematicVelocityBased,
+            Velocity::zero(),
+            Ccd::enabled(),
+            CollisionGroups::new(OBSTACLES, Group::ALL),
+            Patrol {
+                path,
+                speed: mover.speed * GRID_CELL,
+                next: 1,
+                forwards: true,
+            },
+        ));
+    }
+
+    let open = arena.half_height() * layout.goal_size.clamp(ArenaLayout::MIN_GOAL_SIZE, 1.0);
+    let post = arena.half_height() - open;
+    if post <= 0.0 {
+        return;
+    }
+    for player in [Player::Player1, Player::Player2] {
+        let goal_x = arena.goal_x(player);
+        let x = goal_x - goal_x.signum() * POST_DEPTH / 2.0;
+        for side in [1.0, -1.0] {
+            let y = side * (open + post / 2.0);
+            spawn_piece(&mut commands, Vec2::new(x, y), Vec2::new(POST_DEPTH, post));
+        }
+    }
+}
+
+fn spawn_piece<'a>(commands: &'a mut Commands, position: Vec2, size: Vec2) -> EntityCommands<'a> {
+    commands.spawn((
+        SpriteBundle {
+            transform: Transform::from_translation(position.extend(-0.5)),
+            sprite: Sprite {
+                color: PIECE_COLOUR,
+                custom_size: Some(size),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        RigidBody::Fixed,
+        Collider::cuboid(size.x / 2.0, size.y / 2.0),
+        LayoutPiece,
+    ))
+}
+
+/// Steers each moving block towards its next waypoint, slowing on the last frame so it
+/// stops on the spot rather than overshooting.
+fn patrol(time: Res<Time>, mut movers: Query<(&Transform, &mut Velocity, &mut Patrol)>) {
+    let delta = time.delta_seconds();
+    for (transform, mut velocity, mut patrol) in &mut movers {
+        let Some(target) = patrol.path.get(patrol.next).copied() else {
+            velocity.linvel = Vec2::ZERO;
+            continue;
+        };
+        let to_target = target - transform.translation.truncate();
+        let step = patrol.speed * delta;
+        if to_target.length() <= step {
+            let last = patrol.path.len() - 1;
+            if patrol.next == last || (patrol.next == 0 && !patrol.forwards) {
+                patrol.forwards = !patrol.forwards;
+            }
+            patrol.next = if patrol.forwards {
+                patrol.next + 1
+            } else {
+                patrol.next.saturating_sub(1)
+            };
+        }
+        velocity.linvel = if delta > 0.0 {
+            to_target.clamp_length_max(step) / delta
+        } else {
+            Vec2::ZERO
+        };
+    }
+}
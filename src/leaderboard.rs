@@ -0,0 +1,466 @@
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+use crate::{
+    GameState, MatchOver, Player,
+    ai::GameMode,
+    fonts::FontStack,
+    locale::Locale,
+    magnet::RankedPlay,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    settings::Settings,
+    storage::Storage,
+    survival::RunEnded,
+};
+
+const PENDING_KEY: &str = "leaderboard-pending";
+const RANKINGS_KEY: &str = "leaderboard-rankings";
+/// Entries shown for each board.
+const TOP: usize = 10;
+const TIMEOUT: Duration = Duration::from_secs(10);
+/// How long to wait after the server couldn't be reached before sending scores again.
+const RETRY: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Board {
+    /// Longest survival runs, in seconds.
+    Survival,
+    /// Most matches in a row won against the computer.
+    WinStreak,
+}
+
+impl Board {
+    const ALL: [Board; 2] = [Board::Survival, Board::WinStreak];
+
+    fn title(self) -> &'static str {
+        match self {
+            Board::Survival => "leaderboard-survival",
+            Board::WinStreak => "leaderboard-win-streak",
+        }
+    }
+
+    /// Path segment of the board on the server.
+    fn path(self) -> &'static str {
+        match self {
+            Board::Survival => "survival",
+            Board::WinStreak => "win-streak",
+        }
+    }
+
+    fn entry(self, locale: &Locale, rank: usize, ranking: &Ranking) -> String {
+        let rank = (rank + 1).to_string();
+        match self {
+            Board::Survival => locale.format(
+                "leaderboard-entry-seconds",
+                &[
+                    ("rank", rank.into()),
+                    ("name", ranking.name.clone().into()),
+                    ("seconds", format!("{:.1}", ranking.score).into()),
+                ],
+            ),
+            Board::WinStreak => locale.format(
+                "leaderboard-entry-wins",
+                &[
+                    ("rank", rank.into()),
+                    ("name", ranking.name.clone().into()),
+                    ("wins", (ranking.score as i32).into()),
+                ],
+            ),
+        }
+    }
+}
+
+/// A score on its way to the server. The body of `POST {url}/scores`.
+#[derive(Clone, Serialize, Deserialize)]
+struct Submission {
+    board: Board,
+    name: String,
+    score: f32,
+}
+
+/// One line of a board, as returned by `GET {url}/scores/{board}`, best first.
+#[derive(Clone, Serialize, Deserialize)]
+struct Ranking {
+    name: String,
+    score: f32,
+}
+
+/// The last rankings fetched for each board, saved so they can still be shown offline.
+#[derive(Resource, Default, Serialize, Deserialize)]
+struct Rankings(HashMap<Board, Vec<Ranking>>);
+
+#[derive(Resource)]
+struct Leaderboard {
+    url: String,
+    agent: ureq::Agent,
+    /// Scores the server hasn't taken yet, saved until it does.
+    pending: Vec<Submission>,
+    /// Scores from the front of `pending` are being sent.
+    sending: bool,
+    /// Whether the last request reached the server.
+    online: bool,
+    /// Real time after which sending may be tried again.
+    retry_at: Duration,
+    /// Matches won in a row against the computer this session.
+    streak: u32,
+    /// The rankings screen is showing, over the pause menu.
+    open: bool,
+}
+
+/// What came back from a request running in the background.
+enum Reply {
+    /// This many scores from the front of `pending` were taken.
+    Sent(usize),
+    Rankings(Board, Vec<Ranking>),
+    /// No scores could be sent.
+    NotSent,
+    /// Rankings couldn't be fetched. Scores being sent meanwhile are left to their own reply.
+    NotFetched,
+}
+
+#[derive(Resource, Default)]
+struct Requests(Vec<Task<Reply>>);
+
+#[derive(Component)]
+struct LeaderboardRoot;
+
+#[derive(Component)]
+struct LeaderboardText;
+
+/// `--leaderboard URL`: submits survival bests and win streaks against the computer to a
+/// leaderboard server, and shows the global rankings on a screen opened with Tab from the
+/// pause menu. Nothing is sent, or fetched, until Share scores is turned on in the settings
+/// menu, and then only the name from the settings file and the score. Scores the server
+/// can't be reached for are saved and sent later; the last rankings fetched are shown
+/// while offline.
+pub struct LeaderboardPlugin {
+    /// Base address of the server, such as `https://example.com/pong`.
+    pub url: String,
+}
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Leaderboard {
+            url: self.url.trim_end_matches('/').to_string(),
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+            pending: Vec::new(),
+            sending: false,
+            online: true,
+            retry_at: Duration::ZERO,
+            streak: 0,
+            open: false,
+        });
+        app.init_resource::<Rankings>();
+        app.init_resource::<Requests>();
+        app.add_setting_row(SettingRow {
+            label: "setting-share-scores",
+            value: |s, l| on_off(l, s.share_scores),
+            change: |s, _| s.share_scores = !s.share_scores,
+        });
+        app.add_systems(Startup, (load_cache, spawn_screen));
+        app.add_systems(
+            Update,
+            (
+                mark_ranked.run_if(resource_changed::<Settings>),
+                (submit_runs, count_streak),
+                send_pending,
+                toggle_screen,
+                receive_replies,
+                draw_screen,
+            )
+                .chain(),
+        );
+        app.add_systems(OnExit(GameState::Paused), close_screen);
+    }
+}
+
+fn load_cache(
+    storage: Res<Storage>,
+    mut board: ResMut<Leaderboard>,
+    mut rankings: ResMut<Rankings>,
+) {
+    if let Some(pending) = storage.load(PENDING_KEY) {
+        board.pending = pending;
+    }
+    if let Some(saved) = storage.load(RANKINGS_KEY) {
+        *rankings = saved;
+    }
+}
+
+impl Leaderboard {
+    /// Queues a score if the player has opted in.
+    fn submit(&mut self, settings: &Settings, storage: &Storage, board: Board, score: f32) {
+        if !settings.share_scores {
+            return;
+        }
+        let name = match settings.player_name.trim() {
+            "" => "anonymous",
+            name => name,
+        };
+        self.pending.push(Submission {
+            board,
+            name: name.to_string(),
+            score,
+        });
+        storage.save(PENDING_KEY, &self.pending);
+    }
+}
+
+/// Sharing scores makes play ranked, which turns off assists such as the magnet.
+fn mark_ranked(settings: Res<Settings>, mut ranked: ResMut<RankedPlay>) {
+    if ranked.0 != settings.share_scores {
+        ranked.0 = settings.share_scores;
+    }
+}
+
+fn submit_runs(
+    mut runs: EventReader<RunEnded>,
+    settings: Res<Settings>,
+    storage: Res<Storage>,
+    mut board: ResMut<Leaderboard>,
+) {
+    for run in runs.read() {
+        if run.best {
+            board.submit(&settings, &storage, Board::Survival, run.seconds);
+        }
+    }
+}
+
+/// Every win against the computer submits the streak so far; the server keeps the best.
+fn count_streak(
+    mut events: EventReader<MatchOver>,
+    mode: Res<GameMode>,
+    settings: Res<Settings>,
+    storage: Res<Storage>,
+    mut board: ResMut<Leaderboard>,
+) {
+    for MatchOver(winner) in events.read() {
+        if *mode != GameMode::OnePlayer {
+            continue;
+        }
+        if *winner != Player::Player1 {
+            board.streak = 0;
+            continue;
+        }
+        board.streak += 1;
+        let streak = board.streak as f32;
+        board.submit(&settings, &storage, Board::WinStreak, streak);
+    }
+}
+
+/// One batch at a time, so nothing is sent twice.
+fn send_pending(
+    time: Res<Time<Real>>,
+    settings: Res<Settings>,
+    mut board: ResMut<Leaderboard>,
+    mut requests: ResMut<Requests>,
+) {
+    if !settings.share_scores
+        || board.pending.is_empty()
+        || board.sending
+        || time.elapsed() < board.retry_at
+    {
+        return;
+    }
+    board.sending = true;
+    let (agent, url, batch) = (
+        board.agent.clone(),
+        board.url.clone(),
+        board.pending.clone(),
+    );
+    requests.0.push(IoTaskPool::get().spawn(async move {
+        let sent = batch
+            .iter()
+            .take_while(|submission| post(&agent, &url, submission).is_ok())
+            .count();
+        if sent == 0 {
+            Reply::NotSent
+        } else {
+            Reply::Sent(sent)
+        }
+    }));
+}
+
+fn post(agent: &ureq::Agent, url: &str, submission: &Submission) -> Result<(), String> {
+    let body = serde_json::to_string(submission).map_err(|err| err.to_string())?;
+    agent
+        .post(&format!("{url}/scores"))
+        .set("Content-Type", "application/json")
+        .send_string(&body)
+        .map_err(|err| err.to_string())?;
+    Ok(())
+}
+
+fn fetch(agent: &ureq::Agent, url: &str, board: Board) -> Result<Vec<Ranking>, String> {
+    let body = agent
+        .get(&format!("{url}/scores/{}", board.path()))
+        .query("limit", &TOP.to_string())
+        .call()
+        .map_err(|err| err.to_string())?
+        .into_string()
+        .map_err(|err| err.to_string())?;
+    serde_json::from_str(&body).map_err(|err| err.to_string())
+}
+
+/// Tab, while paused, opens or closes the rankings, which are fetched afresh on opening.
+fn toggle_screen(
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    settings: Res<Settings>,
+    mut board: ResMut<Leaderboard>,
+    mut requests: ResMut<Requests>,
+) {
+    if *state.get() != GameState::Paused || !keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+    board.open = !board.open;
+    if !board.open || !settings.share_scores {
+        return;
+    }
+    for kind in Board::ALL {
+        let (agent, url) = (board.agent.clone(), board.url.clone());
+        requests.0.push(IoTaskPool::get().spawn(async move {
+            match fetch(&agent, &url, kind) {
+                Ok(rankings) => Reply::Rankings(kind, rankings),
+                Err(err) => {
+                    warn!("couldn't fetch the {} leaderboard: {err}", kind.path());
+                    Reply::NotFetched
+                }
+            }
+        }));
+    }
+}
+
+fn receive_replies(
+    time: Res<Time<Real>>,
+    storage: Res<Storage>,
+    mut requests: ResMut<Requests>,
+    mut board: ResMut<Leaderboard>,
+    mut rankings: ResMut<Rankings>,
+) {
+    let mut finished = Vec::new();
+    requests
+        .0
+        .retain_mut(|task| match block_on(future::poll_once(task)) {
+            Some(reply) => {
+                finished.push(reply);
+                false
+            }
+            None => true,
+        });
+    for reply in finished {
+        match reply {
+            Reply::Sent(count) => {
+                let count = count.min(board.pending.len());
+                board.pending.drain(..count);
+                board.sending = false;
+                board.online = true;
+                storage.save(PENDING_KEY, &board.pending);
+            }
+            Reply::Rankings(kind, list) => {
+                rankings.0.insert(kind, list);
+                board.online = true;
+                storage.save(RANKINGS_KEY, &*rankings);
+            }
+            Reply::NotSent => {
+                board.sending = false;
+                board.online = false;
+                board.retry_at = time.elapsed() + RETRY;
+            }
+            Reply::NotFetched => board.online = false,
+        }
+    }
+}
+
+fn close_screen(mut board: ResMut<Leaderboard>) {
+    board.open = false;
+}
+
+fn spawn_screen(mut commands: Commands, fonts: Res<FontStack>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.9).into(),
+                // Over the pause menu.
+                z_index: ZIndex::Global(6),
+                visibility: Visibility::Hidden,
+                ..Default::default()
+            },
+            LeaderboardRoot,
+        ))
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 28.), LeaderboardText));
+        });
+}
+
+fn draw_screen(
+    board: Res<Leaderboard>,
+    rankings: Res<Rankings>,
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    mut roots: Query<&mut Visibility, With<LeaderboardRoot>>,
+    mut texts: Query<&mut Text, With<LeaderboardText>>,
+) {
+    if !(board.is_changed()
+        || rankings.is_changed()
+        || settings.is_changed()
+        || locale.is_changed())
+    {
+        return;
+    }
+    for mut visibility in &mut roots {
+        visibility.set_if_neq(if board.open {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        });
+    }
+    if !board.open {
+        return;
+    }
+
+    let mut lines = vec![locale.text("leaderboard-title"), String::new()];
+    for kind in Board::ALL {
+        lines.push(locale.text(kind.title()));
+        match rankings.0.get(&kind).filter(|list| !list.is_empty()) {
+            Some(list) => lines.extend(
+                list.iter()
+                    .take(TOP)
+                    .enumerate()
+                    .map(|(rank, ranking)| kind.entry(&locale, rank, ranking)),
+            ),
+            None => lines.push(locale.text("leaderboard-empty")),
+        }
+        lines.push(String::new());
+    }
+    if !settings.share_scores {
+        lines.push(locale.text("leaderboard-opt-in"));
+    } else if !board.online {
+        lines.push(locale.text("leaderboard-offline"));
+    }
+    if !board.pending.is_empty() {
+        lines.push(locale.format(
+            "leaderboard-pending",
+            &[("count", board.pending.len().into())],
+        ));
+    }
+    lines.push(locale.text("leaderboard-hint"));
+    for mut text in &mut texts {
+        fonts.set(&mut text, &lines.join("\n"));
+    }
+}
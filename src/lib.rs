@@ -0,0 +1,829 @@
+use bevy::{
+    asset::AssetMetaCheck, color::palettes::css::DARK_GRAY, log::LogPlugin, prelude::*,
+    window::WindowResolution,
+};
+use bevy_rapier2d::prelude::*;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+mod ai;
+#[cfg(feature = "dev")]
+mod ai_telemetry;
+mod arena;
+mod assist;
+mod atlas;
+mod barriers;
+mod blur;
+mod bricks;
+mod camera;
+mod campaign;
+mod celebration;
+mod cli;
+mod console;
+mod controls;
+mod coop;
+mod counter;
+pub mod court;
+mod crowd;
+mod cues;
+#[cfg(feature = "dev")]
+mod dev;
+mod diagnostics;
+mod display;
+mod editor;
+mod event_log;
+mod fonts;
+mod handicap;
+mod headless;
+mod hits;
+mod hitstop;
+mod hud;
+mod layout;
+#[cfg(all(feature = "leaderboard", not(target_arch = "wasm32")))]
+mod leaderboard;
+mod lights_out;
+mod loading;
+mod locale;
+mod magnet;
+mod menu;
+mod mirror;
+mod mobile;
+mod mods;
+mod motion;
+mod mutators;
+mod narration;
+mod overtime;
+#[cfg(not(target_arch = "wasm32"))]
+mod placement;
+mod pool;
+mod portals;
+#[cfg(all(feature = "discord", not(target_arch = "wasm32")))]
+mod presence;
+mod progression;
+mod rally;
+mod recording;
+mod replay;
+mod restart;
+mod rng;
+mod screensaver;
+mod screenshot;
+#[cfg(feature = "scripting")]
+mod scripting;
+mod serve;
+mod settings;
+mod skins;
+mod slow_motion;
+mod smash;
+mod sound;
+mod speed;
+mod speedometer;
+mod splash;
+mod stamina;
+mod stats;
+mod storage;
+mod stress;
+mod survival;
+#[cfg(all(feature = "telemetry", not(target_arch = "wasm32")))]
+mod telemetry;
+pub mod testing;
+mod theme;
+mod tilt;
+mod toast;
+mod tournament;
+mod trajectory;
+mod transitions;
+mod tuning;
+#[cfg(not(target_arch = "wasm32"))]
+mod twitch;
+mod wind;
+
+const WINDOW_WIDTH: f32 = 1280.0;
+const WINDOW_HEIGHT: f32 = 720.0;
+
+const BALL_RADIUS: f32 = 25.0;
+
+const WINNING_SCORE: i32 = 11;
+
+/// Where the Android and iOS builds start, since they load the game as a library. Desktop
+/// and web builds start from `main.rs`.
+#[bevy_main]
+fn main() {
+    run();
+}
+
+/// Parses the command line and runs the game until it is closed, or for a headless run,
+/// until its frames are done.
+pub fn run() {
+    let mut cli = cli::Cli::parse();
+    let files = storage::UserFiles::default();
+    let playback = cli.play_inputs.as_ref().map(|path| {
+        recording::InputRecording::load(&files, path).unwrap_or_else(|err| {
+            eprintln!("could not read recording {}: {err}", path.display());
+            std::process::exit(1);
+        })
+    });
+    if let Some(recording) = &playback {
+        cli.mode = recording.mode;
+        cli.target_score = recording.target_score;
+    }
+    let headless = cli.headless.then(|| {
+        playback
+            .as_ref()
+            .map_or(cli.frames, recording::InputRecording::frames)
+    });
+    // Headless runs are for comparing results, so they are reproducible by default.
+    let seed = playback
+        .as_ref()
+        .map(|recording| recording.seed)
+        .or(cli.seed)
+        .or(headless.map(|_| 0));
+    let record_inputs = cli.record_inputs.clone();
+    let stress = cli.stress;
+    let tournament = cli.tournament;
+    let campaign = cli.campaign;
+    let bricks = cli.bricks;
+    let editor = cli.editor.clone();
+    #[cfg(not(target_arch = "wasm32"))]
+    let twitch = cli.twitch.clone();
+    #[cfg(feature = "scripting")]
+    let script = cli.script.clone();
+    #[cfg(all(feature = "leaderboard", not(target_arch = "wasm32")))]
+    let leaderboard = cli.leaderboard.clone();
+    #[cfg(all(feature = "telemetry", not(target_arch = "wasm32")))]
+    let telemetry = cli.telemetry.clone();
+    let mut app = App::new();
+    mods::register_asset_source(&mut app);
+    if headless.is_some() {
+        app.add_plugins(headless::HeadlessPlugins);
+        // Start from default settings and leave the saves alone.
+        app.insert_resource(storage::Storage::new(storage::MemoryStorage::default()));
+    } else {
+        app.add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        resolution: WindowResolution::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+                        // On the web, render into the page's `#pong` canvas and follow its
+                        // container's size. Both are ignored natively.
+                        canvas: Some("#pong".into()),
+                        fit_canvas_to_parent: true,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })
+                .set(LogPlugin {
+                    custom_layer: event_log::session_layer,
+                    ..Default::default()
+                })
+                .set(AssetPlugin {
+                    // Static web hosts answer missing `.meta` files with errors or HTML pages.
+                    meta_check: AssetMetaCheck::Never,
+                    ..Default::default()
+                }),
+        );
+    }
+    add_match(
+        &mut app,
+        MatchRules {
+            target_score: cli.target_score,
+        },
+        seed,
+    );
+    app.insert_resource(cli.mode);
+    app.insert_resource(cli);
+
+    // Draws with gizmos, which need a renderer.
+    #[cfg(debug_assertions)]
+    if headless.is_none() {
+        app.add_plugins(RapierDebugRenderPlugin::default());
+    }
+    app.add_plugins((
+        fonts::FontsPlugin,
+        locale::LocalePlugin,
+        toast::ToastPlugin,
+        stats::StatsPlugin,
+        screenshot::ScreenshotPlugin,
+        menu::MenuPlugin,
+        theme::ThemePlugin,
+        assist::AssistPlugin,
+        narration::NarrationPlugin,
+        motion::MotionPlugin,
+        sound::SoundPlugin,
+        cues::CuesPlugin,
+    ));
+    app.add_plugins((
+        speed::SpeedPlugin,
+        controls::ControlsPlugin,
+        display::DisplayPlugin,
+        mobile::MobilePlugin,
+        console::ConsolePlugin,
+        diagnostics::DiagnosticsOverlayPlugin,
+        ai::AiPlugin,
+        tuning::TuningPlugin,
+        event_log::EventLogPlugin,
+        replay::ReplayPlugin,
+        survival::SurvivalPlugin,
+        progression::ProgressionPlugin,
+        skins::SkinsPlugin,
+        mutators::MutatorsPlugin,
+        layout::LayoutPlugin,
+    ));
+    app.add_plugins((
+        mods::ModsPlugin,
+        camera::CameraPlugin,
+        portals::PortalsPlugin,
+        wind::WindPlugin,
+        smash::SmashPlugin,
+        tilt::TiltPlugin,
+        barriers::BarriersPlugin,
+        stamina::StaminaPlugin,
+        blur::BlurPlugin,
+        counter::CounterPlugin,
+        rally::RallyPlugin,
+        speedometer::SpeedometerPlugin,
+        hud::HudPlugin,
+        crowd::CrowdPlugin,
+        overtime::OvertimePlugin,
+    ));
+    app.add_plugins((
+        atlas::AtlasPlugin,
+        coop::CoopPlugin,
+        handicap::HandicapPlugin,
+        mirror::MirrorPlugin,
+        lights_out::LightsOutPlugin,
+        trajectory::TrajectoryPlugin,
+        restart::RestartPlugin,
+        celebration::CelebrationPlugin,
+        transitions::TransitionsPlugin,
+        screensaver::ScreensaverPlugin,
+        magnet::MagnetPlugin,
+        slow_motion::SlowMotionPlugin,
+        hitstop::HitstopPlugin,
+    ));
+    if headless.is_none() {
+        // Windows open on the splash, then load, before anything else.
+        app.insert_state(GameState::Splash);
+        app.add_plugins((splash::SplashPlugin, loading::LoadingPlugin));
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    if headless.is_none() {
+        app.add_plugins(placement::PlacementPlugin);
+    }
+    #[cfg(all(feature = "discord", not(target_arch = "wasm32")))]
+    if headless.is_none() {
+        app.add_plugins(presence::PresencePlugin);
+    }
+    #[cfg(feature = "dev")]
+    app.add_plugins(dev::DevPlugin);
+    if tournament {
+        app.add_plugins(tournament::TournamentPlugin);
+    }
+    if campaign {
+        app.add_plugins(campaign::CampaignPlugin);
+    }
+    if bricks {
+        app.add_plugins(bricks::BricksPlugin);
+    }
+    if let Some(path) = editor {
+        app.add_plugins(editor::EditorPlugin { path });
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(channel) = twitch {
+        app.add_plugins(twitch::TwitchPlugin { channel });
+    }
+    #[cfg(feature = "scripting")]
+    if let Some(path) = script {
+        app.add_plugins(scripting::ScriptingPlugin { path });
+    }
+    #[cfg(all(feature = "leaderboard", not(target_arch = "wasm32")))]
+    if let Some(url) = leaderboard {
+        app.add_plugins(leaderboard::LeaderboardPlugin { url });
+    }
+    #[cfg(all(feature = "telemetry", not(target_arch = "wasm32")))]
+    if let Some(url) = telemetry {
+        app.add_plugins(telemetry::TelemetryPlugin { url });
+    }
+    if let Some(balls) = stress {
+        app.add_plugins(stress::StressPlugin { balls });
+    }
+    if let Some(path) = record_inputs {
+        app.add_plugins(recording::RecordInputsPlugin { path });
+    }
+    if let Some(recording) = playback {
+        app.add_plugins(recording::PlayInputsPlugin { recording });
+    }
+
+    app.add_systems(Startup, (spawn_score, spawn_camera));
+    app.add_systems(Update, ball_hit.after(hits::detect_hits));
+    app.add_systems(PostUpdate, show_score.after(score));
+
+    match headless {
+        Some(frames) => headless::run(app, frames),
+        None => {
+            app.run();
+        }
+    }
+}
+
+/// The match itself: the court, paddles and ball, serves, goals and the score, with nothing
+/// drawn or heard. `run` builds the game on top of this, and [`testing::TestGame`] plays
+/// it on its own.
+fn add_match(app: &mut App, rules: MatchRules, seed: Option<u64>) {
+    app.add_plugins(RapierPhysicsPlugin::<NoUserData>::default());
+    app.insert_resource(RapierConfiguration {
+        gravity: Vec2::ZERO,
+        ..RapierConfiguration::new(1.0)
+    });
+    app.init_resource::<Score>();
+    app.insert_resource(rules);
+    // Read by `detect_reset`; the rest of the stats come with `StatsPlugin`.
+    app.init_resource::<stats::MatchStats>();
+    // The defaults until `TuningPlugin` loads the tuning file.
+    app.init_resource::<tuning::GameplayConfig>();
+    app.add_event::<GameEvents>();
+    app.add_event::<PointScored>();
+    app.add_event::<MatchOver>();
+    app.init_state::<GameState>();
+    app.add_plugins(rng::RngPlugin { seed });
+    app.add_plugins((
+        storage::StoragePlugin,
+        arena::ArenaPlugin,
+        settings::SettingsPlugin,
+        serve::ServePlugin,
+        hits::HitsPlugin,
+    ));
+
+    app.add_systems(Startup, (spawn_players, spawn_border, spawn_ball));
+    app.add_systems(
+        Update,
+        (
+            (
+                move_paddle,
+                free_pinned_balls.after(move_paddle),
+                detect_reset,
+            )
+                .run_if(in_state(GameState::Playing)),
+            start_new_match,
+        ),
+    );
+    app.add_systems(Update, freeze_physics.run_if(state_changed::<GameState>));
+    app.add_systems(PostUpdate, (reset_ball, score, check_winner.after(score)));
+}
+
+#[derive(Component, Reflect)]
+struct Paddle {
+    move_up: KeyCode,
+    move_down: KeyCode,
+}
+
+/// Player 1 defends the left goal and Player 2 the right.
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum Player {
+    Player1,
+    Player2,
+}
+
+impl Player {
+    fn start_speed(&self, speed: f32) -> Velocity {
+        match self {
+            Player::Player1 => Velocity::linear(Vec2::new(speed, 0.0)),
+            Player::Player2 => Velocity::linear(Vec2::new(-speed, 0.0)),
+        }
+    }
+
+    fn opponent(&self) -> Player {
+        match self {
+            Player::Player1 => Player::Player2,
+            Player::Player2 => Player::Player1,
+        }
+    }
+}
+
+#[derive(States, Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum GameState {
+    #[default]
+    Playing,
+    Paused,
+    /// Showing the lead-up to the last goal; play resumes when it ends.
+    Replay,
+    /// On a tournament screen, between matches.
+    Intermission,
+    /// Preloading assets before the first match. See `loading`.
+    Loading,
+    /// The logo shown at startup, before loading. See `splash`.
+    Splash,
+}
+
+/// A body's velocity from before play stopped, given back when it starts again.
+#[derive(Component)]
+struct FrozenVelocity(Velocity);
+
+/// Physics only steps while playing. In the pause menu, replays and between tournament
+/// matches bodies are also brought to rest, so nothing reading their velocity, such as the
+/// speedometer, sees them moving. Anything given a new velocity while frozen,
+/// such as a ball reset from the console, keeps it.
+fn freeze_physics(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    mut config: ResMut<RapierConfiguration>,
+    mut bodies: Query<(Entity, &mut Velocity, Option<&FrozenVelocity>)>,
+) {
+    let playing = *state.get() == GameState::Playing;
+    config.physics_pipeline_active = playing;
+    for (entity, mut velocity, frozen) in &mut bodies {
+        match (playing, frozen) {
+            (false, None) => {
+                commands.entity(entity).insert(FrozenVelocity(*velocity));
+                *velocity = Velocity::zero();
+            }
+            (true, Some(FrozenVelocity(before))) => {
+                if *velocity == Velocity::zero() {
+                    *velocity = *before;
+                }
+                commands.entity(entity).remove::<FrozenVelocity>();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Component, Reflect)]
+struct Wall;
+
+/// Borders are laid out for the current arena and refitted by `arena` when it changes.
+fn spawn_border(mut commands: Commands, arena: Res<arena::Arena>) {
+    let (half_width, half_height) = (arena.half_width(), arena.half_height());
+    for side in [1.0, -1.0] {
+        let (transform, collider) = court::wall(half_width, half_height, side);
+        commands.spawn((
+            SpatialBundle::from_transform(transform),
+            RigidBody::Fixed,
+            collider,
+            Wall,
+        ));
+    }
+    for player in [Player::Player1, Player::Player2] {
+        let side = arena.goal_x(player).signum();
+        let (transform, collider) = court::goal_line(half_width, half_height, side);
+        commands.spawn((
+            SpatialBundle::from_transform(transform),
+            RigidBody::Fixed,
+            collider,
+            player,
+            Sensor,
+        ));
+    }
+}
+
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), camera::CameraRig::default()));
+}
+
+fn spawn_players(
+    mut commands: Commands,
+    settings: Res<settings::Settings>,
+    arena: Res<arena::Arena>,
+) {
+    commands.spawn((
+        SpriteBundle {
+            transform: Transform::from_translation(Vec3::new(
+                arena.paddle_x(Player::Player1),
+                0.0,
+                0.0,
+            )),
+            sprite: Sprite {
+                color: settings.player_colour(Player::Player1),
+                custom_size: Some(Vec2::new(10.0, 150.0)),
+                ..Default::default()
+            },
+
+            ..Default::default()
+        },
+        Paddle {
+            move_up: settings.bindings.player1.up,
+            move_down: settings.bindings.player1.down,
+        },
+        Player::Player1,
+        RigidBody::KinematicVelocityBased,
+        Velocity::zero(),
+        Collider::cuboid(5.0, 75.0),
+    ));
+
+    commands.spawn((
+        SpriteBundle {
+            transform: Transform::from_translation(Vec3::new(
+                arena.paddle_x(Player::Player2),
+                0.0,
+                0.0,
+            )),
+            sprite: Sprite {
+                color: settings.player_colour(Player::Player2),
+                custom_size: Some(Vec2::new(10.0, 150.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        Paddle {
+            move_up: settings.bindings.player2.up,
+            move_down: settings.bindings.player2.down,
+        },
+        Player::Player2,
+        RigidBody::KinematicVelocityBased,
+        Velocity::zero(),
+        Collider::cuboid(5.0, 75.0),
+    ));
+}
+
+/// Paddles are moved by velocity, not by setting their position, so physics sees them
+/// sweep into the ball and pushes it out properly. This is the velocity that takes
+/// `paddle` to the height `to` this frame, stopping at the walls.
+fn paddle_velocity(arena: &arena::Arena, paddle: &Transform, to: f32, delta: f32) -> Vec2 {
+    if delta <= 0.0 {
+        return Vec2::ZERO;
+    }
+    // Assists can stretch the paddle through its scale.
+    let half_height = 75.0 * paddle.scale.y;
+    Vec2::Y * (arena.clamp_y(to, half_height) - paddle.translation.y) / delta
+}
+
+type KeyboardPaddle<'a> = (
+    &'a Transform,
+    &'a mut Velocity,
+    &'a Paddle,
+    &'a Player,
+    Option<&'a stamina::Stamina>,
+);
+
+fn move_paddle(
+    mut paddles: Query<KeyboardPaddle, Without<ai::AiPaddle>>,
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    arena: Res<arena::Arena>,
+    config: Res<tuning::GameplayConfig>,
+    settings: Res<settings::Settings>,
+) {
+    for (pos, mut velocity, controls, player, stamina) in &mut paddles {
+        let speed = config.paddle_speed
+            * arena.scale
+            * settings.handicaps.get(*player).speed_factor()
+            * stamina.map_or(1.0, stamina::Stamina::pace);
+        let mut direction = 0.0;
+        if input.pressed(controls.move_up) {
+            direction += 1.0;
+        }
+        if input.pressed(controls.move_down) {
+            direction -= 1.0;
+        }
+        let to = pos.translation.y + direction * speed * time.delta_seconds();
+        velocity.linvel = paddle_velocity(&arena, pos, to, time.delta_seconds());
+    }
+}
+
+/// How far the ball may sink into a paddle before it counts as stuck.
+const PINNED_DEPTH: f32 = 4.0;
+
+/// A paddle sliding along a wall can still squeeze the ball against it. Rather than let
+/// physics push it out through the wall, a ball sunk into a paddle goes back in front of
+/// it, heading into the court. Balls already behind the paddle are left to score.
+fn free_pinned_balls(
+    paddles: Query<&Transform, (With<Paddle>, Without<Ball>)>,
+    mut balls: Query<(&mut Transform, &mut Velocity), With<Ball>>,
+) {
+    for (mut ball, mut velocity) in &mut balls {
+        let radius = BALL_RADIUS * ball.scale.x;
+        for paddle in &paddles {
+            let half_size = Vec2::new(5.0, 75.0) * paddle.scale.truncate();
+            let offset = (ball.translation - paddle.translation).truncate();
+            let depth = half_size + Vec2::splat(radius) - offset.abs();
+            // Towards the middle of the court.
+            let front = -paddle.translation.x.signum();
+            if depth.min_element() < PINNED_DEPTH || offset.x * front < -half_size.x {
+                continue;
+            }
+            ball.translation.x = paddle.translation.x + front * (half_size.x + radius);
+            velocity.linvel.x = front * velocity.linvel.x.abs();
+        }
+    }
+}
+
+#[derive(Component, Reflect)]
+struct Ball;
+
+/// Everything a ball is made of. The texture comes from the chosen ball skin, see `skins`.
+fn ball_bundle(config: &tuning::GameplayConfig, position: Vec2, velocity: Velocity) -> impl Bundle {
+    (
+        SpriteBundle {
+            transform: Transform::from_translation(position.extend(1.0)),
+            sprite: Sprite {
+                color: Color::WHITE,
+                custom_size: Some(Vec2::new(BALL_RADIUS * 2.0, BALL_RADIUS * 2.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        Ball,
+        court::ball_body(),
+        ActiveEvents::COLLISION_EVENTS,
+        CollidingEntities::default(),
+        velocity,
+        Restitution {
+            coefficient: config.ball_restitution,
+            combine_rule: CoefficientCombineRule::Max,
+        },
+    )
+}
+
+fn spawn_ball(mut commands: Commands, config: Res<tuning::GameplayConfig>) {
+    commands.spawn(ball_bundle(
+        &config,
+        Vec2::new(-300.0, 0.0),
+        Player::Player1.start_speed(config.serve_speed),
+    ));
+}
+
+fn ball_hit(
+    mut hits: EventReader<hits::BallHitPaddle>,
+    mut balls: Query<&mut Sprite, With<Ball>>,
+    settings: Res<settings::Settings>,
+) {
+    for hit in hits.read() {
+        if let Ok(mut sprite) = balls.get_mut(hit.ball) {
+            sprite.color = settings.player_colour(hit.player);
+        }
+    }
+}
+
+fn detect_reset(
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    stats: Res<stats::MatchStats>,
+    balls: Query<(&CollidingEntities, &Velocity), With<Ball>>,
+    goles: Query<&Player, With<Sensor>>,
+    mut game_events: EventWriter<GameEvents>,
+    mut points: EventWriter<PointScored>,
+) {
+    if input.just_pressed(KeyCode::Space) {
+        let player = Player::Player1;
+
+        game_events.send(GameEvents::ResetBall(player));
+        return;
+    }
+
+    for (hits, velocity) in &balls {
+        for hit in hits.iter() {
+            if let Ok(player) = goles.get(hit) {
+                game_events.send(GameEvents::ResetBall(*player));
+                points.send(PointScored {
+                    scorer: *player,
+                    conceder: player.opponent(),
+                    ball_speed: velocity.linvel.length(),
+                    rally: stats.rally,
+                    time_secs: time.elapsed_seconds(),
+                });
+            }
+        }
+    }
+}
+
+#[derive(Event)]
+enum GameEvents {
+    ResetBall(Player),
+}
+
+/// A goal, with what led up to it.
+#[derive(Event, Clone, Copy, Debug)]
+struct PointScored {
+    scorer: Player,
+    conceder: Player,
+    /// In pixels per second, as the ball crossed the line.
+    ball_speed: f32,
+    /// Paddle hits since the serve.
+    rally: u32,
+    /// Game clock, which stops while paused.
+    time_secs: f32,
+}
+
+type PaddleOnly = (With<Paddle>, Without<Ball>);
+
+/// Puts the ball in front of the server's paddle, where it waits to be served.
+fn reset_ball(
+    mut commands: Commands,
+    mut balls: Query<(Entity, &mut Transform, &mut Velocity), With<Ball>>,
+    paddles: Query<(&Transform, &Player), PaddleOnly>,
+    mut game_events: EventReader<GameEvents>,
+    arena: Res<arena::Arena>,
+    settings: Res<settings::Settings>,
+) {
+    for GameEvents::ResetBall(server) in game_events.read() {
+        let y = paddles
+            .iter()
+            .find(|(_, player)| *player == server)
+            .map_or(0.0, |(paddle, _)| paddle.translation.y);
+        let x = arena.paddle_x(*server);
+        let into_court = -x.signum();
+        for (entity, mut ball, mut speed) in &mut balls {
+            ball.translation = Vec3::new(x + into_court * serve::SERVE_DISTANCE, y, 0.0);
+            *speed = Velocity::zero();
+            commands
+                .entity(entity)
+                .insert(serve::Serving::new(*server, settings.serve_style));
+        }
+    }
+}
+
+#[derive(Component)]
+struct ScoreBoard;
+
+fn spawn_score(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    margin: UiRect::horizontal(Val::Auto),
+                    top: Val::ZERO,
+                    align_content: AlignContent::Stretch,
+                    justify_content: JustifyContent::SpaceBetween,
+                    width: Val::Percent(30.0),
+                    height: Val::Percent(20.0),
+                    ..Default::default()
+                },
+                background_color: DARK_GRAY.into(),
+                ..Default::default()
+            },
+            ScoreBoard,
+            Interaction::default(),
+        ))
+        .with_children(|p| {
+            let style = TextStyle {
+                font_size: 100.,
+                ..Default::default()
+            };
+            counter::spawn_counter(p, 0, style.clone(), Player::Player1);
+
+            p.spawn((
+                TextBundle {
+                    text: Text {
+                        sections: vec![TextSection {
+                            value: "|".to_string(),
+                            style: TextStyle {
+                                font_size: 100.,
+                                ..Default::default()
+                            },
+                        }],
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                hud::HudWidget,
+            ));
+
+            counter::spawn_counter(p, 0, style, Player::Player2);
+        });
+}
+
+#[derive(Default, Resource)]
+struct Score(HashMap<Player, i32>);
+
+fn score(mut points: EventReader<PointScored>, mut score: ResMut<Score>) {
+    for point in points.read() {
+        *score.0.entry(point.scorer).or_default() += 1;
+    }
+}
+
+/// Anything that changes `Score` just changes the resource; the scoreboard follows here,
+/// including counters spawned after the points were scored.
+fn show_score(score: Res<Score>, mut counters: Query<(&mut counter::RollingCounter, Ref<Player>)>) {
+    for (mut counter, player) in &mut counters {
+        if score.is_changed() || player.is_added() {
+            counter.set(score.0.get(&*player).copied().unwrap_or(0));
+        }
+    }
+}
+
+#[derive(Event)]
+struct MatchOver(Player);
+
+#[derive(Resource)]
+struct MatchRules {
+    target_score: i32,
+}
+
+fn check_winner(score: Res<Score>, rules: Res<MatchRules>, mut match_over: EventWriter<MatchOver>) {
+    if !score.is_changed() {
+        return;
+    }
+    for (player, points) in &score.0 {
+        if *points >= rules.target_score {
+            match_over.send(MatchOver(*player));
+        }
+    }
+}
+
+fn start_new_match(mut events: EventReader<MatchOver>, mut score: ResMut<Score>) {
+    if events.read().last().is_none() {
+        return;
+    }
+    score.0.clear();
+}
@@ -0,0 +1,160 @@
+use bevy::{
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderRef},
+    sprite::{Material2d, Material2dPlugin, MaterialMesh2dBundle, Mesh2dHandle},
+};
+
+use crate::{
+    Ball, GameState, Paddle,
+    arena::Arena,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    mutators::{ActiveMutators, Mutator},
+    settings::Settings,
+};
+
+const SHADER_PATH: &str = "shaders/lights_out.wgsl";
+/// Must match the array in the shader.
+const MAX_SPOTS: usize = 6;
+/// Seconds of play with the lights on, then off, in turn.
+const LIGHT_SECONDS: f32 = 12.0;
+const DARK_SECONDS: f32 = 6.0;
+/// How long the lights take to go out or come back on.
+const FADE_SECONDS: f32 = 0.75;
+/// How dark it gets outside the spotlights, from 0 to 1.
+const DARKNESS: f32 = 0.96;
+const BALL_SPOT_RADIUS: f32 = 70.0;
+const PADDLE_SPOT_RADIUS: f32 = 110.0;
+/// Above everything on the court.
+const OVERLAY_Z: f32 = 50.0;
+
+#[derive(Asset, TypePath, AsBindGroup, Clone, Default)]
+struct LightsOutMaterial {
+    /// Centre and radius of each spotlight. Spots with no radius are unused.
+    #[uniform(0)]
+    spots: [Vec4; MAX_SPOTS],
+    #[uniform(1)]
+    darkness: f32,
+}
+
+impl Material2d for LightsOutMaterial {
+    fn fragment_shader() -> ShaderRef {
+        SHADER_PATH.into()
+    }
+}
+
+#[derive(Component)]
+struct Overlay(Handle<LightsOutMaterial>);
+
+/// Play time into the current light and dark cycle.
+#[derive(Resource, Default)]
+struct Cycle(f32);
+
+/// The lights-out mutator: every so often the court goes dark except for spotlights on
+/// the ball and the paddles, so players have to follow the ball by ear and by guessing
+/// where it went. The lights fade out and back on rather than snapping. Keep lights on,
+/// in the settings menu, opts out for anyone who can't play that way.
+pub struct LightsOutPlugin;
+
+impl Plugin for LightsOutPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<LightsOutMaterial>::default());
+        app.init_resource::<Cycle>();
+        app.add_setting_row(SettingRow {
+            label: "setting-keep-lights-on",
+            value: |s, l| on_off(l, s.keep_lights_on),
+            change: |s, _| s.keep_lights_on = !s.keep_lights_on,
+        });
+        app.add_systems(Startup, spawn_overlay);
+        app.add_systems(
+            Update,
+            (fit_overlay.run_if(resource_changed::<Arena>), dim_lights),
+        );
+    }
+}
+
+fn spawn_overlay(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<LightsOutMaterial>>,
+) {
+    let material = materials.add(LightsOutMaterial::default());
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: Mesh2dHandle(meshes.add(Rectangle::new(1.0, 1.0))),
+            material: material.clone(),
+            transform: Transform::from_xyz(0.0, 0.0, OVERLAY_Z),
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        },
+        Overlay(material),
+    ));
+}
+
+/// Covers the court with room to spare, so camera shakes and zooms never show its edge.
+fn fit_overlay(arena: Res<Arena>, mut overlays: Query<&mut Transform, With<Overlay>>) {
+    for mut transform in &mut overlays {
+        transform.scale = Vec3::new(arena.width * 2.0, arena.height * 2.0, 1.0);
+    }
+}
+
+/// How dark it is `seconds` into a cycle, easing in and out at the ends of the dark spell.
+fn darkness(seconds: f32) -> f32 {
+    let dark = seconds - LIGHT_SECONDS;
+    if dark <= 0.0 {
+        return 0.0;
+    }
+    let fade_in = (dark / FADE_SECONDS).min(1.0);
+    let fade_out = ((DARK_SECONDS - dark) / FADE_SECONDS).clamp(0.0, 1.0);
+    DARKNESS * fade_in.min(fade_out)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn dim_lights(
+    time: Res<Time>,
+    state: Res<State<GameState>>,
+    settings: Res<Settings>,
+    mutators: Res<ActiveMutators>,
+    mut cycle: ResMut<Cycle>,
+    balls: Query<&Transform, With<Ball>>,
+    paddles: Query<&Transform, With<Paddle>>,
+    mut overlays: Query<(&Overlay, &mut Visibility)>,
+    mut materials: ResMut<Assets<LightsOutMaterial>>,
+) {
+    if !mutators.has(Mutator::LightsOut) || settings.keep_lights_on {
+        cycle.0 = 0.0;
+    } else if *state.get() == GameState::Playing {
+        cycle.0 = (cycle.0 + time.delta_seconds()) % (LIGHT_SECONDS + DARK_SECONDS);
+    }
+    let darkness = darkness(cycle.0);
+
+    let spots = balls
+        .iter()
+        .map(|ball| (ball, BALL_SPOT_RADIUS * ball.scale.x))
+        .chain(
+            paddles
+                .iter()
+                .map(|paddle| (paddle, PADDLE_SPOT_RADIUS * paddle.scale.y)),
+        )
+        .map(|(transform, radius)| transform.translation.truncate().extend(radius).extend(0.0));
+    let mut lit = LightsOutMaterial {
+        darkness,
+        ..Default::default()
+    };
+    for (slot, spot) in lit.spots.iter_mut().zip(spots) {
+        *slot = spot;
+    }
+
+    for (Overlay(handle), mut visibility) in &mut overlays {
+        let shown = if darkness > 0.0 {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+        visibility.set_if_neq(shown);
+        if darkness > 0.0
+            && let Some(material) = materials.get_mut(handle)
+        {
+            *material = lit.clone();
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use bevy::{asset::LoadState, prelude::*};
+
+use crate::{
+    GameState, fonts::FontStack, locale::Locale, skins::SkinManifest, theme::ThemePacks,
+    tuning::ConfigFiles,
+};
+
+const BACKGROUND: Color = Color::srgb(0.05, 0.05, 0.08);
+const BAR_COLOUR: Color = Color::srgb(0.3, 0.7, 1.0);
+
+/// Images, fonts and music named by the config files, once those are in. `None` until then.
+#[derive(Resource, Default)]
+struct Preload(Option<Vec<UntypedHandle>>);
+
+/// Where to go once everything has loaded: straight into play, unless a mode asked for
+/// another state at startup.
+#[derive(Resource)]
+struct AfterLoading(GameState);
+
+#[derive(Component)]
+struct LoadingScreen;
+
+#[derive(Component)]
+struct LoadingBar;
+
+#[derive(Component)]
+struct LoadingText;
+
+/// Holds the game in [`GameState::Loading`], behind a progress bar, until the config
+/// files and every texture, font and piece of music they name have loaded or failed to,
+/// so nothing pops in mid-match. Not used for headless runs or tests, which start in play.
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Preload>();
+        app.insert_resource(AfterLoading(GameState::Playing));
+        app.add_systems(Startup, spawn_screen);
+        app.add_systems(PostStartup, hold_startup_state);
+        app.add_systems(
+            Update,
+            (gather, track_loading)
+                .chain()
+                .run_if(in_state(GameState::Loading)),
+        );
+        app.add_systems(
+            OnExit(GameState::Loading),
+            |mut commands: Commands, screens: Query<Entity, With<LoadingScreen>>| {
+                for screen in &screens {
+                    commands.entity(screen).despawn_recursive();
+                }
+            },
+        );
+    }
+}
+
+/// The campaign, tournament and editor open their screens at startup; that waits until
+/// loading is done.
+fn hold_startup_state(mut next: ResMut<NextState<GameState>>, mut after: ResMut<AfterLoading>) {
+    if let NextState::Pending(state) = next.as_ref() {
+        after.0 = *state;
+        next.reset();
+    }
+}
+
+fn spawn_screen(mut commands: Commands, fonts: Res<FontStack>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(12.0),
+                    ..Default::default()
+                },
+                background_color: BACKGROUND.into(),
+                z_index: ZIndex::Global(20),
+                ..Default::default()
+            },
+            LoadingScreen,
+        ))
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 24.0), LoadingText));
+            p.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(320.0),
+                    height: Val::Px(8.0),
+                    ..Default::default()
+                },
+                background_color: Color::srgba(1.0, 1.0, 1.0, 0.2).into(),
+                ..Default::default()
+            })
+            .with_children(|p| {
+                p.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..Default::default()
+                        },
+                        background_color: BAR_COLOUR.into(),
+                        ..Default::default()
+                    },
+                    LoadingBar,
+                ));
+            });
+        });
+}
+
+/// A failed load is as done as it will get; whatever uses it falls back as usual.
+fn settled(asset_server: &AssetServer, handle: &UntypedHandle) -> bool {
+    matches!(
+        asset_server.load_state(handle.id()),
+        LoadState::Loaded | LoadState::Failed(_)
+    )
+}
+
+/// Config files are copied into their resources a frame after they load, so the assets
+/// they name are only asked for the frame after that.
+fn gather(
+    asset_server: Res<AssetServer>,
+    configs: Res<ConfigFiles>,
+    skins: Res<SkinManifest>,
+    themes: Res<ThemePacks>,
+    fonts: Res<FontStack>,
+    mut configs_in: Local<bool>,
+    mut preload: ResMut<Preload>,
+) {
+    if preload.0.is_some() {
+        return;
+    }
+    if !*configs_in {
+        *configs_in = configs
+            .0
+            .iter()
+            .all(|handle| settled(&asset_server, handle));
+        return;
+    }
+    let mut handles = fonts.handles();
+    for skin in skins.paddles.iter().chain(&skins.balls) {
+        if let Some(image) = &skin.image {
+            handles.push(asset_server.load::<Image>(image.clone()).untyped());
+        }
+    }
+    for pack in &themes.0 {
+        if let Some(image) = &pack.theme.wall_image {
+            handles.push(asset_server.load::<Image>(image.clone()).untyped());
+        }
+        if let Some(music) = &pack.theme.music {
+            handles.push(asset_server.load::<AudioSource>(music.clone()).untyped());
+        }
+    }
+    preload.0 = Some(handles);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn track_loading(
+    asset_server: Res<AssetServer>,
+    configs: Res<ConfigFiles>,
+    preload: Res<Preload>,
+    after: Res<AfterLoading>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    mut next: ResMut<NextState<GameState>>,
+    mut bars: Query<&mut Style, With<LoadingBar>>,
+    mut texts: Query<&mut Text, With<LoadingText>>,
+) {
+    let handles: Vec<&UntypedHandle> = configs.0.iter().chain(preload.0.iter().flatten()).collect();
+    let done = handles
+        .iter()
+        .filter(|handle| settled(&asset_server, handle))
+        .count();
+    let progress = done as f32 / handles.len().max(1) as f32;
+    for mut bar in &mut bars {
+        bar.width = Val::Percent(progress * 100.0);
+    }
+    let value = locale.format(
+        "loading",
+        &[("percent", ((progress * 100.0) as u32).into())],
+    );
+    for mut text in &mut texts {
+        fonts.set(&mut text, &value);
+    }
+    if preload.0.is_some() && done == handles.len() {
+        next.set(after.0);
+    }
+}
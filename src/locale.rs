@@ -0,0 +1,253 @@
+use bevy::{
+    asset::{AssetLoader, AsyncReadExt, LoadContext, io::Reader},
+    prelude::*,
+};
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue, concurrent::FluentBundle};
+use serde::{Deserialize, Serialize};
+use unic_langid::LanguageIdentifier;
+
+use crate::{
+    Player,
+    fonts::FontStack,
+    menu::{AppSettingsExt, SettingRow},
+    settings::Settings,
+};
+
+/// Built into the binary so there is always something to show, even before (or without)
+/// the asset for the active language loading.
+const FALLBACK_FTL: &str = include_str!("../assets/locales/en-US.ftl");
+
+/// Every language with a translation file in `assets/locales`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    French,
+    German,
+    Spanish,
+    Russian,
+}
+
+impl Language {
+    const ALL: [Language; 5] = [
+        Language::English,
+        Language::French,
+        Language::German,
+        Language::Spanish,
+        Language::Russian,
+    ];
+
+    fn code(self) -> &'static str {
+        match self {
+            Language::English => "en-US",
+            Language::French => "fr-FR",
+            Language::German => "de-DE",
+            Language::Spanish => "es-ES",
+            Language::Russian => "ru-RU",
+        }
+    }
+
+    /// Shown untranslated, so players can always find their own language.
+    fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::French => "Français",
+            Language::German => "Deutsch",
+            Language::Spanish => "Español",
+            Language::Russian => "Русский",
+        }
+    }
+
+    fn identifier(self) -> LanguageIdentifier {
+        self.code().parse().unwrap()
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|l| *l == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+}
+
+/// A Fluent translation file from `assets/locales/<language>.ftl`.
+#[derive(Asset, TypePath)]
+pub struct FtlAsset(String);
+
+#[derive(Default)]
+struct FtlLoader;
+
+impl AssetLoader for FtlLoader {
+    type Asset = FtlAsset;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<FtlAsset, Self::Error> {
+        let mut source = String::new();
+        reader.read_to_string(&mut source).await?;
+        Ok(FtlAsset(source))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ftl"]
+    }
+}
+
+/// The active language. All player-facing text is looked up here by message id.
+#[derive(Resource)]
+pub struct Locale {
+    language: Language,
+    handle: Handle<FtlAsset>,
+    bundle: Option<FluentBundle<FluentResource>>,
+    fallback: FluentBundle<FluentResource>,
+}
+
+impl FromWorld for Locale {
+    fn from_world(world: &mut World) -> Self {
+        let language = Language::default();
+        let handle = world
+            .resource::<AssetServer>()
+            .load(format!("locales/{}.ftl", language.code()));
+        Locale {
+            fallback: build_bundle(language.identifier(), FALLBACK_FTL),
+            language,
+            handle,
+            bundle: None,
+        }
+    }
+}
+
+impl Locale {
+    /// A plain message, e.g. `locale.text("menu-paused")`.
+    pub fn text(&self, id: &str) -> String {
+        self.format(id, &[])
+    }
+
+    /// A message with placeables, e.g.
+    /// `locale.format("announce-match-won", &[("player", name.into())])`.
+    pub fn format(&self, id: &str, args: &[(&str, FluentValue)]) -> String {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+
+        self.bundle
+            .as_ref()
+            .and_then(|bundle| lookup(bundle, id, &fluent_args))
+            .or_else(|| lookup(&self.fallback, id, &fluent_args))
+            .unwrap_or_else(|| {
+                warn!("missing translation for {id}");
+                id.to_string()
+            })
+    }
+
+    pub fn player(&self, player: Player) -> String {
+        self.text(match player {
+            Player::Player1 => "player-1",
+            Player::Player2 => "player-2",
+        })
+    }
+}
+
+fn build_bundle(language: LanguageIdentifier, source: &str) -> FluentBundle<FluentResource> {
+    let resource =
+        FluentResource::try_new(source.to_string()).unwrap_or_else(|(resource, errors)| {
+            warn!("errors in {language} translations: {errors:?}");
+            resource
+        });
+    let mut bundle = FluentBundle::new_concurrent(vec![language]);
+    // Bevy's fonts have no glyphs for the Unicode isolation marks Fluent wraps arguments in.
+    bundle.set_use_isolating(false);
+    if let Err(errors) = bundle.add_resource(resource) {
+        warn!("duplicate translations: {errors:?}");
+    }
+    bundle
+}
+
+fn lookup(bundle: &FluentBundle<FluentResource>, id: &str, args: &FluentArgs) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, Some(args), &mut errors);
+    if !errors.is_empty() {
+        warn!("errors formatting {id}: {errors:?}");
+    }
+    Some(text.into_owned())
+}
+
+/// UI text that is a single message, re-rendered whenever the language changes.
+#[derive(Component)]
+pub struct LocalizedText(pub &'static str);
+
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<FtlAsset>();
+        app.init_asset_loader::<FtlLoader>();
+        app.init_resource::<Locale>();
+        app.add_setting_row(SettingRow {
+            label: "setting-language",
+            value: |s, _| s.language.native_name().to_string(),
+            change: |s, step| s.language = s.language.cycle(step),
+        });
+        app.add_systems(
+            PreUpdate,
+            (
+                switch_language.run_if(resource_changed::<Settings>),
+                rebuild_bundle,
+            )
+                .chain(),
+        );
+        app.add_systems(
+            PostUpdate,
+            refresh_localized_text.run_if(resource_changed::<Locale>),
+        );
+    }
+}
+
+/// Starts loading the newly chosen language; the old one stays up until it arrives.
+fn switch_language(
+    settings: Res<Settings>,
+    asset_server: Res<AssetServer>,
+    mut locale: ResMut<Locale>,
+) {
+    if locale.language == settings.language {
+        return;
+    }
+    locale.language = settings.language;
+    locale.handle = asset_server.load(format!("locales/{}.ftl", settings.language.code()));
+}
+
+fn refresh_localized_text(
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    mut texts: Query<(&mut Text, &LocalizedText)>,
+) {
+    for (mut text, LocalizedText(id)) in &mut texts {
+        fonts.set(&mut text, &locale.text(id));
+    }
+}
+
+/// Swaps in the translation once it loads, and again whenever the file is edited.
+fn rebuild_bundle(
+    mut events: EventReader<AssetEvent<FtlAsset>>,
+    assets: Res<Assets<FtlAsset>>,
+    mut locale: ResMut<Locale>,
+) {
+    for event in events.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = event
+        else {
+            continue;
+        };
+        if *id != locale.handle.id() {
+            continue;
+        }
+        if let Some(FtlAsset(source)) = assets.get(*id) {
+            let bundle = build_bundle(locale.language.identifier(), source);
+            locale.bundle = Some(bundle);
+        }
+    }
+}
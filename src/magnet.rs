@@ -0,0 +1,200 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Ball, GameState, Paddle,
+    ai::AiPaddle,
+    arena::Arena,
+    fonts::FontStack,
+    locale::Locale,
+    menu::{AppSettingsExt, SettingRow},
+    settings::Settings,
+};
+
+/// Half a paddle's height at normal size. Matches the collider.
+const PADDLE_HALF_HEIGHT: f32 = 75.0;
+/// How far beyond the paddle's end a ball can be heading and still be pulled in.
+const REACH: f32 = 90.0;
+/// Only balls this close to the paddle's line, across the court, are pulled.
+const RANGE: f32 = 320.0;
+const FLAG_COLOUR: Color = Color::srgb(1.0, 0.8, 0.3);
+
+/// How hard the beginner magnet pulls the ball toward a paddle, chosen in the settings menu.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum MagnetStrength {
+    #[default]
+    Off,
+    Gentle,
+    Medium,
+    Strong,
+}
+
+impl MagnetStrength {
+    const ALL: [MagnetStrength; 4] = [
+        MagnetStrength::Off,
+        MagnetStrength::Gentle,
+        MagnetStrength::Medium,
+        MagnetStrength::Strong,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            MagnetStrength::Off => "setting-off",
+            MagnetStrength::Gentle => "magnet-gentle",
+            MagnetStrength::Medium => "magnet-medium",
+            MagnetStrength::Strong => "magnet-strong",
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|s| *s == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+
+    /// How quickly, per second, the ball's heading closes on the paddle.
+    fn rate(self) -> f32 {
+        match self {
+            MagnetStrength::Off => 0.0,
+            MagnetStrength::Gentle => 1.5,
+            MagnetStrength::Medium => 3.0,
+            MagnetStrength::Strong => 6.0,
+        }
+    }
+}
+
+/// Whether scores can end up on an online leaderboard, where assists that play for you
+/// are off. Kept up to date by `leaderboard`.
+#[derive(Resource, Default)]
+pub struct RankedPlay(pub bool);
+
+#[derive(Component)]
+struct MagnetFlag;
+
+/// A beginner assist so young children can rally: a ball heading just past a player's
+/// paddle is drawn gently toward it as it gets close, at the strength picked in the
+/// settings menu. Computer paddles never get it. While it is on, a label in the corner
+/// says so, and it does nothing at all when scores go to an online leaderboard.
+pub struct MagnetPlugin;
+
+impl Plugin for MagnetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RankedPlay>();
+        app.add_setting_row(SettingRow {
+            label: "setting-magnet",
+            value: |s, l| l.text(s.assist.magnet.name()),
+            change: |s, step| s.assist.magnet = s.assist.magnet.cycle(step),
+        });
+        app.add_systems(Startup, spawn_flag);
+        app.add_systems(
+            Update,
+            (
+                pull_balls.run_if(in_state(GameState::Playing)),
+                show_flag.run_if(
+                    resource_changed::<Settings>
+                        .or_else(resource_changed::<RankedPlay>)
+                        .or_else(resource_changed::<Locale>),
+                ),
+            ),
+        );
+    }
+}
+
+/// The assist's strength, or `Off` in ranked play.
+fn strength(settings: &Settings, ranked: &RankedPlay) -> MagnetStrength {
+    if ranked.0 {
+        MagnetStrength::Off
+    } else {
+        settings.assist.magnet
+    }
+}
+
+/// Steers the ball so the point it will cross the paddle's line at eases toward the
+/// paddle's centre. Walls are ignored; near the paddle there is rarely one in the way.
+fn pull_balls(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    ranked: Res<RankedPlay>,
+    arena: Res<Arena>,
+    paddles: Query<&Transform, (With<Paddle>, Without<AiPaddle>)>,
+    mut balls: Query<(&Transform, &mut Velocity), With<Ball>>,
+) {
+    let strength = strength(&settings, &ranked);
+    if strength == MagnetStrength::Off {
+        return;
+    }
+    let pull = 1.0 - (-strength.rate() * time.delta_seconds()).exp();
+    for (ball, mut velocity) in &mut balls {
+        let ball_position = ball.translation.truncate();
+        for paddle in &paddles {
+            let paddle_position = paddle.translation.truncate();
+            let gap = paddle_position.x - ball_position.x;
+            // Heading for this paddle, and close enough to it.
+            if gap * velocity.linvel.x <= 0.0 || gap.abs() > RANGE * arena.scale {
+                continue;
+            }
+            let seconds = gap / velocity.linvel.x;
+            let crossing = ball_position.y + velocity.linvel.y * seconds;
+            let miss = paddle_position.y - crossing;
+            let reach = (PADDLE_HALF_HEIGHT * paddle.scale.y + REACH) * arena.scale;
+            if miss.abs() > reach {
+                continue;
+            }
+            velocity.linvel.y += miss / seconds * pull;
+        }
+    }
+}
+
+fn spawn_flag(mut commands: Commands, fonts: Res<FontStack>) {
+    commands.spawn((
+        TextBundle {
+            text: fonts.text(
+                "",
+                TextStyle {
+                    font_size: 16.0,
+                    color: FLAG_COLOUR,
+                    ..Default::default()
+                },
+            ),
+            ..Default::default()
+        }
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(12.0),
+            right: Val::Px(12.0),
+            padding: UiRect::all(Val::Px(4.0)),
+            ..Default::default()
+        })
+        .with_background_color(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        MagnetFlag,
+    ));
+}
+
+fn show_flag(
+    settings: Res<Settings>,
+    ranked: Res<RankedPlay>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    mut flags: Query<(&mut Text, &mut Visibility), With<MagnetFlag>>,
+) {
+    let chosen = settings.assist.magnet;
+    let value = if chosen == MagnetStrength::Off {
+        None
+    } else if strength(&settings, &ranked) == MagnetStrength::Off {
+        Some(locale.text("magnet-ranked"))
+    } else {
+        Some(locale.format(
+            "magnet-flag",
+            &[("strength", locale.text(chosen.name()).into())],
+        ))
+    };
+    for (mut text, mut visibility) in &mut flags {
+        *visibility = match &value {
+            Some(value) => {
+                fonts.set(&mut text, value);
+                Visibility::Inherited
+            }
+            None => Visibility::Hidden,
+        };
+    }
+}
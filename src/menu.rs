@@ -0,0 +1,421 @@
+use bevy::{prelude::*, utils::HashMap, window::WindowFocused};
+
+use crate::{
+    GameState,
+    fonts::FontStack,
+    locale::{Locale, LocalizedText},
+    narration::Announcement,
+    settings::Settings,
+};
+
+/// How many rows the pause menu shows at once; it scrolls to keep the selected one in view.
+/// Fits a 1280x720 window with the title and the scroll markers.
+const VISIBLE_ROWS: usize = 9;
+
+/// One adjustable line in the pause menu.
+pub struct SettingRow {
+    /// Message id of the label.
+    pub label: &'static str,
+    pub value: fn(&Settings, &Locale) -> String,
+    /// Called with `-1` / `1` for left / right, and `1` for enter.
+    pub change: fn(&mut Settings, i32),
+}
+
+/// Display helper for boolean rows.
+pub fn on_off(locale: &Locale, value: bool) -> String {
+    locale.text(if value { "setting-on" } else { "setting-off" })
+}
+
+/// A pause menu line choosing from a list only known at runtime, such as skins from a
+/// manifest. The list lives in [`MenuChoices`] under `list`; settings keep the chosen id.
+pub struct ChoiceRow {
+    /// Message id of the label.
+    pub label: &'static str,
+    pub list: &'static str,
+    pub selected: fn(&Settings) -> &str,
+    pub select: fn(&mut Settings, &str),
+}
+
+/// A pause menu line holding one key. Enter, or left or right, waits for the next key
+/// pressed and binds it; Escape cancels. A key taken from another key row is swapped over
+/// to it, so no two actions ever share a key.
+pub struct KeyRow {
+    /// Message id of the label.
+    pub label: &'static str,
+    pub key: fn(&Settings) -> KeyCode,
+    pub bind: fn(&mut Settings, KeyCode),
+}
+
+pub struct Choice {
+    pub id: String,
+    /// Shown as is; choices come from data files, not translations.
+    pub name: String,
+}
+
+/// Option lists for [`ChoiceRow`]s, by list name. Plugins refill them as their data loads.
+#[derive(Resource, Default)]
+pub struct MenuChoices(pub HashMap<&'static str, Vec<Choice>>);
+
+enum Row {
+    Setting(SettingRow),
+    Choice(ChoiceRow),
+    Key(KeyRow),
+}
+
+impl Row {
+    fn label(&self) -> &'static str {
+        match self {
+            Row::Setting(row) => row.label,
+            Row::Choice(row) => row.label,
+            Row::Key(row) => row.label,
+        }
+    }
+
+    fn value(&self, settings: &Settings, locale: &Locale, choices: &MenuChoices) -> String {
+        match self {
+            Row::Setting(row) => (row.value)(settings, locale),
+            Row::Choice(row) => {
+                let id = (row.selected)(settings);
+                choices
+                    .0
+                    .get(row.list)
+                    .and_then(|list| list.iter().find(|c| c.id == id))
+                    .map_or_else(|| id.to_string(), |c| c.name.clone())
+            }
+            Row::Key(row) => format!("{:?}", (row.key)(settings)),
+        }
+    }
+
+    fn change(&self, settings: &mut Settings, step: i32, choices: &MenuChoices) {
+        match self {
+            Row::Setting(row) => (row.change)(settings, step),
+            Row::Choice(row) => {
+                let Some(list) = choices.0.get(row.list).filter(|l| !l.is_empty()) else {
+                    return;
+                };
+                let current = list.iter().position(|c| c.id == (row.selected)(settings));
+                let index = current.map_or(0, |i| {
+                    (i as i32 + step).rem_euclid(list.len() as i32) as usize
+                });
+                (row.select)(settings, &list[index].id);
+            }
+            // Keys are rebound by capturing one instead; see `navigate_menu`.
+            Row::Key(_) => {}
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct SettingRows(Vec<Row>);
+
+/// Lets any plugin add its options to the pause menu.
+pub trait AppSettingsExt {
+    fn add_setting_row(&mut self, row: SettingRow) -> &mut Self;
+    fn add_choice_row(&mut self, row: ChoiceRow) -> &mut Self;
+    fn add_key_row(&mut self, row: KeyRow) -> &mut Self;
+}
+
+impl AppSettingsExt for App {
+    fn add_setting_row(&mut self, row: SettingRow) -> &mut Self {
+        self.init_resource::<SettingRows>();
+        self.world_mut()
+            .resource_mut::<SettingRows>()
+            .0
+            .push(Row::Setting(row));
+        self
+    }
+
+    fn add_choice_row(&mut self, row: ChoiceRow) -> &mut Self {
+        self.init_resource::<SettingRows>();
+        self.world_mut()
+            .resource_mut::<SettingRows>()
+            .0
+            .push(Row::Choice(row));
+        self
+    }
+
+    fn add_key_row(&mut self, row: KeyRow) -> &mut Self {
+        self.init_resource::<SettingRows>();
+        self.world_mut()
+            .resource_mut::<SettingRows>()
+            .0
+            .push(Row::Key(row));
+        self
+    }
+}
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SettingRows>();
+        app.init_resource::<MenuChoices>();
+        app.init_resource::<Selected>();
+        app.init_resource::<Capturing>();
+        app.add_setting_row(SettingRow {
+            label: "setting-keep-playing-unfocused",
+            value: |s, l| on_off(l, s.keep_playing_unfocused),
+            change: |s, _| s.keep_playing_unfocused = !s.keep_playing_unfocused,
+        });
+        app.add_systems(
+            Update,
+            (toggle_pause.before(navigate_menu), pause_on_focus_loss),
+        );
+        app.add_systems(OnEnter(GameState::Paused), spawn_menu);
+        app.add_systems(
+            OnExit(GameState::Paused),
+            (despawn_menu, |mut capturing: ResMut<Capturing>| {
+                capturing.0 = false;
+            }),
+        );
+        app.add_systems(
+            Update,
+            (navigate_menu, refresh_menu)
+                .chain()
+                .run_if(in_state(GameState::Paused)),
+        );
+    }
+}
+
+#[derive(Resource, Default)]
+struct Selected(usize);
+
+/// The selected key row is waiting for a key to be pressed.
+#[derive(Resource, Default)]
+struct Capturing(bool);
+
+#[derive(Component)]
+struct MenuRoot;
+
+#[derive(Component)]
+struct MenuRow(usize);
+
+/// Shown above or below the rows while there are more that way.
+#[derive(Component)]
+enum ScrollMarker {
+    Up,
+    Down,
+}
+
+fn toggle_pause(
+    input: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    capturing: Res<Capturing>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    // While a key is being captured, Escape cancels that instead.
+    if !input.just_pressed(KeyCode::Escape) || capturing.0 {
+        return;
+    }
+    match state.get() {
+        GameState::Playing => next.set(GameState::Paused),
+        GameState::Paused => next.set(GameState::Playing),
+        // Escape skips the replay instead.
+        GameState::Replay | GameState::Intermission | GameState::Loading | GameState::Splash => {}
+    }
+}
+
+/// Alt-tabbing away mid-rally shouldn't cost anyone a point.
+fn pause_on_focus_loss(
+    mut focus: EventReader<WindowFocused>,
+    settings: Res<Settings>,
+    state: Res<State<GameState>>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    let lost = focus.read().any(|event| !event.focused);
+    if lost && !settings.keep_playing_unfocused && *state.get() == GameState::Playing {
+        next.set(GameState::Paused);
+    }
+}
+
+fn spawn_menu(
+    mut commands: Commands,
+    rows: Res<SettingRows>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    row_gap: Val::Px(12.0),
+                    ..Default::default()
+                },
+                // Dims the court, so it is clear the game is paused and the text stands out.
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.6).into(),
+                z_index: ZIndex::Global(5),
+                ..Default::default()
+            },
+            MenuRoot,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                fonts.text_bundle(&locale.text("menu-paused"), 80.),
+                LocalizedText("menu-paused"),
+            ));
+            p.spawn((fonts.text_bundle("▲", 24.), ScrollMarker::Up));
+            for index in 0..rows.0.len() {
+                p.spawn((fonts.text_bundle("", 32.), MenuRow(index)));
+            }
+            p.spawn((fonts.text_bundle("▼", 24.), ScrollMarker::Down));
+        });
+}
+
+fn despawn_menu(mut commands: Commands, menus: Query<Entity, With<MenuRoot>>) {
+    for menu in &menus {
+        commands.entity(menu).despawn_recursive();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn navigate_menu(
+    mut input: ResMut<ButtonInput<KeyCode>>,
+    rows: Res<SettingRows>,
+    mut selected: ResMut<Selected>,
+    mut capturing: ResMut<Capturing>,
+    mut settings: ResMut<Settings>,
+    locale: Res<Locale>,
+    choices: Res<MenuChoices>,
+    mut announcements: EventWriter<Announcement>,
+) {
+    if rows.0.is_empty() {
+        return;
+    }
+    let count = rows.0.len();
+    if capturing.0 {
+        let Some(key) = input.get_just_pressed().next().copied() else {
+            return;
+        };
+        capturing.0 = false;
+        // Nothing else should act on the key that was just bound.
+        input.clear_just_pressed(key);
+        let row = &rows.0[selected.0.min(count - 1)];
+        if let Row::Key(key_row) = row
+            && key != KeyCode::Escape
+        {
+            bind_key(&rows, key_row, key, &mut settings);
+        }
+        announcements.send(Announcement(format!(
+            "{}: {}",
+            locale.text(row.label()),
+            row.value(&settings, &locale, &choices)
+        )));
+        return;
+    }
+    let mut moved = false;
+
+    if input.any_just_pressed([KeyCode::ArrowUp, KeyCode::KeyW]) {
+        selected.0 = (selected.0 + count - 1) % count;
+        moved = true;
+    }
+    if input.any_just_pressed([KeyCode::ArrowDown, KeyCode::KeyS]) {
+        selected.0 = (selected.0 + 1) % count;
+        moved = true;
+    }
+
+    let row = &rows.0[selected.0.min(count - 1)];
+    let left = input.any_just_pressed([KeyCode::ArrowLeft, KeyCode::KeyA]);
+    let right = input.any_just_pressed([KeyCode::ArrowRight, KeyCode::KeyD])
+        // Alt+Enter belongs to the fullscreen toggle.
+        || (input.just_pressed(KeyCode::Enter)
+            && !input.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]));
+    if (left || right) && matches!(row, Row::Key(_)) {
+        capturing.0 = true;
+        announcements.send(Announcement(format!(
+            "{}: {}",
+            locale.text(row.label()),
+            locale.text("menu-press-key")
+        )));
+        return;
+    }
+    if left {
+        row.change(&mut settings, -1, &choices);
+    } else if right {
+        row.change(&mut settings, 1, &choices);
+    } else if !moved {
+        return;
+    }
+    announcements.send(Announcement(format!(
+        "{}: {}",
+        locale.text(row.label()),
+        row.value(&settings, &locale, &choices)
+    )));
+}
+
+/// Binds `key` to `row`, handing the row's old key to any other row that had `key`.
+fn bind_key(rows: &SettingRows, row: &KeyRow, key: KeyCode, settings: &mut Settings) {
+    let old = (row.key)(settings);
+    for other in &rows.0 {
+        match other {
+            Row::Key(other) if other.label != row.label && (other.key)(settings) == key => {
+                (other.bind)(settings, old);
+            }
+            _ => {}
+        }
+    }
+    (row.bind)(settings, key);
+}
+
+/// Only a window of [`VISIBLE_ROWS`] rows starting at `top` is laid out, moved along just
+/// enough to keep the selected row in it.
+#[allow(clippy::too_many_arguments)]
+fn refresh_menu(
+    rows: Res<SettingRows>,
+    selected: Res<Selected>,
+    capturing: Res<Capturing>,
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+    choices: Res<MenuChoices>,
+    fonts: Res<FontStack>,
+    mut top: Local<usize>,
+    mut texts: Query<(&mut Text, &mut Style, &MenuRow)>,
+    mut markers: Query<(&mut Visibility, &ScrollMarker)>,
+) {
+    let count = rows.0.len();
+    if selected.0 < *top {
+        *top = selected.0;
+    } else if selected.0 >= *top + VISIBLE_ROWS {
+        *top = selected.0 + 1 - VISIBLE_ROWS;
+    }
+    *top = (*top).min(count.saturating_sub(VISIBLE_ROWS));
+    let shown = *top..*top + VISIBLE_ROWS;
+
+    for (mut visibility, marker) in &mut markers {
+        let more = match marker {
+            ScrollMarker::Up => shown.start > 0,
+            ScrollMarker::Down => shown.end < count,
+        };
+        // Hidden rather than removed from the layout, so the rows don't jump.
+        visibility.set_if_neq(if more {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+
+    for (mut text, mut style, MenuRow(index)) in &mut texts {
+        let display = if shown.contains(index) {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if style.display != display {
+            style.display = display;
+        }
+        let row = &rows.0[*index];
+        let cursor = if *index == selected.0 { "> " } else { "  " };
+        let value = if *index == selected.0 && capturing.0 {
+            locale.text("menu-press-key")
+        } else {
+            row.value(&settings, &locale, &choices)
+        };
+        let value = format!("{cursor}{}: {value}", locale.text(row.label()));
+        fonts.set(&mut text, &value);
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::{input::InputSystem, prelude::*};
+use std::f32::consts::PI;
+
+use crate::{
+    GameState, Paddle,
+    arena::{Arena, orient_camera},
+    camera::MainCamera,
+    mutators::{ActiveMutators, Mutator},
+};
+
+/// Seconds of play between flips.
+const FLIP_SECONDS: f32 = 15.0;
+
+/// Whether the court is shown upside down, and how long until it turns again.
+#[derive(Resource)]
+pub struct Flip {
+    flipped: bool,
+    timer: Timer,
+}
+
+impl Flip {
+    pub fn flipped(&self) -> bool {
+        self.flipped
+    }
+}
+
+impl Default for Flip {
+    fn default() -> Self {
+        Flip {
+            flipped: false,
+            timer: Timer::from_seconds(FLIP_SECONDS, TimerMode::Repeating),
+        }
+    }
+}
+
+/// The mirror mutator, a party-mode challenge: every 15 seconds of play the view turns
+/// half a turn, so each player's paddle is suddenly on the other side of the screen.
+/// Up and down keys are swapped while it is turned, so a paddle still moves the way its
+/// key points on screen; it is only everything else that has moved.
+pub struct MirrorPlugin;
+
+impl Plugin for MirrorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Flip>();
+        app.add_systems(PreUpdate, mirror_controls.after(InputSystem));
+        app.add_systems(
+            Update,
+            (
+                flip_court,
+                turn_camera
+                    .after(orient_camera)
+                    .run_if(resource_changed::<Flip>.or_else(resource_changed::<Arena>)),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Only play time counts, and the court rights itself when the mutator goes.
+fn flip_court(
+    time: Res<Time>,
+    state: Res<State<GameState>>,
+    mutators: Res<ActiveMutators>,
+    mut flip: ResMut<Flip>,
+) {
+    if !mutators.has(Mutator::Mirror) {
+        if flip.flipped {
+            *flip = Flip::default();
+        }
+        return;
+    }
+    if *state.get() != GameState::Playing {
+        return;
+    }
+    if flip.timer.tick(time.delta()).just_finished() {
+        flip.flipped = !flip.flipped;
+    }
+}
+
+fn turn_camera(flip: Res<Flip>, arena: Res<Arena>, mut cameras: Query<&mut Transform, MainCamera>) {
+    let turn = if flip.flipped { PI } else { 0.0 };
+    for mut transform in &mut cameras {
+        transform.rotation = Quat::from_rotation_z(arena.view_angle() + turn);
+    }
+}
+
+/// Everything reading the keyboard sees the swapped keys, so this runs before anything
+/// else looks at it this frame.
+pub fn mirror_controls(
+    flip: Res<Flip>,
+    paddles: Query<&Paddle>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+) {
+    if !flip.flipped {
+        return;
+    }
+    let mut swapped = Vec::new();
+    for paddle in &paddles {
+        // The co-op partner shares Player 2's keys, which must only swap once.
+        if swapped.contains(&paddle.move_up) {
+            continue;
+        }
+        swapped.push(paddle.move_up);
+        let up = KeyState::of(&keys, paddle.move_up);
+        let down = KeyState::of(&keys, paddle.move_down);
+        up.set(&mut keys, paddle.move_down);
+        down.set(&mut keys, paddle.move_up);
+    }
+}
+
+struct KeyState {
+    pressed: bool,
+    just_pressed: bool,
+    just_released: bool,
+}
+
+impl KeyState {
+    fn of(keys: &ButtonInput<KeyCode>, key: KeyCode) -> Self {
+        KeyState {
+            pressed: keys.pressed(key),
+            just_pressed: keys.just_pressed(key),
+            just_released: keys.just_released(key),
+        }
+    }
+
+    /// `ButtonInput` only changes through presses and releases, so the state is rebuilt
+    /// from those.
+    fn set(&self, keys: &mut ButtonInput<KeyCode>, key: KeyCode) {
+        keys.reset(key);
+        if self.just_released {
+            keys.press(key);
+            keys.release(key);
+        }
+        if self.pressed {
+            keys.press(key);
+        }
+        if !self.just_pressed {
+            keys.clear_just_pressed(key);
+        }
+    }
+}
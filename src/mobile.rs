@@ -0,0 +1,84 @@
+use bevy::{prelude::*, window::AppLifecycle};
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    GameState, Paddle, Player, ScoreBoard, ai::AiPaddle, arena::Arena, camera::MainCamera,
+    move_paddle, paddle_velocity,
+};
+
+/// Touch controls and app lifecycle handling for phones and tablets. Everything here is
+/// harmless on desktop, where touch screens and mouse clicks use it too.
+pub struct MobilePlugin;
+
+impl Plugin for MobilePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                touch_paddles
+                    .after(move_paddle)
+                    .run_if(in_state(GameState::Playing)),
+                toggle_pause_on_tap,
+                pause_on_suspend,
+            ),
+        );
+    }
+}
+
+/// Each half of the court belongs to one player; their paddle follows any finger on it.
+fn touch_paddles(
+    touches: Res<Touches>,
+    cameras: Query<(&Camera, &GlobalTransform), MainCamera>,
+    arena: Res<Arena>,
+    time: Res<Time>,
+    mut paddles: Query<(&Transform, &mut Velocity, &Player, Has<AiPaddle>), With<Paddle>>,
+) {
+    let Ok((camera, camera_transform)) = cameras.get_single() else {
+        return;
+    };
+    for touch in touches.iter() {
+        // Goes through the camera, so it stays right when the court is rotated for portrait.
+        let Some(point) = camera.viewport_to_world_2d(camera_transform, touch.position()) else {
+            continue;
+        };
+        let side = if point.x < 0.0 {
+            Player::Player1
+        } else {
+            Player::Player2
+        };
+        for (transform, mut velocity, player, ai) in &mut paddles {
+            if *player == side && !ai {
+                velocity.linvel = paddle_velocity(&arena, transform, point.y, time.delta_seconds());
+            }
+        }
+    }
+}
+
+/// There is no Escape key on a phone, so tapping the scoreboard pauses and resumes.
+fn toggle_pause_on_tap(
+    boards: Query<&Interaction, (Changed<Interaction>, With<ScoreBoard>)>,
+    state: Res<State<GameState>>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    if !boards.iter().any(|i| *i == Interaction::Pressed) {
+        return;
+    }
+    match state.get() {
+        GameState::Playing => next.set(GameState::Paused),
+        GameState::Paused => next.set(GameState::Playing),
+        GameState::Replay | GameState::Intermission | GameState::Loading | GameState::Splash => {}
+    }
+}
+
+/// Pause before the OS puts the app in the background, so nobody comes back to a lost point.
+fn pause_on_suspend(
+    mut lifecycle: EventReader<AppLifecycle>,
+    state: Res<State<GameState>>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    for event in lifecycle.read() {
+        if *event == AppLifecycle::WillSuspend && *state.get() == GameState::Playing {
+            next.set(GameState::Paused);
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use bevy::{asset::io::AssetSourceBuilder, prelude::*};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    console::{AppConsoleExt, ConsoleCommand},
+    skins::{Skin, SkinManifest},
+    theme::{ThemePack, ThemePacks},
+    tuning::ApplyConfig,
+};
+
+const MODS_DIR: &str = "mods";
+/// Asset source for files inside mods, e.g. `mods://my-mod/walls.png`.
+const MODS_SOURCE: &str = "mods";
+
+/// Makes files in `mods/` loadable like assets. Asset sources have to be registered before
+/// the asset plugin is added.
+pub fn register_asset_source(app: &mut App) {
+    app.register_asset_source(
+        MODS_SOURCE,
+        AssetSourceBuilder::platform_default(MODS_DIR, None),
+    );
+}
+
+/// What the mods in `mods/` add to the game, and what had to be left out. Each mod is a
+/// folder; entries from a mod earlier in alphabetical order win over later ones, and
+/// built-in entries win over all of them.
+#[derive(Resource, Default)]
+pub struct ModCatalog {
+    mods: Vec<String>,
+    themes: Vec<(String, ThemePack)>,
+    paddle_skins: Vec<(String, Skin)>,
+    ball_skins: Vec<(String, Skin)>,
+    /// Arena files by name, for `--arena <name>`.
+    pub arenas: HashMap<String, PathBuf>,
+    /// Who first claimed each id, by kind of entry.
+    owners: HashMap<(&'static str, String), String>,
+    /// One line per entry that was left out, for the `mods` console command.
+    problems: Vec<String>,
+}
+
+impl ModCatalog {
+    fn add_mod(&mut self, dir: &Path) {
+        let name = dir
+            .file_name()
+            .map_or_else(String::new, |n| n.to_string_lossy().into_owned());
+
+        if let Some(packs) = read_json::<Vec<ThemePack>>(&dir.join("themes.json")) {
+            for mut pack in packs {
+                if self.claim("theme", &pack.id, &name) {
+                    pack.theme.wall_image = pack.theme.wall_image.map(|p| mod_asset(&name, &p));
+                    pack.theme.music = pack.theme.music.map(|p| mod_asset(&name, &p));
+                    self.themes.push((name.clone(), pack));
+                }
+            }
+        }
+
+        if let Some(skins) = read_json::<SkinManifest>(&dir.join("skins.json")) {
+            for mut skin in skins.paddles {
+                if self.claim("paddle skin", &skin.id, &name) {
+                    skin.image = skin.image.map(|p| mod_asset(&name, &p));
+                    self.paddle_skins.push((name.clone(), skin));
+                }
+            }
+            for mut skin in skins.balls {
+                if self.claim("ball skin", &skin.id, &name) {
+                    skin.image = skin.image.map(|p| mod_asset(&name, &p));
+                    self.ball_skins.push((name.clone(), skin));
+                }
+            }
+        }
+
+        if let Ok(entries) = fs::read_dir(dir.join("arenas")) {
+            for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+                if path.extension().is_none_or(|ext| ext != "ron") {
+                    continue;
+                }
+                let Some(stem) = path.file_stem().map(|s| s.to_string_lossy().into_owned()) else {
+                    continue;
+                };
+                if self.claim("arena", &stem, &name) {
+                    self.arenas.insert(stem, path);
+                }
+            }
+        }
+
+        // Nothing in the game plays these yet; say so rather than ignoring them silently.
+        for unsupported in ["sounds", "powerups.json"] {
+            if dir.join(unsupported).exists() {
+                self.problem(format!(
+                    "mod `{name}`: `{unsupported}` isn't supported yet and was skipped"
+                ));
+            }
+        }
+
+        self.mods.push(name);
+    }
+
+    /// Records `owner` as the mod defining `id`, unless an earlier mod already does.
+    fn claim(&mut self, kind: &'static str, id: &str, owner: &str) -> bool {
+        match self.owners.get(&(kind, id.to_string())) {
+            Some(first) => {
+                let first = first.clone();
+                self.problem(format!(
+                    "mod `{owner}`: {kind} `{id}` is already defined by mod `{first}`, skipped"
+                ));
+                false
+            }
+            None => {
+                self.owners
+                    .insert((kind, id.to_string()), owner.to_string());
+                true
+            }
+        }
+    }
+
+    fn problem(&mut self, message: String) {
+        warn!("{message}");
+        if !self.problems.contains(&message) {
+            self.problems.push(message);
+        }
+    }
+
+    /// Adds mod entries to a built-in list, leaving out ids the list already has.
+    fn merge_into<T: Clone>(
+        &mut self,
+        kind: &str,
+        list: &mut Vec<T>,
+        entries: impl Fn(&ModCatalog) -> &[(String, T)],
+        id: fn(&T) -> &str,
+    ) {
+        let mut problems = Vec::new();
+        for (owner, entry) in entries(self) {
+            if list.iter().any(|e| id(e) == id(entry)) {
+                problems.push(format!(
+                    "mod `{owner}`: {kind} `{}` is already built in, skipped",
+                    id(entry)
+                ));
+            } else {
+                list.push(entry.clone());
+            }
+        }
+        for message in problems {
+            self.problem(message);
+        }
+    }
+}
+
+/// A built-in catalog that mods add entries to.
+trait Moddable: Asset + Resource {
+    fn merge(&mut self, catalog: &mut ModCatalog);
+}
+
+impl Moddable for ThemePacks {
+    fn merge(&mut self, catalog: &mut ModCatalog) {
+        catalog.merge_into("theme", &mut self.0, |c| &c.themes, |p| &p.id);
+    }
+}
+
+impl Moddable for SkinManifest {
+    fn merge(&mut self, catalog: &mut ModCatalog) {
+        catalog.merge_into(
+            "paddle skin",
+            &mut self.paddles,
+            |c| &c.paddle_skins,
+            |s| &s.id,
+        );
+        catalog.merge_into("ball skin", &mut self.balls, |c| &c.ball_skins, |s| &s.id);
+    }
+}
+
+fn mod_asset(name: &str, path: &str) -> String {
+    format!("{MODS_SOURCE}://{name}/{path}")
+}
+
+/// `None` if the file doesn't exist; a broken file is also reported.
+fn read_json<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    let raw = fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&raw) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            warn!("ignoring {}: {err}", path.display());
+            None
+        }
+    }
+}
+
+/// Adds themes, skins and arenas from folders in `mods/`, next to the built-in ones.
+pub struct ModsPlugin;
+
+impl Plugin for ModsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ModCatalog>();
+        app.add_console_command(ConsoleCommand {
+            name: "mods",
+            usage: "mods",
+            run: list_mods,
+        });
+        app.add_systems(PreStartup, scan_mods);
+        app.add_systems(
+            PreUpdate,
+            (merge_mods::<ThemePacks>, merge_mods::<SkinManifest>).after(ApplyConfig),
+        );
+    }
+}
+
+fn scan_mods(mut catalog: ResMut<ModCatalog>) {
+    let Ok(entries) = fs::read_dir(MODS_DIR) else {
+        return;
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    // Sorted, so the same mod wins a conflict on every run.
+    dirs.sort();
+    for dir in dirs {
+        catalog.add_mod(&dir);
+    }
+    if !catalog.mods.is_empty() {
+        info!("loaded mods: {}", catalog.mods.join(", "));
+    }
+}
+
+/// The built-in list is copied from its file whenever that loads, so mod entries are added
+/// again each time.
+fn merge_mods<T: Moddable>(
+    mut events: EventReader<AssetEvent<T>>,
+    mut catalog: ResMut<ModCatalog>,
+    mut target: ResMut<T>,
+) {
+    let loaded = events.read().any(|event| {
+        matches!(
+            event,
+            AssetEvent::LoadedWithDependencies { .. } | AssetEvent::Modified { .. }
+        )
+    });
+    if loaded {
+        target.merge(&mut catalog);
+    }
+}
+
+fn list_mods(world: &mut World, _: &[&str]) -> Result<String, String> {
+    let catalog = world.resource::<ModCatalog>();
+    if catalog.mods.is_empty() {
+        return Ok(format!("no mods in {MODS_DIR}/"));
+    }
+    let mut lines = vec![format!("mods: {}", catalog.mods.join(", "))];
+    lines.extend(catalog.problems.iter().cloned());
+    Ok(lines.join("\n"))
+}
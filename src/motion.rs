@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::{
+    menu::{AppSettingsExt, SettingRow, on_off},
+    settings::Settings,
+};
+
+/// Whether the player wants decorative motion turned off. Screen shake, particles,
+/// background animation and flashing effects check this (or use [`motion_allowed`]);
+/// gameplay never does.
+#[derive(Resource, Clone, Copy, Default)]
+pub struct MotionPreferences {
+    pub reduced: bool,
+}
+
+/// Run condition for purely decorative motion.
+pub fn motion_allowed(motion: Res<MotionPreferences>) -> bool {
+    !motion.reduced
+}
+
+pub struct MotionPlugin;
+
+impl Plugin for MotionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MotionPreferences>();
+        app.add_setting_row(SettingRow {
+            label: "setting-reduced-motion",
+            value: |s, l| on_off(l, s.reduced_motion),
+            change: |s, _| s.reduced_motion = !s.reduced_motion,
+        });
+        app.add_systems(
+            PreUpdate,
+            sync_preferences.run_if(resource_changed::<Settings>),
+        );
+    }
+}
+
+fn sync_preferences(settings: Res<Settings>, mut motion: ResMut<MotionPreferences>) {
+    motion.reduced = settings.reduced_motion;
+}
@@ -0,0 +1,342 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Ball, GameEvents, Paddle, Player,
+    arena::Arena,
+    assist::ball_scale,
+    ball_bundle,
+    layout::OBSTACLES,
+    reset_ball,
+    rng::GameRng,
+    serve::{Served, Serving, launch_serves},
+    settings::Settings,
+    tuning::GameplayConfig,
+};
+
+const FAST_SERVE_FACTOR: f32 = 1.5;
+const WIDE_PADDLE_SCALE: f32 = 1.4;
+const TINY_PADDLE_SCALE: f32 = 0.6;
+/// Downward pull on the ball, in pixels per second squared on a standard court.
+const GRAVITY: f32 = 250.0;
+/// How far a light ball's heading wanders, in radians per second at most.
+const LIGHT_JITTER: f32 = 4.0;
+/// A ghost ball is see-through.
+const GHOST_ALPHA: f32 = 0.45;
+
+/// A rule change, switched on by a campaign stage or a custom arena.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Mutator {
+    /// Serves leave at one and a half times the usual speed.
+    FastServes,
+    /// The opponent's paddle is as long as a large-paddle assist.
+    WideOpponent,
+    /// A big, slow ball that smashes bricks in one hit.
+    HeavyBall,
+    /// A small, fast ball that never quite flies straight.
+    LightBall,
+    /// A see-through ball that passes through blocks and bricks, but not paddles or walls.
+    GhostBall,
+    /// Every so often the court turns upside down. See `mirror`.
+    Mirror,
+    /// Every so often the lights go out but for spotlights on the ball and paddles. See
+    /// `lights_out`.
+    LightsOut,
+    /// Every serve sends a second ball the other way from the middle.
+    Multiball,
+    /// Both paddles are a little over half their usual length.
+    TinyPaddles,
+    /// The ball falls towards the bottom wall.
+    Gravity,
+}
+
+impl Mutator {
+    pub const ALL: [Mutator; 10] = [
+        Mutator::FastServes,
+        Mutator::WideOpponent,
+        Mutator::HeavyBall,
+        Mutator::LightBall,
+        Mutator::GhostBall,
+        Mutator::Mirror,
+        Mutator::LightsOut,
+        Mutator::Multiball,
+        Mutator::TinyPaddles,
+        Mutator::Gravity,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Mutator::FastServes => "mutator-fast-serves",
+            Mutator::WideOpponent => "mutator-wide-opponent",
+            Mutator::HeavyBall => "mutator-heavy-ball",
+            Mutator::LightBall => "mutator-light-ball",
+            Mutator::GhostBall => "mutator-ghost-ball",
+            Mutator::Mirror => "mutator-mirror",
+            Mutator::LightsOut => "mutator-lights-out",
+            Mutator::Multiball => "mutator-multiball",
+            Mutator::TinyPaddles => "mutator-tiny-paddles",
+            Mutator::Gravity => "mutator-gravity",
+        }
+    }
+}
+
+/// Which ball is in play. Set by the ball mutators; if several are on, the first listed wins.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BallType {
+    Normal,
+    Heavy,
+    Light,
+    Ghost,
+}
+
+impl BallType {
+    /// Serve speed, relative to the usual one.
+    fn speed(self) -> f32 {
+        match self {
+            BallType::Heavy => 0.75,
+            BallType::Light => 1.3,
+            BallType::Normal | BallType::Ghost => 1.0,
+        }
+    }
+
+    /// Size, relative to the usual one. It scales the collider too.
+    pub fn scale(self) -> f32 {
+        match self {
+            BallType::Heavy => 1.35,
+            BallType::Light => 0.75,
+            BallType::Normal | BallType::Ghost => 1.0,
+        }
+    }
+
+    fn density(self) -> f32 {
+        match self {
+            BallType::Heavy => 4.0,
+            BallType::Light => 0.5,
+            BallType::Normal | BallType::Ghost => 1.0,
+        }
+    }
+
+    fn collision_groups(self) -> CollisionGroups {
+        match self {
+            BallType::Ghost => CollisionGroups::new(Group::ALL, Group::ALL.difference(OBSTACLES)),
+            _ => CollisionGroups::default(),
+        }
+    }
+}
+
+/// The mutators in play for the current match.
+#[derive(Resource, Default)]
+pub struct ActiveMutators(pub Vec<Mutator>);
+
+impl ActiveMutators {
+    pub fn has(&self, mutator: Mutator) -> bool {
+        self.0.contains(&mutator)
+    }
+
+    /// Paddle length, relative to the usual one, before the assists and handicaps.
+    pub fn paddle_scale(&self) -> f32 {
+        if self.has(Mutator::TinyPaddles) {
+            TINY_PADDLE_SCALE
+        } else {
+            1.0
+        }
+    }
+
+    /// How fast the gravity mutator pulls balls down, in pixels per second squared on a
+    /// standard court. Nothing without it.
+    pub fn gravity(&self) -> f32 {
+        if self.has(Mutator::Gravity) {
+            GRAVITY
+        } else {
+            0.0
+        }
+    }
+
+    pub fn ball_type(&self) -> BallType {
+        self.0
+            .iter()
+            .find_map(|mutator| match mutator {
+                Mutator::HeavyBall => Some(BallType::Heavy),
+                Mutator::LightBall => Some(BallType::Light),
+                Mutator::GhostBall => Some(BallType::Ghost),
+                _ => None,
+            })
+            .unwrap_or(BallType::Normal)
+    }
+}
+
+pub struct MutatorsPlugin;
+
+impl Plugin for MutatorsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActiveMutators>();
+        app.add_systems(
+            Update,
+            (
+                widen_opponent,
+                shape_balls,
+                fade_ghosts.after(crate::ball_hit),
+                jitter_light_balls,
+                pull_balls_down,
+            ),
+        );
+        app.add_systems(
+            Update,
+            (speed_up_serves, add_extra_balls)
+                .chain()
+                .after(launch_serves),
+        );
+        app.add_systems(PostUpdate, remove_extra_balls.after(reset_ball));
+    }
+}
+
+/// A multiball mutator's second ball. Only the first goal of a rally counts, so it goes
+/// when the rally ends.
+#[derive(Component)]
+struct ExtraBall;
+
+/// Reapplied every frame, so it outlasts the assists resizing the paddles.
+fn widen_opponent(
+    mutators: Res<ActiveMutators>,
+    mut paddles: Query<(&mut Transform, &Player), With<Paddle>>,
+) {
+    if !mutators.has(Mutator::WideOpponent) {
+        return;
+    }
+    for (mut transform, player) in &mut paddles {
+        if *player == Player::Player2 {
+            transform.scale.y = WIDE_PADDLE_SCALE;
+        }
+    }
+}
+
+fn speed_up_serves(
+    mut served: EventReader<Served>,
+    mutators: Res<ActiveMutators>,
+    mut balls: Query<&mut Velocity, With<Ball>>,
+) {
+    let mut factor = mutators.ball_type().speed();
+    if mutators.has(Mutator::FastServes) {
+        factor *= FAST_SERVE_FACTOR;
+    }
+    for Served { ball, .. } in served.read() {
+        if let Ok(mut velocity) = balls.get_mut(*ball) {
+            velocity.linvel *= factor;
+        }
+    }
+}
+
+/// Sets the second ball off from the middle, the opposite way to the serve and as fast.
+fn add_extra_balls(
+    mut commands: Commands,
+    mut served: EventReader<Served>,
+    mutators: Res<ActiveMutators>,
+    config: Res<GameplayConfig>,
+    balls: Query<&Velocity, With<Ball>>,
+    extras: Query<(), With<ExtraBall>>,
+) {
+    let Some(Served { ball }) = served.read().last() else {
+        return;
+    };
+    if !mutators.has(Mutator::Multiball) || !extras.is_empty() {
+        return;
+    }
+    let Ok(velocity) = balls.get(*ball) else {
+        return;
+    };
+    let linvel = Vec2::new(-velocity.linvel.x, velocity.linvel.y);
+    commands.spawn((
+        ball_bundle(&config, Vec2::ZERO, Velocity::linear(linvel)),
+        ExtraBall,
+    ));
+}
+
+/// After `reset_ball`, which has already queued the extra ball for serving, so the
+/// despawn comes last.
+fn remove_extra_balls(
+    mut commands: Commands,
+    mut events: EventReader<GameEvents>,
+    mutators: Res<ActiveMutators>,
+    extras: Query<Entity, With<ExtraBall>>,
+) {
+    let reset = events.read().last().is_some();
+    if !reset && mutators.has(Mutator::Multiball) {
+        return;
+    }
+    for extra in &extras {
+        commands.entity(extra).despawn_recursive();
+    }
+}
+
+/// A ball waiting to be served stays put.
+fn pull_balls_down(
+    time: Res<Time>,
+    mutators: Res<ActiveMutators>,
+    arena: Res<Arena>,
+    mut balls: Query<&mut Velocity, (With<Ball>, Without<Serving>)>,
+) {
+    if !mutators.has(Mutator::Gravity) {
+        return;
+    }
+    for mut velocity in &mut balls {
+        velocity.linvel.y -= mutators.gravity() * arena.scale * time.delta_seconds();
+    }
+}
+
+/// Gives new balls, or all of them when the mutators change, the size, weight and
+/// collisions of the ball type in play. The size goes through the assists, which can
+/// enlarge it further.
+fn shape_balls(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mutators: Res<ActiveMutators>,
+    all: Query<Entity, With<Ball>>,
+    added: Query<Entity, Added<Ball>>,
+    mut transforms: Query<&mut Transform, With<Ball>>,
+) {
+    let balls: Vec<Entity> = if mutators.is_changed() {
+        all.iter().collect()
+    } else {
+        added.iter().collect()
+    };
+    let ball_type = mutators.ball_type();
+    let scale = ball_scale(&settings, &mutators);
+    for ball in balls {
+        if let Ok(mut transform) = transforms.get_mut(ball) {
+            transform.scale = Vec3::new(scale, scale, 1.0);
+        }
+        commands.entity(ball).insert((
+            ColliderMassProperties::Density(ball_type.density()),
+            ball_type.collision_groups(),
+        ));
+    }
+}
+
+/// Hits recolour the ball, so a ghost ball's transparency is put back every frame.
+fn fade_ghosts(mutators: Res<ActiveMutators>, mut balls: Query<&mut Sprite, With<Ball>>) {
+    if mutators.ball_type() != BallType::Ghost {
+        return;
+    }
+    for mut sprite in &mut balls {
+        sprite.color.set_alpha(GHOST_ALPHA);
+    }
+}
+
+/// Turns a light ball's heading a little this way or that, keeping its speed.
+fn jitter_light_balls(
+    time: Res<Time>,
+    mutators: Res<ActiveMutators>,
+    mut rng: ResMut<GameRng>,
+    mut balls: Query<&mut Velocity, With<Ball>>,
+) {
+    if mutators.ball_type() != BallType::Light {
+        return;
+    }
+    let limit = LIGHT_JITTER * time.delta_seconds();
+    for mut velocity in &mut balls {
+        let turn = rng.gen_range(-1.0..=1.0) * limit;
+        velocity.linvel = Vec2::from_angle(turn).rotate(velocity.linvel);
+    }
+}
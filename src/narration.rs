@@ -0,0 +1,106 @@
+use bevy::{
+    a11y::{
+        AccessibilityNode,
+        accesskit::{Live, NodeBuilder, Role},
+    },
+    prelude::*,
+};
+
+use crate::{GameState, MatchOver, PointScored, Score, locale::Locale, score, toast::Toast};
+
+/// A line for screen readers, e.g. "Player 1 scores, 3 to 2".
+#[derive(Event)]
+pub struct Announcement(pub String);
+
+pub struct NarrationPlugin;
+
+impl Plugin for NarrationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Announcement>();
+        app.add_systems(Startup, spawn_live_region);
+        app.add_systems(OnEnter(GameState::Paused), announce_pause);
+        app.add_systems(OnExit(GameState::Paused), announce_resume);
+        app.add_systems(Update, (announce_toasts, announce_winner));
+        app.add_systems(PostUpdate, announce_points.after(score));
+        app.add_systems(Last, update_live_region);
+    }
+}
+
+/// Invisible polite live region whose name is replaced with each announcement.
+#[derive(Component)]
+struct LiveRegion;
+
+fn spawn_live_region(mut commands: Commands) {
+    let mut node = NodeBuilder::new(Role::Status);
+    node.set_live(Live::Polite);
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        AccessibilityNode(node),
+        LiveRegion,
+    ));
+}
+
+fn update_live_region(
+    mut announcements: EventReader<Announcement>,
+    mut regions: Query<&mut AccessibilityNode, With<LiveRegion>>,
+) {
+    let Some(Announcement(text)) = announcements.read().last() else {
+        return;
+    };
+    for mut region in &mut regions {
+        region.set_name(text.clone());
+    }
+}
+
+fn announce_pause(locale: Res<Locale>, mut announcements: EventWriter<Announcement>) {
+    announcements.send(Announcement(locale.text("announce-paused")));
+}
+
+fn announce_resume(locale: Res<Locale>, mut announcements: EventWriter<Announcement>) {
+    announcements.send(Announcement(locale.text("announce-resumed")));
+}
+
+fn announce_points(
+    mut points: EventReader<PointScored>,
+    score: Res<Score>,
+    locale: Res<Locale>,
+    mut announcements: EventWriter<Announcement>,
+) {
+    for point in points.read() {
+        let mine = score.0.get(&point.scorer).copied().unwrap_or(0);
+        let theirs = score.0.get(&point.conceder).copied().unwrap_or(0);
+        announcements.send(Announcement(locale.format(
+            "announce-point",
+            &[
+                ("player", locale.player(point.scorer).into()),
+                ("mine", mine.into()),
+                ("theirs", theirs.into()),
+            ],
+        )));
+    }
+}
+
+fn announce_winner(
+    mut events: EventReader<MatchOver>,
+    locale: Res<Locale>,
+    mut announcements: EventWriter<Announcement>,
+) {
+    for MatchOver(winner) in events.read() {
+        announcements.send(Announcement(locale.format(
+            "announce-match-won",
+            &[("player", locale.player(*winner).into())],
+        )));
+    }
+}
+
+fn announce_toasts(mut toasts: EventReader<Toast>, mut announcements: EventWriter<Announcement>) {
+    for Toast(message) in toasts.read() {
+        announcements.send(Announcement(message.clone()));
+    }
+}
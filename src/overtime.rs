@@ -0,0 +1,140 @@
+use bevy::{audio::AudioSink, prelude::*};
+
+use crate::{
+    GameState, MatchRules, Player, Score, locale::Locale, motion::MotionPreferences,
+    settings::Settings, theme::ThemeMusic, toast::Toast,
+};
+
+const TINT: Color = Color::srgba(0.8, 0.05, 0.05, 0.12);
+/// How much the tint swells and fades, on top of its usual alpha.
+const TINT_PULSE: f32 = 0.06;
+const MUSIC_SPEED: f32 = 1.25;
+
+/// Where a match stands. Only exists while playing.
+#[derive(SubStates, Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+#[source(GameState = GameState::Playing)]
+enum MatchPhase {
+    #[default]
+    Regular,
+    /// Tied one point short of the target: the next goal wins.
+    SuddenDeath,
+}
+
+/// The red wash over the court during sudden death.
+#[derive(Component)]
+struct OvertimeTint;
+
+/// When both players are one point from winning, the match goes to sudden death: the court
+/// turns red and the music speeds up until the deciding goal.
+pub struct OvertimePlugin;
+
+impl Plugin for OvertimePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_sub_state::<MatchPhase>();
+        app.enable_state_scoped_entities::<MatchPhase>();
+        app.add_systems(OnEnter(MatchPhase::SuddenDeath), spawn_tint);
+        app.add_systems(
+            Update,
+            (
+                track_phase.run_if(in_state(GameState::Playing)),
+                announce_sudden_death.run_if(resource_changed::<Score>),
+                pulse_tint.run_if(in_state(MatchPhase::SuddenDeath)),
+                speed_up_music,
+            ),
+        );
+    }
+}
+
+fn sudden_death(score: &Score, rules: &MatchRules) -> bool {
+    let points = |player| score.0.get(&player).copied().unwrap_or(0);
+    let p1 = points(Player::Player1);
+    rules.target_score > 1 && p1 == rules.target_score - 1 && points(Player::Player2) == p1
+}
+
+/// Worked out from the score, so the phase comes back after a pause or a replay.
+fn track_phase(
+    score: Res<Score>,
+    rules: Res<MatchRules>,
+    phase: Res<State<MatchPhase>>,
+    mut next: ResMut<NextState<MatchPhase>>,
+) {
+    let wanted = if sudden_death(&score, &rules) {
+        MatchPhase::SuddenDeath
+    } else {
+        MatchPhase::Regular
+    };
+    if *phase.get() != wanted {
+        next.set(wanted);
+    }
+}
+
+/// Only when the tying goal goes in, not every time play resumes.
+fn announce_sudden_death(
+    score: Res<Score>,
+    rules: Res<MatchRules>,
+    locale: Res<Locale>,
+    mut toasts: EventWriter<Toast>,
+) {
+    if sudden_death(&score, &rules) {
+        toasts.send(Toast(locale.text("toast-sudden-death")));
+    }
+}
+
+fn spawn_tint(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..Default::default()
+            },
+            background_color: TINT.into(),
+            // Under the rest of the UI.
+            z_index: ZIndex::Global(-1),
+            ..Default::default()
+        },
+        OvertimeTint,
+        StateScoped(MatchPhase::SuddenDeath),
+    ));
+}
+
+/// With reduced motion the tint stays put. It would colour a chroma-key background, so
+/// that hides it; the toast still says it's sudden death.
+fn pulse_tint(
+    time: Res<Time>,
+    motion: Res<MotionPreferences>,
+    settings: Res<Settings>,
+    mut tints: Query<&mut BackgroundColor, With<OvertimeTint>>,
+) {
+    if settings.chroma_key.colour().is_some() {
+        for mut tint in &mut tints {
+            tint.0 = Color::NONE;
+        }
+        return;
+    }
+    let pulse = if motion.reduced {
+        0.0
+    } else {
+        (time.elapsed_seconds() * 3.0).sin() * TINT_PULSE / 2.0
+    };
+    for mut tint in &mut tints {
+        tint.0 = TINT.with_alpha(TINT.alpha() + pulse);
+    }
+}
+
+/// Music starts again at normal speed when sudden death ends, or when play stops.
+fn speed_up_music(
+    phase: Option<Res<State<MatchPhase>>>,
+    music: Query<&AudioSink, With<ThemeMusic>>,
+) {
+    let speed = match phase.as_deref().map(State::get) {
+        Some(MatchPhase::SuddenDeath) => MUSIC_SPEED,
+        _ => 1.0,
+    };
+    for sink in &music {
+        if sink.speed() != speed {
+            sink.set_speed(speed);
+        }
+    }
+}
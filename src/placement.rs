@@ -0,0 +1,149 @@
+use bevy::{
+    app::AppExit,
+    prelude::*,
+    window::{MonitorSelection, PrimaryWindow, WindowMode, WindowPosition},
+    winit::WinitWindows,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::storage::Storage;
+
+const PLACEMENT_KEY: &str = "window";
+/// Anything smaller than this is treated as a mistake and not restored.
+const MIN_SIZE: Vec2 = Vec2::new(320.0, 180.0);
+
+/// Where the window was last time, in windowed mode. Position is in physical pixels.
+#[derive(Resource, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct WindowPlacement {
+    width: f32,
+    height: f32,
+    position: Option<IVec2>,
+    monitor: Option<String>,
+}
+
+/// The saved placement until it has been applied. Moving the window needs the monitor
+/// list, which only exists once the OS window has been created.
+#[derive(Resource, Default)]
+struct PendingPlacement(Option<WindowPlacement>);
+
+/// Restores the window's size, position and monitor between runs. Desktop only.
+pub struct PlacementPlugin;
+
+impl Plugin for PlacementPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WindowPlacement>();
+        app.init_resource::<PendingPlacement>();
+        app.add_systems(Startup, restore_size);
+        app.add_systems(
+            Update,
+            (
+                restore_position.run_if(|pending: Res<PendingPlacement>| pending.0.is_some()),
+                track_placement,
+            )
+                .chain(),
+        );
+        app.add_systems(Last, save_placement);
+    }
+}
+
+fn restore_size(
+    storage: Res<Storage>,
+    mut pending: ResMut<PendingPlacement>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Some(placement) = storage.load::<WindowPlacement>(PLACEMENT_KEY) else {
+        return;
+    };
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    if placement.width >= MIN_SIZE.x && placement.height >= MIN_SIZE.y {
+        window.resolution.set(placement.width, placement.height);
+    }
+    pending.0 = Some(placement);
+}
+
+/// Puts the window back where it was if that spot is still on a connected monitor.
+/// Otherwise it is centred on the same monitor, or on the primary one if that is gone too.
+fn restore_position(
+    winit_windows: NonSend<WinitWindows>,
+    mut pending: ResMut<PendingPlacement>,
+    mut windows: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+) {
+    let Ok((entity, mut window)) = windows.get_single_mut() else {
+        return;
+    };
+    let Some(winit_window) = winit_windows.get_window(entity) else {
+        return;
+    };
+    let Some(placement) = pending.0.take() else {
+        return;
+    };
+
+    let monitors: Vec<(Option<String>, IRect)> = winit_window
+        .available_monitors()
+        .map(|monitor| {
+            let origin = IVec2::new(monitor.position().x, monitor.position().y);
+            let size = IVec2::new(monitor.size().width as i32, monitor.size().height as i32);
+            (monitor.name(), IRect::from_corners(origin, origin + size))
+        })
+        .collect();
+
+    let on_screen = placement
+        .position
+        .filter(|position| monitors.iter().any(|(_, rect)| rect.contains(*position)));
+    window.position = match on_screen {
+        Some(position) => WindowPosition::At(position),
+        None => {
+            let same_monitor = monitors
+                .iter()
+                .position(|(name, _)| name.is_some() && *name == placement.monitor);
+            WindowPosition::Centered(
+                same_monitor.map_or(MonitorSelection::Primary, MonitorSelection::Index),
+            )
+        }
+    };
+}
+
+fn track_placement(
+    winit_windows: NonSend<WinitWindows>,
+    windows: Query<(Entity, Ref<Window>), With<PrimaryWindow>>,
+    mut placement: ResMut<WindowPlacement>,
+) {
+    let Ok((entity, window)) = windows.get_single() else {
+        return;
+    };
+    if !window.is_changed() {
+        return;
+    }
+    // Fullscreen sizes say nothing about the window the player wants back.
+    if window.mode != WindowMode::Windowed {
+        return;
+    }
+    let position = match window.position {
+        WindowPosition::At(position) => Some(position),
+        _ => placement.position,
+    };
+    let monitor = winit_windows
+        .get_window(entity)
+        .and_then(|w| w.current_monitor())
+        .and_then(|m| m.name())
+        .or_else(|| placement.monitor.clone());
+
+    placement.set_if_neq(WindowPlacement {
+        width: window.resolution.width(),
+        height: window.resolution.height(),
+        position,
+        monitor,
+    });
+}
+
+fn save_placement(
+    mut exits: EventReader<AppExit>,
+    storage: Res<Storage>,
+    placement: Res<WindowPlacement>,
+) {
+    if exits.read().last().is_some() && placement.width > 0.0 {
+        storage.save(PLACEMENT_KEY, &*placement);
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Recycles short-lived entities marked with `M`, such as hit cues, so that effects firing
+/// many times a second don't spawn and despawn an entity each time. A finished entity is
+/// hidden and loses its `M` marker, so queries for `M` skip it, and the next effect takes it
+/// over.
+///
+/// At most `capacity` entities are in use at once. Past that, [`Pool::take`] gives nothing
+/// and the effect is skipped, which is the right trade for decoration.
+#[derive(Resource)]
+pub struct Pool<M: Component> {
+    parked: Vec<Entity>,
+    in_use: usize,
+    capacity: usize,
+    marker: PhantomData<M>,
+}
+
+impl<M: Component> Pool<M> {
+    pub fn new(capacity: usize) -> Self {
+        Pool {
+            parked: Vec::new(),
+            in_use: 0,
+            capacity,
+            marker: PhantomData,
+        }
+    }
+
+    /// Puts `bundle`, which should include an `M`, on a parked entity, or on a new one.
+    /// Gives `None` when the pool is full.
+    pub fn take(&mut self, commands: &mut Commands, bundle: impl Bundle) -> Option<Entity> {
+        if self.in_use >= self.capacity {
+            return None;
+        }
+        self.in_use += 1;
+        // Parked entities can go with the rest of the court, when it is rebuilt.
+        while let Some(entity) = self.parked.pop() {
+            if let Some(mut parked) = commands.get_entity(entity) {
+                parked.insert((bundle, Visibility::Inherited));
+                return Some(entity);
+            }
+        }
+        Some(commands.spawn(bundle).id())
+    }
+
+    /// Hides `entity` and keeps it for the next [`Pool::take`].
+    pub fn give_back(&mut self, commands: &mut Commands, entity: Entity) {
+        commands
+            .entity(entity)
+            .remove::<M>()
+            .insert(Visibility::Hidden);
+        self.parked.push(entity);
+        self.in_use = self.in_use.saturating_sub(1);
+    }
+}
@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use std::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_4, PI};
+
+use crate::{
+    Ball,
+    arena::Arena,
+    layout::{ArenaLayout, GRID_CELL, cell_centre},
+    motion::MotionPreferences,
+};
+
+const PAD_SIZE: f32 = GRID_CELL * 0.8;
+/// The two ends of each pair, so players can tell which pads belong together.
+const PAD_COLOURS: [Color; 2] = [
+    Color::srgba(1.0, 0.55, 0.1, 0.6),
+    Color::srgba(0.2, 0.6, 1.0, 0.6),
+];
+/// How long a ball that just went through can't use a pad again. Without it a ball would
+/// come out inside the other pad and go straight back.
+const COOLDOWN_SECONDS: f32 = 0.3;
+const FLASH_SECONDS: f32 = 0.35;
+
+/// Balls free to go through a portal.
+type ReadyBall = (With<Ball>, Without<WarpCooldown>);
+
+/// One end of a portal. It is a sensor, so the ball passes over it instead of bouncing.
+#[derive(Component)]
+struct Pad {
+    exit: Vec2,
+    mirror: bool,
+}
+
+/// On a ball that has just gone through a portal.
+#[derive(Component)]
+struct WarpCooldown(Timer);
+
+/// The ring that flashes at both ends when a ball warps.
+#[derive(Component)]
+struct WarpFlash(Timer);
+
+/// Teleporter pads from the arena file.
+pub struct PortalsPlugin;
+
+impl Plugin for PortalsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                build_pads
+                    .run_if(resource_changed::<ArenaLayout>.or_else(resource_changed::<Arena>)),
+                (cool_down, warp_balls).chain(),
+                fade_flashes,
+            ),
+        );
+    }
+}
+
+fn build_pads(mut commands: Commands, layout: Res<ArenaLayout>, pads: Query<Entity, With<Pad>>) {
+    for pad in &pads {
+        commands.entity(pad).despawn();
+    }
+    for portal in &layout.portals {
+        let (a, b) = (cell_centre(portal.a), cell_centre(portal.b));
+        for ((position, exit), colour) in [(a, b), (b, a)].into_iter().zip(PAD_COLOURS) {
+            commands.spawn((
+                SpriteBundle {
+                    transform: Transform::from_translation(position.extend(-0.6))
+                        .with_rotation(Quat::from_rotation_z(FRAC_PI_4)),
+                    sprite: Sprite {
+                        color: colour,
+                        custom_size: Some(Vec2::splat(PAD_SIZE * FRAC_1_SQRT_2)),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                RigidBody::Fixed,
+                Collider::ball(PAD_SIZE / 2.0),
+                Sensor,
+                Pad {
+                    exit,
+                    mirror: portal.mirror,
+                },
+            ));
+        }
+    }
+}
+
+/// The cooldown runs out once its time is up and the ball has left the pad it came out of.
+fn cool_down(
+    mut commands: Commands,
+    time: Res<Time>,
+    pads: Query<(), With<Pad>>,
+    mut balls: Query<(Entity, &CollidingEntities, &mut WarpCooldown)>,
+) {
+    for (ball, hits, mut cooldown) in &mut balls {
+        let on_pad = hits.iter().any(|hit| pads.contains(hit));
+        if cooldown.0.tick(time.delta()).finished() && !on_pad {
+            commands.entity(ball).remove::<WarpCooldown>();
+        }
+    }
+}
+
+fn warp_balls(
+    mut commands: Commands,
+    pads: Query<&Pad>,
+    mut balls: Query<(Entity, &CollidingEntities, &mut Transform, &mut Velocity), ReadyBall>,
+) {
+    for (ball, hits, mut transform, mut velocity) in &mut balls {
+        let Some(pad) = hits.iter().find_map(|hit| pads.get(hit).ok()) else {
+            continue;
+        };
+        let entry = transform.translation.truncate();
+        transform.translation = pad.exit.extend(transform.translation.z);
+        if pad.mirror {
+            velocity.linvel.x = -velocity.linvel.x;
+        }
+        commands
+            .entity(ball)
+            .insert(WarpCooldown(Timer::from_seconds(
+                COOLDOWN_SECONDS,
+                TimerMode::Once,
+            )));
+        for position in [entry, pad.exit] {
+            commands.spawn((
+                SpriteBundle {
+                    transform: Transform::from_translation(position.extend(2.0)),
+                    sprite: Sprite {
+                        color: Color::srgba(0.8, 0.6, 1.0, 0.8),
+                        custom_size: Some(Vec2::splat(PAD_SIZE)),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                WarpFlash(Timer::from_seconds(FLASH_SECONDS, TimerMode::Once)),
+            ));
+        }
+    }
+}
+
+/// Flashes spin open and fade. With reduced motion they only fade.
+fn fade_flashes(
+    mut commands: Commands,
+    time: Res<Time>,
+    motion: Res<MotionPreferences>,
+    mut flashes: Query<(Entity, &mut WarpFlash, &mut Transform, &mut Sprite)>,
+) {
+    for (entity, mut flash, mut transform, mut sprite) in &mut flashes {
+        if flash.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let progress = flash.0.fraction();
+        sprite.color.set_alpha(0.8 * (1.0 - progress));
+        if !motion.reduced {
+            transform.scale = Vec3::splat(1.0 + progress);
+            transform.rotation = Quat::from_rotation_z(progress * PI);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use bevy::{prelude::*, utils::SystemTime};
+use discord_rich_presence::{
+    DiscordIpc, DiscordIpcClient,
+    activity::{Activity, Timestamps},
+};
+
+use crate::{GameState, Player, Score, ai::GameMode, locale::Locale};
+
+/// The Discord application the activity is shown under, set when building.
+const APPLICATION_ID: Option<&str> = option_env!("DISCORD_APPLICATION_ID");
+/// Discord may be started after the game, so connecting is tried again this often.
+const RETRY_SECONDS: f32 = 30.0;
+
+#[derive(Resource)]
+struct Presence {
+    client: DiscordIpcClient,
+    connected: bool,
+    retry: Timer,
+    /// When the game started, shown by Discord as time elapsed.
+    started: i64,
+    /// Details and state last sent, so only changes are.
+    shown: Option<(String, String)>,
+}
+
+/// With the `discord` feature, shows what is going on in the game, such as "Against the
+/// computer" and "In match, 7–5", as Discord Rich Presence. The application id comes from
+/// `DISCORD_APPLICATION_ID` when building; without it, or without Discord running, the game
+/// carries on as usual.
+pub struct PresencePlugin;
+
+impl Plugin for PresencePlugin {
+    fn build(&self, app: &mut App) {
+        let Some(id) = APPLICATION_ID else {
+            info!("built without DISCORD_APPLICATION_ID, so Discord Rich Presence is off");
+            return;
+        };
+        let mut retry = Timer::from_seconds(RETRY_SECONDS, TimerMode::Repeating);
+        // Try on the first frame.
+        retry.set_elapsed(retry.duration());
+        app.insert_resource(Presence {
+            client: DiscordIpcClient::new(id),
+            connected: false,
+            retry,
+            started: unix_seconds(),
+            shown: None,
+        });
+        app.add_systems(Last, publish);
+    }
+}
+
+fn details(mode: GameMode, locale: &Locale) -> String {
+    locale.text(match mode {
+        GameMode::OnePlayer => "presence-1p",
+        GameMode::TwoPlayer => "presence-2p",
+        GameMode::AiVsAi => "presence-ai",
+        GameMode::Survival => "presence-survival",
+        GameMode::Coop => "presence-coop",
+    })
+}
+
+/// Survival has no score worth showing; its only points are misses.
+fn status(state: GameState, mode: GameMode, score: &Score, locale: &Locale) -> String {
+    let id = match state {
+        GameState::Playing => "presence-playing",
+        GameState::Paused => "presence-paused",
+        GameState::Replay => "presence-replay",
+        GameState::Intermission => return locale.text("presence-intermission"),
+        GameState::Loading | GameState::Splash => return locale.text("presence-loading"),
+    };
+    if mode == GameMode::Survival {
+        return locale.text(id);
+    }
+    let points = |player| score.0.get(&player).copied().unwrap_or(0);
+    let score = format!("{}–{}", points(Player::Player1), points(Player::Player2));
+    locale.format(&format!("{id}-score"), &[("score", score.into())])
+}
+
+/// Sends the activity whenever it changes. Any failure is taken as Discord having gone, and
+/// connecting starts over.
+fn publish(
+    time: Res<Time<Real>>,
+    mode: Res<GameMode>,
+    state: Res<State<GameState>>,
+    score: Res<Score>,
+    locale: Res<Locale>,
+    mut presence: ResMut<Presence>,
+) {
+    // Nothing else looks at it, so there's no need to flag it changed every frame.
+    let presence = presence.bypass_change_detection();
+    if !presence.connected {
+        if !presence.retry.tick(time.delta()).just_finished() {
+            return;
+        }
+        if presence.client.connect().is_err() {
+            return;
+        }
+        presence.connected = true;
+        presence.shown = None;
+    }
+
+    let shown = (
+        details(*mode, &locale),
+        status(*state.get(), *mode, &score, &locale),
+    );
+    if presence.shown.as_ref() == Some(&shown) {
+        return;
+    }
+    let activity = Activity::new()
+        .details(shown.0.as_str())
+        .state(shown.1.as_str())
+        .timestamps(Timestamps::new().start(presence.started));
+    if let Err(err) = presence.client.set_activity(activity) {
+        debug!("lost Discord: {err}");
+        let _ = presence.client.close();
+        presence.connected = false;
+        return;
+    }
+    presence.shown = Some(shown);
+}
+
+fn unix_seconds() -> i64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    MatchOver, Player, Score, ai::GameMode, check_winner, locale::Locale, stats::MatchStats,
+    storage::Storage, toast::Toast,
+};
+
+const PROFILE_KEY: &str = "profile";
+
+/// XP for playing a match to the end, and on top for winning it.
+const XP_FINISHED: u32 = 50;
+const XP_WON: u32 = 50;
+const XP_PER_POINT: u32 = 5;
+const XP_PER_HIT: u32 = 1;
+const XP_PER_RALLY_HIT: u32 = 2;
+
+/// XP needed to go from level `n` to `n + 1` is `n` times this.
+const XP_PER_LEVEL_STEP: u32 = 100;
+
+/// The local player's progress. The profile belongs to whoever plays as Player 1.
+#[derive(Resource, Default, Serialize, Deserialize)]
+pub struct Profile {
+    pub xp: u32,
+}
+
+impl Profile {
+    /// Starts at 1.
+    pub fn level(&self) -> u32 {
+        let mut level = 1;
+        let mut needed = XP_PER_LEVEL_STEP;
+        let mut left = self.xp;
+        while left >= needed {
+            left -= needed;
+            level += 1;
+            needed = level * XP_PER_LEVEL_STEP;
+        }
+        level
+    }
+
+    /// XP into the current level, and the XP the level takes.
+    fn level_progress(&self) -> (u32, u32) {
+        let level = self.level();
+        let spent: u32 = (1..level).map(|l| l * XP_PER_LEVEL_STEP).sum();
+        (self.xp - spent, level * XP_PER_LEVEL_STEP)
+    }
+}
+
+pub struct ProgressionPlugin;
+
+impl Plugin for ProgressionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Profile>();
+        app.add_systems(PreStartup, load_profile);
+        app.add_systems(PostUpdate, award_xp.after(check_winner));
+    }
+}
+
+fn load_profile(storage: Res<Storage>, mut profile: ResMut<Profile>) {
+    if let Some(saved) = storage.load(PROFILE_KEY) {
+        *profile = saved;
+    }
+}
+
+/// Runs before the match stats and score are cleared for the next match. Matches the
+/// computer plays against itself earn nothing.
+#[allow(clippy::too_many_arguments)]
+fn award_xp(
+    mut events: EventReader<MatchOver>,
+    mode: Res<GameMode>,
+    score: Res<Score>,
+    stats: Res<MatchStats>,
+    storage: Res<Storage>,
+    locale: Res<Locale>,
+    mut profile: ResMut<Profile>,
+    mut toasts: EventWriter<Toast>,
+) {
+    let Some(MatchOver(winner)) = events.read().last() else {
+        return;
+    };
+    if *mode == GameMode::AiVsAi {
+        return;
+    }
+    let points = score.0.get(&Player::Player1).copied().unwrap_or(0).max(0) as u32;
+    let hits = stats
+        .paddle_hits
+        .get(&Player::Player1)
+        .copied()
+        .unwrap_or(0);
+    let mut earned = XP_FINISHED
+        + points * XP_PER_POINT
+        + hits * XP_PER_HIT
+        + stats.longest_rally * XP_PER_RALLY_HIT;
+    if *winner == Player::Player1 {
+        earned += XP_WON;
+    }
+
+    let old_level = profile.level();
+    profile.xp += earned;
+    storage.save(PROFILE_KEY, &*profile);
+
+    let (into_level, level_size) = profile.level_progress();
+    toasts.send(Toast(locale.format(
+        "toast-xp-earned",
+        &[
+            ("xp", earned.into()),
+            ("level", profile.level().into()),
+            ("progress", into_level.into()),
+            ("needed", level_size.into()),
+        ],
+    )));
+    if profile.level() > old_level {
+        toasts.send(Toast(
+            locale.format("toast-level-up", &[("level", profile.level().into())]),
+        ));
+    }
+}
@@ -0,0 +1,196 @@
+use bevy::{color::palettes::css::GOLD, prelude::*};
+
+use crate::{
+    MatchOver, PointScored,
+    counter::{RollingCounter, spawn_counter},
+    fonts::FontStack,
+    hits::BallHitPaddle,
+    hud::HudWidget,
+    locale::{Locale, LocalizedText},
+    motion::MotionPreferences,
+    toast::Toast,
+};
+
+/// Rally lengths worth a celebration.
+const MILESTONES: [u32; 3] = [10, 25, 50];
+const PULSE_SECONDS: f32 = 0.25;
+/// How much bigger the counter gets on a hit, and on a milestone.
+const HIT_PULSE: f32 = 0.15;
+const MILESTONE_PULSE: f32 = 0.5;
+/// How long the label stays gold after a milestone.
+const MILESTONE_SECONDS: f32 = 2.0;
+
+/// The rally counter under the scoreboard: hits since the last point.
+#[derive(Component)]
+struct RallyHud {
+    hits: u32,
+    pulse: Timer,
+    /// How big the current pulse is.
+    strength: f32,
+    milestone: Timer,
+}
+
+#[derive(Component)]
+struct RallyLabel;
+
+/// Counts paddle hits since the last point, under the scoreboard. The count pulses on
+/// every hit and makes more of a fuss at 10, 25 and 50.
+pub struct RallyPlugin;
+
+impl Plugin for RallyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_rally_hud);
+        app.add_systems(Update, (count_rally, animate_rally).chain());
+    }
+}
+
+fn spawn_rally_hud(mut commands: Commands, fonts: Res<FontStack>) {
+    let mut pulse = Timer::from_seconds(PULSE_SECONDS, TimerMode::Once);
+    pulse.tick(pulse.duration());
+    let mut milestone = Timer::from_seconds(MILESTONE_SECONDS, TimerMode::Once);
+    milestone.tick(milestone.duration());
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(21.0),
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            HudWidget,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    visibility: Visibility::Hidden,
+                    ..Default::default()
+                },
+                RallyHud {
+                    hits: 0,
+                    pulse,
+                    strength: HIT_PULSE,
+                    milestone,
+                },
+            ))
+            .with_children(|p| {
+                p.spawn((
+                    fonts.text_bundle("", 20.),
+                    LocalizedText("hud-rally"),
+                    RallyLabel,
+                ));
+                spawn_counter(
+                    p,
+                    0,
+                    TextStyle {
+                        font_size: 40.,
+                        ..Default::default()
+                    },
+                    (),
+                );
+            });
+        });
+}
+
+/// A point or a new match ends the rally.
+fn count_rally(
+    mut paddle_hits: EventReader<BallHitPaddle>,
+    mut points: EventReader<PointScored>,
+    mut match_over: EventReader<MatchOver>,
+    locale: Res<Locale>,
+    mut toasts: EventWriter<Toast>,
+    mut huds: Query<(&mut RallyHud, &Children)>,
+    mut counters: Query<&mut RollingCounter>,
+) {
+    let Ok((mut hud, children)) = huds.get_single_mut() else {
+        return;
+    };
+    let hits = paddle_hits.read().count() as u32;
+    let point_scored = points.read().last().is_some();
+    let new_match = match_over.read().last().is_some();
+
+    let before = hud.hits;
+    if point_scored || new_match {
+        hud.hits = 0;
+    }
+    if hits > 0 {
+        let from = hud.hits;
+        hud.hits += hits;
+        let milestone = MILESTONES
+            .into_iter()
+            .find(|milestone| from < *milestone && *milestone <= hud.hits);
+        hud.strength = if milestone.is_some() {
+            MILESTONE_PULSE
+        } else {
+            HIT_PULSE
+        };
+        hud.pulse.reset();
+        if let Some(milestone) = milestone {
+            hud.milestone.reset();
+            toasts.send(Toast(
+                locale.format("toast-rally-milestone", &[("hits", milestone.into())]),
+            ));
+        }
+    }
+    if hud.hits != before {
+        let mut counter = counters.iter_many_mut(children);
+        while let Some(mut counter) = counter.fetch_next() {
+            counter.set(hud.hits as i32);
+        }
+    }
+}
+
+/// The counter only shows once there is a rally to speak of. With reduced motion it does
+/// not pulse, but the label still turns gold.
+fn animate_rally(
+    time: Res<Time>,
+    motion: Res<MotionPreferences>,
+    mut huds: Query<(&mut RallyHud, &mut Transform, &mut Visibility)>,
+    mut labels: Query<&mut Text, With<RallyLabel>>,
+) {
+    let Ok((mut hud, mut transform, mut visibility)) = huds.get_single_mut() else {
+        return;
+    };
+    visibility.set_if_neq(if hud.hits > 1 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    });
+
+    let pulse = 1.0 - hud.pulse.tick(time.delta()).fraction();
+    let scale = if motion.reduced {
+        1.0
+    } else {
+        1.0 + hud.strength * pulse
+    };
+    if transform.scale != Vec3::splat(scale) {
+        transform.scale = Vec3::splat(scale);
+    }
+
+    let celebrating = !hud.milestone.tick(time.delta()).finished();
+    let colour = if celebrating {
+        GOLD.into()
+    } else {
+        Color::WHITE
+    };
+    for mut label in &mut labels {
+        if label
+            .sections
+            .iter()
+            .any(|section| section.style.color != colour)
+        {
+            for section in &mut label.sections {
+                section.style.color = colour;
+            }
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use bevy::{
+    app::AppExit,
+    input::InputSystem,
+    prelude::*,
+    time::{Real, TimeSystem, TimeUpdateStrategy},
+    utils::Duration,
+};
+use bevy_rapier2d::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    Ball, MatchRules, Paddle, Score,
+    ai::GameMode,
+    mirror::mirror_controls,
+    rng::GameRng,
+    settings::{Settings, load_settings},
+    storage::{Storage, UserFiles},
+};
+
+/// A checksum of the court is kept every this many frames.
+const CHECKSUM_FRAMES: u32 = 60;
+
+/// A session as what went into it rather than what came out: the seed, the rules and
+/// settings it started with, and the keys held on every frame. Playing it back runs the
+/// whole game again, so it is a fraction of the size of a recording of positions.
+///
+/// Only the keyboard is recorded: touches, the mouse and the window losing focus are not.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct InputRecording {
+    pub seed: u64,
+    pub mode: GameMode,
+    pub target_score: i32,
+    settings: Settings,
+    frames: Vec<RecordedFrame>,
+    /// Taken while recording and checked while playing back, to find where a re-run
+    /// stops matching the original.
+    checksums: Vec<Checksum>,
+}
+
+impl InputRecording {
+    pub fn load(files: &Storage, path: &Path) -> Result<Self, String> {
+        files
+            .read_text(&path.to_string_lossy())
+            .map_err(|err| err.to_string())
+            .and_then(|json| json.ok_or_else(|| "no such file".to_string()))
+            .and_then(|json| serde_json::from_str(&json).map_err(|err| err.to_string()))
+    }
+
+    pub fn frames(&self) -> u32 {
+        self.frames.len() as u32
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct RecordedFrame {
+    /// How long the frame took, in seconds.
+    dt: f32,
+    /// Every key held from this frame on. Only written when it changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    keys: Option<Vec<KeyCode>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+struct Checksum {
+    frame: u32,
+    value: u64,
+}
+
+/// Folds the score and the position of every ball and paddle into one number. FNV-1a,
+/// so it comes out the same on every machine and build.
+pub(crate) fn court_checksum<'a>(
+    score: &Score,
+    tracked: impl Iterator<Item = (Entity, &'a Transform)>,
+) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut add = |bits: u32| {
+        for byte in bits.to_le_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    };
+    let mut points: Vec<_> = score.0.iter().map(|(p, s)| (*p as u32, *s)).collect();
+    points.sort_unstable();
+    for (player, points) in points {
+        add(player);
+        add(points as u32);
+    }
+    let mut tracked: Vec<_> = tracked.collect();
+    tracked.sort_unstable_by_key(|(entity, _)| *entity);
+    for (_, transform) in tracked {
+        add(transform.translation.x.to_bits());
+        add(transform.translation.y.to_bits());
+    }
+    hash
+}
+
+type Tracked = Or<(With<Ball>, With<Paddle>)>;
+
+#[derive(Resource)]
+struct Recorder {
+    path: PathBuf,
+    recording: InputRecording,
+    held: Vec<KeyCode>,
+}
+
+/// `--record-inputs`: writes the session to `path` as an [`InputRecording`] when the game
+/// closes.
+pub struct RecordInputsPlugin {
+    pub path: PathBuf,
+}
+
+impl Plugin for RecordInputsPlugin {
+    fn build(&self, app: &mut App) {
+        let path = self.path.clone();
+        app.add_systems(
+            Startup,
+            move |mut commands: Commands,
+                  rng: Res<GameRng>,
+                  mode: Res<GameMode>,
+                  rules: Res<MatchRules>,
+                  settings: Res<Settings>| {
+                commands.insert_resource(Recorder {
+                    path: path.clone(),
+                    recording: InputRecording {
+                        seed: rng.seed(),
+                        mode: *mode,
+                        target_score: rules.target_score,
+                        settings: settings.clone(),
+                        frames: Vec::new(),
+                        checksums: Vec::new(),
+                    },
+                    held: Vec::new(),
+                });
+            },
+        );
+        // The keys as pressed, before the mirror mutator swaps any.
+        app.add_systems(
+            PreUpdate,
+            record_inputs.after(InputSystem).before(mirror_controls),
+        );
+        app.add_systems(Last, (record_checksum, save_recording).chain());
+    }
+}
+
+fn record_inputs(
+    time: Res<Time<Real>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut recorder: ResMut<Recorder>,
+) {
+    let held: Vec<KeyCode> = keys.get_pressed().copied().collect();
+    let changed =
+        held.len() != recorder.held.len() || held.iter().any(|key| !recorder.held.contains(key));
+    recorder.recording.frames.push(RecordedFrame {
+        dt: time.delta_seconds(),
+        keys: changed.then(|| held.clone()),
+    });
+    recorder.held = held;
+}
+
+fn record_checksum(
+    score: Res<Score>,
+    tracked: Query<(Entity, &Transform), Tracked>,
+    mut recorder: ResMut<Recorder>,
+) {
+    let frame = recorder.recording.frames();
+    if frame.is_multiple_of(CHECKSUM_FRAMES) {
+        let value = court_checksum(&score, tracked.iter());
+        recorder.recording.checksums.push(Checksum { frame, value });
+    }
+}
+
+fn save_recording(mut exits: EventReader<AppExit>, recorder: Res<Recorder>, files: Res<UserFiles>) {
+    if exits.read().last().is_none() {
+        return;
+    }
+    recorder.save(&files);
+}
+
+/// Headless runs stop without an [`AppExit`], so they save any recording through this once
+/// their last frame is done.
+pub fn finish_recording(world: &World) {
+    if let Some(recorder) = world.get_resource::<Recorder>() {
+        recorder.save(world.resource::<UserFiles>());
+    }
+}
+
+impl Recorder {
+    fn save(&self, files: &Storage) {
+        let written = serde_json::to_string(&self.recording)
+            .map_err(|err| err.to_string())
+            .and_then(|json| {
+                files
+                    .write_text(&self.path.to_string_lossy(), &json)
+                    .map_err(|err| err.to_string())
+            });
+        match written {
+            Ok(()) => info!(
+                "recorded {} frames to {}",
+                self.recording.frames(),
+                self.path.display()
+            ),
+            Err(err) => warn!("could not save recording {}: {err}", self.path.display()),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct Playback {
+    recording: InputRecording,
+    /// The next frame to play.
+    frame: usize,
+    /// The keyboard as the recording has it, which replaces the real one.
+    keys: ButtonInput<KeyCode>,
+    /// Set at the first mismatch or the end of the recording, whichever comes first.
+    checked: bool,
+}
+
+/// `--play-inputs`: runs a recorded session again from its seed and settings, feeding it
+/// the recorded frame lengths and keys. It warns at the first checksum that does not match,
+/// which narrows a desync down to a second of play. Once the recording runs out the
+/// keyboard works again.
+///
+/// The seed, mode and target score are taken from the recording by `main`.
+pub struct PlayInputsPlugin {
+    pub recording: InputRecording,
+}
+
+impl Plugin for PlayInputsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Playback {
+            recording: self.recording.clone(),
+            frame: 0,
+            keys: ButtonInput::default(),
+            checked: false,
+        });
+        app.add_systems(PreStartup, use_recorded_settings.after(load_settings));
+        app.add_systems(First, play_frame_time.before(TimeSystem));
+        app.add_systems(
+            PreUpdate,
+            play_inputs.after(InputSystem).before(mirror_controls),
+        );
+        app.add_systems(Last, check_checksum);
+    }
+}
+
+/// Physics steps with the recorded frame lengths, even in a headless run, which would
+/// otherwise step it at a fixed rate.
+fn use_recorded_settings(
+    mut commands: Commands,
+    playback: Res<Playback>,
+    mut settings: ResMut<Settings>,
+) {
+    *settings = playback.recording.settings.clone();
+    commands.insert_resource(TimestepMode::default());
+}
+
+fn play_frame_time(playback: Res<Playback>, mut strategy: ResMut<TimeUpdateStrategy>) {
+    if let Some(frame) = playback.recording.frames.get(playback.frame) {
+        *strategy = TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(frame.dt));
+    }
+}
+
+fn play_inputs(mut playback: ResMut<Playback>, mut keys: ResMut<ButtonInput<KeyCode>>) {
+    let playback = &mut *playback;
+    let Some(frame) = playback.recording.frames.get(playback.frame) else {
+        return;
+    };
+    playback.keys.clear();
+    if let Some(held) = &frame.keys {
+        let released: Vec<KeyCode> = playback
+            .keys
+            .get_pressed()
+            .filter(|key| !held.contains(key))
+            .copied()
+            .collect();
+        for key in released {
+            playback.keys.release(key);
+        }
+        for key in held {
+            playback.keys.press(*key);
+        }
+    }
+    *keys = playback.keys.clone();
+    playback.frame += 1;
+}
+
+fn check_checksum(
+    score: Res<Score>,
+    tracked: Query<(Entity, &Transform), Tracked>,
+    mut playback: ResMut<Playback>,
+) {
+    if playback.checked {
+        return;
+    }
+    let frame = playback.frame as u32;
+    let matching = playback
+        .recording
+        .checksums
+        .iter()
+        .find(|checksum| checksum.frame == frame)
+        .is_none_or(|expected| court_checksum(&score, tracked.iter()) == expected.value);
+    if !matching {
+        warn!(
+            "playback stopped matching the recording in the {CHECKSUM_FRAMES} frames before frame {frame}"
+        );
+        playback.checked = true;
+        return;
+    }
+    if frame == playback.recording.frames() {
+        info!("played back {frame} frames, matching the recording throughout");
+        playback.checked = true;
+    }
+}
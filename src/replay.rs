@@ -0,0 +1,421 @@
+use bevy::{
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+        view::RenderLayers,
+    },
+    utils::HashMap,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use crate::{
+    Ball, GameState, Paddle, PointScored,
+    arena::Arena,
+    fonts::FontStack,
+    locale::{Locale, LocalizedText},
+    menu::{AppSettingsExt, SettingRow},
+    settings::Settings,
+};
+
+/// How much play is kept, and replayed, in seconds.
+const REPLAY_SECONDS: f32 = 2.0;
+const REPLAY_SPEED: f32 = 0.5;
+/// Size of the corner replay window, in logical pixels.
+const CORNER_SIZE: UVec2 = UVec2::new(320, 180);
+/// Only the corner replay camera sees this layer.
+const CORNER_LAYER: usize = 1;
+
+/// How goals are replayed.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum GoalReplays {
+    /// Play stops while the replay fills the court.
+    #[default]
+    FullScreen,
+    /// Play carries on while a small window in the corner shows the replay.
+    Corner,
+    Off,
+}
+
+impl GoalReplays {
+    const ALL: [GoalReplays; 3] = [
+        GoalReplays::FullScreen,
+        GoalReplays::Corner,
+        GoalReplays::Off,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            GoalReplays::FullScreen => "replay-full-screen",
+            GoalReplays::Corner => "replay-corner",
+            GoalReplays::Off => "setting-off",
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|r| *r == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+}
+
+/// What a replay shows: the balls and paddles.
+type Actor = Or<(With<Ball>, With<Paddle>)>;
+type ReplayUi = Or<(With<Ghost>, With<ReplayBanner>)>;
+/// How an actor looks, for its ghost to copy.
+type Look<'a> = (
+    Entity,
+    &'a Sprite,
+    &'a Handle<Image>,
+    Option<&'a TextureAtlas>,
+);
+
+/// Where the ball and paddles were on one frame.
+struct Snapshot {
+    time: f32,
+    transforms: HashMap<Entity, Transform>,
+}
+
+/// The last `REPLAY_SECONDS` of play, oldest first.
+#[derive(Resource, Default)]
+struct ReplayBuffer(VecDeque<Snapshot>);
+
+/// The replay being shown, and how far into it playback is, in recorded seconds.
+#[derive(Resource, Default)]
+struct Replay {
+    snapshots: Vec<Snapshot>,
+    position: f32,
+}
+
+impl Replay {
+    fn new(buffer: &mut ReplayBuffer) -> Self {
+        Replay {
+            snapshots: buffer.0.drain(..).collect(),
+            position: 0.0,
+        }
+    }
+
+    /// Moves playback on by `seconds` of game time. Returns the two recorded frames to
+    /// blend between and how far between them, or `None` once the replay has finished.
+    fn advance(&mut self, seconds: f32) -> Option<(&Snapshot, &Snapshot, f32)> {
+        self.position += seconds * REPLAY_SPEED;
+        let (first, last) = (self.snapshots.first()?, self.snapshots.last()?);
+        let at = first.time + self.position;
+        if at >= last.time {
+            return None;
+        }
+        let after = self.snapshots.partition_point(|s| s.time <= at);
+        let (from, to) = (&self.snapshots[after - 1], &self.snapshots[after]);
+        let blend = (at - from.time) / (to.time - from.time).max(f32::EPSILON);
+        Some((from, to, blend))
+    }
+}
+
+/// The replay playing in the corner window, if any.
+#[derive(Resource, Default)]
+struct CornerReplay {
+    replay: Replay,
+    /// Whether its camera, window and ghosts have been spawned yet.
+    shown: bool,
+}
+
+/// Stands in for a ball or paddle during a replay, so the real ones and their physics
+/// are left alone.
+#[derive(Component)]
+struct Ghost(Entity);
+
+#[derive(Component)]
+struct ReplayBanner;
+
+/// Everything belonging to the corner replay: its ghosts, camera and window.
+#[derive(Component)]
+struct CornerPart;
+
+/// After each goal, shows the lead-up again in slow motion: either filling the court, where
+/// any key, click or tap skips it, or in a corner window while play goes on.
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayBuffer>();
+        app.init_resource::<Replay>();
+        app.init_resource::<CornerReplay>();
+        app.add_setting_row(SettingRow {
+            label: "setting-goal-replay",
+            value: |s, l| l.text(s.goal_replays.name()),
+            change: |s, step| s.goal_replays = s.goal_replays.cycle(step),
+        });
+        app.add_systems(
+            Update,
+            (record, play_corner_replay).run_if(in_state(GameState::Playing)),
+        );
+        app.add_systems(
+            PostUpdate,
+            (start_replay, show_corner_replay)
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+        app.add_systems(OnEnter(GameState::Replay), spawn_ghosts);
+        app.add_systems(
+            Update,
+            (play_replay, skip_replay).run_if(in_state(GameState::Replay)),
+        );
+        app.add_systems(OnExit(GameState::Replay), despawn_ghosts);
+    }
+}
+
+fn record(
+    time: Res<Time>,
+    actors: Query<(Entity, &Transform), Actor>,
+    mut buffer: ResMut<ReplayBuffer>,
+) {
+    let now = time.elapsed_seconds();
+    buffer.0.push_back(Snapshot {
+        time: now,
+        transforms: actors.iter().map(|(e, t)| (e, *t)).collect(),
+    });
+    while buffer
+        .0
+        .front()
+        .is_some_and(|oldest| now - oldest.time > REPLAY_SECONDS)
+    {
+        buffer.0.pop_front();
+    }
+}
+
+fn start_replay(
+    mut points: EventReader<PointScored>,
+    settings: Res<Settings>,
+    mut buffer: ResMut<ReplayBuffer>,
+    mut replay: ResMut<Replay>,
+    mut corner: ResMut<CornerReplay>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    let scored = points.read().last().is_some();
+    if !scored || buffer.0.len() < 2 {
+        return;
+    }
+    match settings.goal_replays {
+        GoalReplays::FullScreen => {
+            *replay = Replay::new(&mut buffer);
+            next.set(GameState::Replay);
+        }
+        // Replaces a corner replay that is still going.
+        GoalReplays::Corner => {
+            *corner = CornerReplay {
+                replay: Replay::new(&mut buffer),
+                shown: false,
+            };
+        }
+        GoalReplays::Off => {}
+    }
+}
+
+/// A second camera draws ghosts on their own render layer into an image, which a UI node
+/// in the corner shows.
+fn show_corner_replay(
+    mut commands: Commands,
+    mut corner: ResMut<CornerReplay>,
+    mut images: ResMut<Assets<Image>>,
+    arena: Res<Arena>,
+    actors: Query<Look, Actor>,
+    parts: Query<Entity, With<CornerPart>>,
+) {
+    if corner.shown {
+        return;
+    }
+    let Some(first) = corner.replay.snapshots.first() else {
+        return;
+    };
+    for part in &parts {
+        commands.entity(part).despawn_recursive();
+    }
+
+    let size = Extent3d {
+        width: CORNER_SIZE.x,
+        height: CORNER_SIZE.y,
+        depth_or_array_layers: 1,
+    };
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    let image = images.add(image);
+
+    let camera = Camera2dBundle {
+        camera: Camera {
+            order: -1,
+            target: RenderTarget::Image(image.clone()),
+            clear_color: ClearColorConfig::Custom(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            ..Default::default()
+        },
+        projection: OrthographicProjection {
+            scale: arena.width / CORNER_SIZE.x as f32,
+            ..Camera2dBundle::default().projection
+        },
+        ..Default::default()
+    };
+    commands.spawn((camera, RenderLayers::layer(CORNER_LAYER), CornerPart));
+
+    commands.spawn((
+        ImageBundle {
+            image: UiImage::new(image),
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(24.0),
+                right: Val::Px(24.0),
+                width: Val::Px(CORNER_SIZE.x as f32),
+                height: Val::Px(CORNER_SIZE.y as f32),
+                border: UiRect::all(Val::Px(2.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        BorderColor(Color::WHITE),
+        CornerPart,
+    ));
+
+    for (entity, sprite, texture, section) in &actors {
+        let Some(transform) = first.transforms.get(&entity) else {
+            continue;
+        };
+        let mut ghost = commands.spawn((
+            SpriteBundle {
+                sprite: sprite.clone(),
+                texture: texture.clone(),
+                transform: *transform,
+                ..Default::default()
+            },
+            RenderLayers::layer(CORNER_LAYER),
+            Ghost(entity),
+            CornerPart,
+        ));
+        if let Some(section) = section {
+            ghost.insert(section.clone());
+        }
+    }
+    corner.shown = true;
+}
+
+/// Runs with play, and stops with it when the game is paused.
+fn play_corner_replay(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut corner: ResMut<CornerReplay>,
+    mut ghosts: Query<(&mut Transform, &Ghost), With<CornerPart>>,
+    parts: Query<Entity, With<CornerPart>>,
+) {
+    if !corner.shown {
+        return;
+    }
+    match corner.replay.advance(time.delta_seconds()) {
+        Some((from, to, blend)) => move_ghosts(from, to, blend, ghosts.iter_mut()),
+        None => {
+            for part in &parts {
+                commands.entity(part).despawn_recursive();
+            }
+            *corner = CornerReplay::default();
+        }
+    }
+}
+
+fn move_ghosts<'a>(
+    from: &Snapshot,
+    to: &Snapshot,
+    blend: f32,
+    ghosts: impl Iterator<Item = (Mut<'a, Transform>, &'a Ghost)>,
+) {
+    for (mut transform, Ghost(entity)) in ghosts {
+        if let (Some(a), Some(b)) = (from.transforms.get(entity), to.transforms.get(entity)) {
+            transform.translation = a.translation.lerp(b.translation, blend);
+        }
+    }
+}
+
+fn spawn_ghosts(
+    mut commands: Commands,
+    replay: Res<Replay>,
+    mut actors: Query<(Look, &mut Visibility), Actor>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+) {
+    let Some(first) = replay.snapshots.first() else {
+        return;
+    };
+    for ((entity, sprite, texture, section), mut visibility) in &mut actors {
+        let Some(transform) = first.transforms.get(&entity) else {
+            continue;
+        };
+        *visibility = Visibility::Hidden;
+        let mut ghost = commands.spawn((
+            SpriteBundle {
+                sprite: sprite.clone(),
+                texture: texture.clone(),
+                transform: *transform,
+                ..Default::default()
+            },
+            Ghost(entity),
+        ));
+        if let Some(section) = section {
+            ghost.insert(section.clone());
+        }
+    }
+    commands.spawn((
+        fonts
+            .text_bundle(&locale.text("replay-banner"), 40.)
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(24.0),
+                right: Val::Px(24.0),
+                ..Default::default()
+            }),
+        LocalizedText("replay-banner"),
+        ReplayBanner,
+    ));
+}
+
+/// Moves the ghosts along the recording, blending between frames since playback is
+/// slower than recording.
+fn play_replay(
+    time: Res<Time>,
+    mut replay: ResMut<Replay>,
+    mut ghosts: Query<(&mut Transform, &Ghost), Without<CornerPart>>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    match replay.advance(time.delta_seconds()) {
+        Some((from, to, blend)) => move_ghosts(from, to, blend, ghosts.iter_mut()),
+        None => next.set(GameState::Playing),
+    }
+}
+
+fn skip_replay(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    if keys.get_just_pressed().next().is_some()
+        || mouse.get_just_pressed().next().is_some()
+        || touches.any_just_pressed()
+    {
+        next.set(GameState::Playing);
+    }
+}
+
+fn despawn_ghosts(
+    mut commands: Commands,
+    ghosts: Query<Entity, (ReplayUi, Without<CornerPart>)>,
+    mut actors: Query<&mut Visibility, Actor>,
+) {
+    for ghost in &ghosts {
+        commands.entity(ghost).despawn_recursive();
+    }
+    for mut visibility in &mut actors {
+        *visibility = Visibility::Inherited;
+    }
+}
@@ -0,0 +1,170 @@
+use bevy::prelude::*;
+
+use crate::{
+    GameEvents, GameState, MatchRules, Player, Score,
+    fonts::FontStack,
+    handicap::apply_head_starts,
+    locale::{Locale, LocalizedText},
+    settings::Settings,
+    stats::MatchStats,
+    toast::Toast,
+};
+
+/// Seconds the restart key has to be held.
+const HOLD_SECONDS: f32 = 1.0;
+const PANEL_COLOUR: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
+const BAR_COLOUR: Color = Color::srgb(0.9, 0.3, 0.2);
+
+/// How long the restart key has been held, and whether this hold already restarted.
+#[derive(Resource, Default)]
+struct Hold {
+    seconds: f32,
+    done: bool,
+}
+
+#[derive(Component)]
+struct HoldPanel;
+
+#[derive(Component)]
+struct HoldBar;
+
+/// A shortcut for practice sessions and tournaments: holding the restart key (F5 by
+/// default, changed in the settings menu) for a second during play starts the match over
+/// from 0–0, or the handicap head starts, with a fresh serve, without going through any menu. A quick tap does nothing,
+/// and letting go early cancels it, so a stray key press can't throw a match away.
+pub struct RestartPlugin;
+
+impl Plugin for RestartPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Hold>();
+        app.add_systems(Startup, spawn_panel);
+        app.add_systems(
+            Update,
+            (
+                hold_to_restart.run_if(in_state(GameState::Playing)),
+                show_hold,
+            )
+                .chain(),
+        );
+        app.add_systems(OnExit(GameState::Playing), |mut hold: ResMut<Hold>| {
+            *hold = Hold::default();
+        });
+    }
+}
+
+fn spawn_panel(mut commands: Commands, fonts: Res<FontStack>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(40.0),
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                visibility: Visibility::Hidden,
+                ..Default::default()
+            },
+            HoldPanel,
+        ))
+        .with_children(|p| {
+            p.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(8.0),
+                    padding: UiRect::all(Val::Px(12.0)),
+                    ..Default::default()
+                },
+                background_color: PANEL_COLOUR.into(),
+                ..Default::default()
+            })
+            .with_children(|p| {
+                p.spawn((fonts.text_bundle("", 24.0), LocalizedText("restart-hold")));
+                p.spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(200.0),
+                        height: Val::Px(8.0),
+                        ..Default::default()
+                    },
+                    background_color: Color::srgba(1.0, 1.0, 1.0, 0.2).into(),
+                    ..Default::default()
+                })
+                .with_children(|p| {
+                    p.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(0.0),
+                                height: Val::Percent(100.0),
+                                ..Default::default()
+                            },
+                            background_color: BAR_COLOUR.into(),
+                            ..Default::default()
+                        },
+                        HoldBar,
+                    ));
+                });
+            });
+        });
+}
+
+/// Once it has restarted, the key has to be let go before it can restart again.
+#[allow(clippy::too_many_arguments)]
+fn hold_to_restart(
+    real_time: Res<Time<Real>>,
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    rules: Res<MatchRules>,
+    locale: Res<Locale>,
+    mut hold: ResMut<Hold>,
+    mut score: ResMut<Score>,
+    mut stats: ResMut<MatchStats>,
+    mut game_events: EventWriter<GameEvents>,
+    mut toasts: EventWriter<Toast>,
+) {
+    if !keys.pressed(settings.bindings.restart) {
+        *hold = Hold::default();
+        return;
+    }
+    if hold.done {
+        return;
+    }
+    // Real time, so slow motion doesn't drag the hold out.
+    hold.seconds += real_time.delta_seconds();
+    if hold.seconds < HOLD_SECONDS {
+        return;
+    }
+
+    hold.done = true;
+    score.0.clear();
+    apply_head_starts(&settings, &rules, &mut score);
+    *stats = MatchStats {
+        started_at: time.elapsed_seconds(),
+        ..Default::default()
+    };
+    game_events.send(GameEvents::ResetBall(Player::Player1));
+    toasts.send(Toast(locale.text("toast-match-restarted")));
+}
+
+fn show_hold(
+    hold: Res<Hold>,
+    mut panels: Query<&mut Visibility, With<HoldPanel>>,
+    mut bars: Query<&mut Style, With<HoldBar>>,
+) {
+    if !hold.is_changed() {
+        return;
+    }
+    let holding = hold.seconds > 0.0 && !hold.done;
+    for mut visibility in &mut panels {
+        visibility.set_if_neq(if holding {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+    for mut bar in &mut bars {
+        bar.width = Val::Percent((hold.seconds / HOLD_SECONDS).min(1.0) * 100.0);
+    }
+}
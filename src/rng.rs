@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use std::ops::{Deref, DerefMut};
+
+use crate::settings::{Settings, load_settings};
+
+/// The one source of randomness for gameplay (serve angles, power-ups, AI noise). Runs
+/// with the same seed and the same inputs play out the same way.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        GameRng {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl Deref for GameRng {
+    type Target = StdRng;
+
+    fn deref(&self) -> &StdRng {
+        &self.rng
+    }
+}
+
+impl DerefMut for GameRng {
+    fn deref_mut(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+}
+
+/// Seeds [`GameRng`] from, in order: the seed given here (`--seed`), `rng_seed` in the
+/// settings file, or the OS. The seed is logged so any run can be repeated.
+pub struct RngPlugin {
+    pub seed: Option<u64>,
+}
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        let seed = self.seed;
+        app.insert_resource(GameRng::new(0));
+        app.add_systems(
+            PreStartup,
+            (move |settings: Res<Settings>, mut rng: ResMut<GameRng>| {
+                let seed = seed.or(settings.rng_seed).unwrap_or_else(rand::random);
+                info!("random seed: {seed}");
+                *rng = GameRng::new(seed);
+            })
+            .after(load_settings),
+        );
+    }
+}
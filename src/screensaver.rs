@@ -0,0 +1,176 @@
+use bevy::{
+    input::{InputSystem, mouse::MouseMotion},
+    prelude::*,
+    window::PrimaryWindow,
+};
+use rand::Rng;
+
+use crate::GameState;
+
+const LOGO: &str = "bevy.png";
+/// Real seconds without input on the pause menu or a results screen before it comes on.
+const IDLE_SECONDS: f32 = 180.0;
+const LOGO_SIZE: f32 = 96.0;
+/// Pixels per second, along each axis.
+const LOGO_SPEED: f32 = 80.0;
+const DIM: Color = Color::srgba(0.0, 0.0, 0.0, 0.92);
+const LOGO_TINT: Color = Color::srgba(1.0, 1.0, 1.0, 0.35);
+
+/// Real seconds since the last input, and the logo's velocity while the screensaver is on.
+#[derive(Resource, Default)]
+struct Idle {
+    seconds: f32,
+    velocity: Option<Vec2>,
+}
+
+#[derive(Component)]
+struct Screensaver;
+
+#[derive(Component)]
+struct BouncingLogo;
+
+/// Guards against burn-in: after three minutes on the pause menu or a results screen with
+/// no input, the screen dims and the Bevy logo drifts around it, bouncing off the edges.
+/// The first key, click, tap or mouse movement brings the game straight back, and isn't
+/// passed on, so waking it can't pick a menu item by accident.
+pub struct ScreensaverPlugin;
+
+impl Plugin for ScreensaverPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Idle>();
+        app.add_systems(Startup, spawn_screensaver);
+        app.add_systems(PreUpdate, wake.after(InputSystem));
+        app.add_systems(Update, (count_idle, bounce_logo).chain());
+    }
+}
+
+fn spawn_screensaver(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..Default::default()
+                },
+                background_color: DIM.into(),
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(40),
+                ..Default::default()
+            },
+            Screensaver,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                ImageBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(LOGO_SIZE),
+                        height: Val::Px(LOGO_SIZE),
+                        ..Default::default()
+                    },
+                    image: UiImage::new(asset_server.load(LOGO)).with_color(LOGO_TINT),
+                    ..Default::default()
+                },
+                BouncingLogo,
+            ));
+        });
+}
+
+/// Any input counts as activity. Input that wakes the screensaver is used up on the way.
+fn wake(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    mut gamepads: ResMut<ButtonInput<GamepadButton>>,
+    mut motion: EventReader<MouseMotion>,
+    touches: Res<Touches>,
+    mut idle: ResMut<Idle>,
+    mut screensavers: Query<&mut Visibility, With<Screensaver>>,
+) {
+    let moved = motion.read().count() > 0;
+    let active = moved
+        || keys.get_just_pressed().next().is_some()
+        || mouse.get_just_pressed().next().is_some()
+        || gamepads.get_just_pressed().next().is_some()
+        || touches.any_just_pressed();
+    if !active {
+        return;
+    }
+    idle.seconds = 0.0;
+    if idle.velocity.take().is_none() {
+        return;
+    }
+    keys.reset_all();
+    mouse.reset_all();
+    gamepads.reset_all();
+    for mut visibility in &mut screensavers {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+/// Only the pause menu and the screens between matches count; anything else starts over.
+fn count_idle(
+    time: Res<Time<Real>>,
+    state: Res<State<GameState>>,
+    mut idle: ResMut<Idle>,
+    mut screensavers: Query<&mut Visibility, With<Screensaver>>,
+    mut logos: Query<&mut Style, With<BouncingLogo>>,
+) {
+    if !matches!(state.get(), GameState::Paused | GameState::Intermission) {
+        idle.seconds = 0.0;
+        if idle.velocity.take().is_some() {
+            for mut visibility in &mut screensavers {
+                *visibility = Visibility::Hidden;
+            }
+        }
+        return;
+    }
+    idle.seconds += time.delta_seconds();
+    if idle.seconds < IDLE_SECONDS || idle.velocity.is_some() {
+        return;
+    }
+    let mut rng = rand::thread_rng();
+    let sign = |heads: bool| if heads { 1.0 } else { -1.0 };
+    idle.velocity = Some(Vec2::new(sign(rng.gen_bool(0.5)), sign(rng.gen_bool(0.5))) * LOGO_SPEED);
+    for mut style in &mut logos {
+        style.left = Val::Px(rng.gen_range(0.0..200.0));
+        style.top = Val::Px(rng.gen_range(0.0..200.0));
+    }
+    for mut visibility in &mut screensavers {
+        *visibility = Visibility::Visible;
+    }
+}
+
+/// Turns back at whichever edge of the window it reaches.
+fn bounce_logo(
+    time: Res<Time<Real>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut idle: ResMut<Idle>,
+    mut logos: Query<&mut Style, With<BouncingLogo>>,
+) {
+    let Some(mut velocity) = idle.velocity else {
+        return;
+    };
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let room = Vec2::new(window.width(), window.height()) - LOGO_SIZE;
+    for mut style in &mut logos {
+        let (Val::Px(left), Val::Px(top)) = (style.left, style.top) else {
+            continue;
+        };
+        let mut position = Vec2::new(left, top) + velocity * time.delta_seconds();
+        for axis in 0..2 {
+            if position[axis] <= 0.0 {
+                velocity[axis] = velocity[axis].abs();
+            } else if position[axis] >= room[axis] {
+                velocity[axis] = -velocity[axis].abs();
+            }
+        }
+        position = position.clamp(Vec2::ZERO, room.max(Vec2::ZERO));
+        style.left = Val::Px(position.x);
+        style.top = Val::Px(position.y);
+    }
+    idle.velocity = Some(velocity);
+}
@@ -0,0 +1,56 @@
+use bevy::{
+    prelude::*, render::view::screenshot::ScreenshotManager, utils::SystemTime,
+    window::PrimaryWindow,
+};
+use std::path::PathBuf;
+
+use crate::{locale::Locale, toast::Toast};
+
+const SCREENSHOT_DIR: &str = "screenshots";
+
+pub struct ScreenshotPlugin;
+
+impl Plugin for ScreenshotPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, capture_screenshot);
+    }
+}
+
+fn capture_screenshot(
+    input: Res<ButtonInput<KeyCode>>,
+    window: Query<Entity, With<PrimaryWindow>>,
+    mut screenshots: ResMut<ScreenshotManager>,
+    locale: Res<Locale>,
+    mut toasts: EventWriter<Toast>,
+) {
+    if !input.just_pressed(KeyCode::F12) {
+        return;
+    }
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+
+    // In the browser the screenshot is downloaded instead, so there is no directory to make.
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(err) = std::fs::create_dir_all(SCREENSHOT_DIR) {
+        warn!("failed to create {SCREENSHOT_DIR}: {err}");
+        toasts.send(Toast(locale.text("toast-screenshot-failed")));
+        return;
+    }
+
+    let stamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    let path = PathBuf::from(SCREENSHOT_DIR).join(format!("pong-{stamp}.png"));
+
+    match screenshots.save_screenshot_to_disk(window, &path) {
+        Ok(()) => {
+            toasts.send(Toast(locale.format(
+                "toast-screenshot-saved",
+                &[("path", path.display().to_string().into())],
+            )));
+        }
+        Err(err) => warn!("{err}"),
+    }
+}
@@ -0,0 +1,192 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use bevy_rapier2d::prelude::*;
+use rhai::{AST, CallFnOptions, Dynamic, Engine, FLOAT, INT, Map, Scope};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use crate::{Ball, GameState, Player, PointScored, Score, hits::BallHitPaddle, spawn_ball};
+
+/// Stops runaway scripts, such as an endless loop in `on_tick`.
+const MAX_OPERATIONS: u64 = 100_000;
+const MAX_CALL_LEVELS: usize = 32;
+
+/// What a script asked for. Calls only queue these; they are carried out after the hook
+/// returns, so scripts never touch the world directly.
+#[derive(Clone, Copy, Debug)]
+enum ScriptCommand {
+    SetBallVelocity(Vec2),
+    AddPoints(Player, i32),
+    SpawnBall,
+}
+
+/// A compiled rules script and the state it keeps between hooks.
+#[derive(Resource)]
+struct Script {
+    engine: Engine,
+    ast: AST,
+    scope: Scope<'static>,
+    /// Bound to `this` in every hook, for anything the script wants to remember.
+    state: Dynamic,
+    commands: Arc<Mutex<Vec<ScriptCommand>>>,
+    /// Hooks that raised an error. They are not called again, so a broken `on_tick` logs
+    /// one error instead of sixty a second.
+    failed: HashSet<&'static str>,
+}
+
+impl Script {
+    fn load(path: &Path) -> Result<Self, String> {
+        let commands = Arc::new(Mutex::new(Vec::new()));
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.set_max_call_levels(MAX_CALL_LEVELS);
+        register_api(&mut engine, &commands);
+
+        let ast = engine
+            .compile_file(path.to_path_buf())
+            .map_err(|err| err.to_string())?;
+        let mut script = Script {
+            engine,
+            ast,
+            scope: Scope::new(),
+            state: Map::new().into(),
+            commands,
+            failed: HashSet::new(),
+        };
+        script.call("init", ());
+        Ok(script)
+    }
+
+    /// Calls `hook` if the script defines it.
+    fn call(&mut self, hook: &'static str, args: impl rhai::FuncArgs) {
+        if self.failed.contains(hook) || !self.ast.iter_functions().any(|f| f.name == hook) {
+            return;
+        }
+        // Only the hook runs, never the script's top-level statements.
+        let options = CallFnOptions::new()
+            .eval_ast(false)
+            .bind_this_ptr(&mut self.state);
+        let result = self.engine.call_fn_with_options::<Dynamic>(
+            options,
+            &mut self.scope,
+            &self.ast,
+            hook,
+            args,
+        );
+        if let Err(err) = result {
+            error!("script {hook} failed, disabling it: {err}");
+            self.failed.insert(hook);
+        }
+    }
+
+    fn take_commands(&self) -> Vec<ScriptCommand> {
+        std::mem::take(&mut *self.commands.lock().unwrap())
+    }
+}
+
+/// Players are 1 and 2 in scripts.
+fn player_number(player: Player) -> INT {
+    match player {
+        Player::Player1 => 1,
+        Player::Player2 => 2,
+    }
+}
+
+fn register_api(engine: &mut Engine, commands: &Arc<Mutex<Vec<ScriptCommand>>>) {
+    let queue = commands.clone();
+    engine.register_fn("set_ball_velocity", move |x: FLOAT, y: FLOAT| {
+        let velocity = Vec2::new(x as f32, y as f32);
+        queue
+            .lock()
+            .unwrap()
+            .push(ScriptCommand::SetBallVelocity(velocity));
+    });
+    let queue = commands.clone();
+    engine.register_fn("add_points", move |player: INT, points: INT| {
+        let player = if player == 2 {
+            Player::Player2
+        } else {
+            Player::Player1
+        };
+        queue
+            .lock()
+            .unwrap()
+            .push(ScriptCommand::AddPoints(player, points as i32));
+    });
+    let queue = commands.clone();
+    engine.register_fn("spawn_ball", move || {
+        queue.lock().unwrap().push(ScriptCommand::SpawnBall);
+    });
+}
+
+/// `--script`: custom rules in a Rhai file, run on game events without recompiling.
+pub struct ScriptingPlugin {
+    pub path: PathBuf,
+}
+
+impl Plugin for ScriptingPlugin {
+    fn build(&self, app: &mut App) {
+        match Script::load(&self.path) {
+            Ok(script) => {
+                info!("loaded script {}", self.path.display());
+                app.insert_resource(script);
+            }
+            Err(err) => {
+                error!("couldn't load script {}: {err}", self.path.display());
+                return;
+            }
+        }
+        app.add_systems(
+            Update,
+            (
+                (
+                    paddle_hit_hook,
+                    goal_hook,
+                    tick_hook.run_if(in_state(GameState::Playing)),
+                ),
+                run_script_commands,
+            )
+                .chain(),
+        );
+    }
+}
+
+fn paddle_hit_hook(mut hits: EventReader<BallHitPaddle>, mut script: ResMut<Script>) {
+    for hit in hits.read() {
+        script.call("on_paddle_hit", (player_number(hit.player),));
+    }
+}
+
+fn goal_hook(mut points: EventReader<PointScored>, mut script: ResMut<Script>) {
+    for point in points.read() {
+        script.call("on_goal", (player_number(point.scorer),));
+    }
+}
+
+fn tick_hook(time: Res<Time>, mut script: ResMut<Script>) {
+    script.call("on_tick", (time.delta_seconds() as FLOAT,));
+}
+
+fn run_script_commands(world: &mut World) {
+    let commands = world.resource::<Script>().take_commands();
+    for command in commands {
+        match command {
+            ScriptCommand::SetBallVelocity(velocity) => {
+                let mut balls = world.query_filtered::<&mut Velocity, With<Ball>>();
+                for mut ball in balls.iter_mut(world) {
+                    ball.linvel = velocity;
+                }
+            }
+            ScriptCommand::AddPoints(player, points) => {
+                let mut score = world.resource_mut::<Score>();
+                let total = score.0.entry(player).or_insert(0);
+                *total = (*total + points).max(0);
+            }
+            ScriptCommand::SpawnBall => {
+                world.run_system_once(spawn_ball);
+            }
+        }
+    }
+}
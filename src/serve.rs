@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Ball, GameState, Paddle, Player,
+    ai::AiPaddle,
+    arena::Arena,
+    menu::{AppSettingsExt, SettingRow},
+    move_paddle,
+    rng::GameRng,
+    tuning::GameplayConfig,
+};
+
+/// How long the ball waits in front of the server's paddle.
+const SERVE_DELAY_SECONDS: f32 = 1.0;
+/// How far in front of the server's paddle the ball waits.
+pub const SERVE_DISTANCE: f32 = 60.0;
+/// A lob goes off at this angle, in radians, at this fraction of the serve speed.
+const LOB_ANGLE: f32 = 0.75;
+const LOB_SPEED: f32 = 0.6;
+/// A power serve goes this many times faster, but the ball waits this long for it.
+const POWER_SPEED: f32 = 1.8;
+const POWER_DELAY_SECONDS: f32 = 2.5;
+
+/// How serves leave the paddle, chosen in the settings menu.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ServeStyle {
+    /// Flat across the court.
+    Straight,
+    /// At a random angle, up to the configured maximum.
+    #[default]
+    RandomAngle,
+    /// Slow and steep, off the walls.
+    Lob,
+    /// Fast, after a longer wait.
+    Power,
+}
+
+impl ServeStyle {
+    const ALL: [ServeStyle; 4] = [
+        ServeStyle::Straight,
+        ServeStyle::RandomAngle,
+        ServeStyle::Lob,
+        ServeStyle::Power,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            ServeStyle::Straight => "serve-straight",
+            ServeStyle::RandomAngle => "serve-random",
+            ServeStyle::Lob => "serve-lob",
+            ServeStyle::Power => "serve-power",
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|s| *s == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+
+    fn delay(self) -> f32 {
+        match self {
+            ServeStyle::Power => POWER_DELAY_SECONDS,
+            _ => SERVE_DELAY_SECONDS,
+        }
+    }
+
+    fn speed(self) -> f32 {
+        match self {
+            ServeStyle::Lob => LOB_SPEED,
+            ServeStyle::Power => POWER_SPEED,
+            _ => 1.0,
+        }
+    }
+
+    /// The angle off horizontal, upwards for a positive `aim`. Without aim it is up to
+    /// the style, which for a lob means a random side.
+    fn angle(self, aim: f32, max_angle: f32, rng: &mut GameRng) -> f32 {
+        let steepest = if self == ServeStyle::Lob {
+            LOB_ANGLE
+        } else {
+            max_angle
+        };
+        if aim != 0.0 {
+            return aim * steepest;
+        }
+        match self {
+            ServeStyle::Straight => 0.0,
+            ServeStyle::RandomAngle | ServeStyle::Power => rng.gen_range(-max_angle..=max_angle),
+            ServeStyle::Lob => {
+                if rng.gen_bool(0.5) {
+                    LOB_ANGLE
+                } else {
+                    -LOB_ANGLE
+                }
+            }
+        }
+    }
+}
+
+/// A ball waiting to be served, at rest in front of the server's paddle.
+#[derive(Component)]
+pub struct Serving {
+    pub server: Player,
+    style: ServeStyle,
+    delay: Timer,
+}
+
+impl Serving {
+    pub fn new(server: Player, style: ServeStyle) -> Self {
+        Serving {
+            server,
+            style,
+            delay: Timer::from_seconds(style.delay(), TimerMode::Once),
+        }
+    }
+
+    /// Starts the wait over, for while something covers the court.
+    pub fn restart_delay(&mut self) {
+        self.delay.reset();
+    }
+}
+
+/// A ball has just been served.
+#[derive(Event)]
+pub struct Served {
+    pub ball: Entity,
+}
+
+type ServerPaddle = (With<Paddle>, Without<Ball>);
+
+/// After a point the ball is put in front of the server's paddle and waits a moment before
+/// heading for the receiver, as in the arcade game. `reset_ball` sets it up; this plugin
+/// counts down and sends it off.
+///
+/// While it waits the ball moves up and down with the server's paddle, and holding up or
+/// down as it leaves sends it off at the steepest angle that way instead of the style's
+/// usual one.
+pub struct ServePlugin;
+
+impl Plugin for ServePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Served>();
+        app.add_setting_row(SettingRow {
+            label: "setting-serve-style",
+            value: |s, l| l.text(s.serve_style.name()),
+            change: |s, step| s.serve_style = s.serve_style.cycle(step),
+        });
+        app.add_systems(
+            Update,
+            (follow_server.after(move_paddle), launch_serves)
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+fn follow_server(
+    paddles: Query<(&Transform, &Player), ServerPaddle>,
+    mut balls: Query<(&Serving, &mut Transform), With<Ball>>,
+) {
+    for (serving, mut ball) in &mut balls {
+        if let Some((paddle, _)) = paddles
+            .iter()
+            .find(|(_, player)| **player == serving.server)
+        {
+            ball.translation.y = paddle.translation.y;
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn launch_serves(
+    mut commands: Commands,
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    config: Res<GameplayConfig>,
+    arena: Res<Arena>,
+    mut rng: ResMut<GameRng>,
+    paddles: Query<(&Paddle, &Player), Without<AiPaddle>>,
+    mut balls: Query<(Entity, &mut Serving, &mut Velocity)>,
+    mut served: EventWriter<Served>,
+) {
+    for (ball, mut serving, mut velocity) in &mut balls {
+        if !serving.delay.tick(time.delta()).finished() {
+            continue;
+        }
+        let aim = paddles
+            .iter()
+            .find(|(_, player)| **player == serving.server)
+            .map_or(0.0, |(controls, _)| {
+                match (
+                    keys.pressed(controls.move_up),
+                    keys.pressed(controls.move_down),
+                ) {
+                    (true, false) => 1.0,
+                    (false, true) => -1.0,
+                    _ => 0.0,
+                }
+            });
+        let style = serving.style;
+        *velocity = serving
+            .server
+            .start_speed(config.serve_speed * style.speed() * arena.scale);
+        // Angles turn Player 2's serves, which head left, the other way.
+        let facing = velocity.linvel.x.signum();
+        let angle = style.angle(aim, config.max_serve_angle, &mut rng) * facing;
+        velocity.linvel = Vec2::from_angle(angle).rotate(velocity.linvel);
+        commands.entity(ball).remove::<Serving>();
+        served.send(Served { ball });
+    }
+}
@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ai::AiDifficulty,
+    arena::CourtSize,
+    assist::Assists,
+    barriers::Barriers,
+    cli::Cli,
+    controls::Bindings,
+    display::{DisplayMode, FrameRate},
+    handicap::Handicaps,
+    locale::Language,
+    replay::GoalReplays,
+    serve::ServeStyle,
+    skins::{BallSkin, PaddleSkins},
+    slow_motion::SaveSlowMotion,
+    speed::GameSpeed,
+    speedometer::SpeedUnits,
+    storage::{Storage, UserFiles},
+    theme::{ArenaTheme, ChromaKey, PaddleColours, Palette},
+};
+
+const SETTINGS_KEY: &str = "settings";
+
+/// Player-facing options shared by every system.
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Write a JSON report of every finished match to the exports directory.
+    pub export_match_json: bool,
+    pub palette: Palette,
+    pub paddle_colours: PaddleColours,
+    /// Mark paddles with distinct patterns so they differ by more than colour.
+    pub paddle_patterns: bool,
+    /// Maximum-contrast colours, thicker lines and a black background, overriding the palette.
+    pub high_contrast: bool,
+    /// Flat green or magenta background with the decoration hidden, for keying into overlays.
+    pub chroma_key: ChromaKey,
+    pub assist: Assists,
+    /// Turn off shake, particles, background animation and flashing.
+    pub reduced_motion: bool,
+    /// Show an on-screen marker for every sound.
+    pub visual_cues: bool,
+    pub game_speed: GameSpeed,
+    pub save_slow_motion: SaveSlowMotion,
+    pub bindings: Bindings,
+    pub language: Language,
+    pub display_mode: DisplayMode,
+    pub frame_rate: FrameRate,
+    /// UI size in percent, on top of the monitor's scale factor. `None` fits the window.
+    pub ui_scale: Option<u32>,
+    /// Fixed random seed for reproducing a session. Not in the menu; edit the file.
+    pub rng_seed: Option<u64>,
+    pub ai_difficulty: AiDifficulty,
+    pub goal_replays: GoalReplays,
+    pub paddle_skins: PaddleSkins,
+    pub ball_skin: BallSkin,
+    pub arena_theme: ArenaTheme,
+    /// Lean the camera towards the ball. Off by default, for competitive play.
+    pub follow_camera: bool,
+    pub barriers: Barriers,
+    /// Moving and dashing use up stamina. Off by default.
+    pub stamina: bool,
+    /// Blur the court behind the pause menu, on top of dimming it.
+    pub pause_blur: bool,
+    pub speed_meter: SpeedUnits,
+    /// Hide everything but the score digits.
+    pub minimal_hud: bool,
+    pub serve_style: ServeStyle,
+    /// Carry on when the window loses focus instead of pausing, for streaming setups.
+    pub keep_playing_unfocused: bool,
+    pub handicaps: Handicaps,
+    /// The lights-out mutator never darkens the court.
+    pub keep_lights_on: bool,
+    pub court_size: CourtSize,
+    /// Send scores to the online leaderboard, with the `leaderboard` feature. Off by default.
+    pub share_scores: bool,
+    /// Name shown on the online leaderboard. Not in the menu; edit the file.
+    pub player_name: String,
+    /// Send anonymous gameplay stats, with the `telemetry` feature. Off by default.
+    pub share_stats: bool,
+}
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Settings>();
+        app.add_systems(PreStartup, load_settings);
+        app.add_systems(Last, save_settings);
+    }
+}
+
+/// The display mode from before `--fullscreen` replaced it, which is what gets saved.
+#[derive(Resource)]
+struct StoredDisplayMode(DisplayMode);
+
+/// `--config` replaces the saved settings and `--fullscreen` goes on top of either.
+pub fn load_settings(
+    mut commands: Commands,
+    storage: Res<Storage>,
+    files: Res<UserFiles>,
+    cli: Option<Res<Cli>>,
+    mut settings: ResMut<Settings>,
+) {
+    let config = cli.as_ref().and_then(|cli| cli.config.as_ref());
+    if let Some(path) = config {
+        match files
+            .read_text(&path.to_string_lossy())
+            .map_err(|err| err.to_string())
+            .and_then(|json| json.ok_or_else(|| "no such file".to_string()))
+            .and_then(|json| serde_json::from_str(&json).map_err(|err| err.to_string()))
+        {
+            Ok(loaded) => *settings = loaded,
+            Err(err) => warn!("could not read config {}: {err}", path.display()),
+        }
+    } else if let Some(saved) = storage.load(SETTINGS_KEY) {
+        *settings = saved;
+    }
+    if cli.is_some_and(|cli| cli.fullscreen) {
+        commands.insert_resource(StoredDisplayMode(settings.display_mode));
+        settings.display_mode = DisplayMode::Borderless;
+    }
+}
+
+/// `--fullscreen` is only for this run, so the display mode saved is the one it replaced.
+fn save_settings(
+    storage: Res<Storage>,
+    cli: Option<Res<Cli>>,
+    stored: Option<Res<StoredDisplayMode>>,
+    settings: Res<Settings>,
+) {
+    if cli.is_some_and(|cli| cli.overrides_settings()) || !settings.is_changed() {
+        return;
+    }
+    match stored {
+        Some(stored) => {
+            let mut saved = settings.clone();
+            saved.display_mode = stored.0;
+            storage.save(SETTINGS_KEY, &saved);
+        }
+        None => storage.save(SETTINGS_KEY, &*settings),
+    }
+}
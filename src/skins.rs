@@ -0,0 +1,241 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Ball, Paddle, Player,
+    atlas::{SpriteAtlas, pack_atlas},
+    menu::{AppSettingsExt, Choice, ChoiceRow, MenuChoices},
+    progression::Profile,
+    settings::Settings,
+    tuning::AppConfigExt,
+};
+
+const PADDLE_SKINS: &str = "paddle-skins";
+const BALL_SKINS: &str = "ball-skins";
+const DEFAULT_PADDLE_SKIN: &str = "classic";
+const DEFAULT_BALL_SKIN: &str = "bevy";
+
+/// A look from the skins manifest. Images are drawn white-on-transparent and tinted with
+/// the player's colour, so skins keep the colour-blind palettes working. Sprites are sized
+/// by the game, not the image, so any resolution works.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Skin {
+    pub id: String,
+    pub name: String,
+    /// Relative to `assets`. Without one the sprite is a plain rectangle.
+    #[serde(default)]
+    pub image: Option<String>,
+    /// Profile level that unlocks the skin.
+    #[serde(default)]
+    pub level: u32,
+}
+
+/// Every skin, read from `assets/skins/default.skins.json`. Adding a skin is adding an image
+/// and a line here.
+#[derive(Asset, Resource, TypePath, Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct SkinManifest {
+    pub paddles: Vec<Skin>,
+    pub balls: Vec<Skin>,
+}
+
+/// The image for skin `id`, or for `fallback` if `id` is missing or still locked. `None`
+/// is a plain sprite.
+fn skin_image<'a>(skins: &'a [Skin], id: &str, fallback: &str, level: u32) -> Option<&'a str> {
+    let usable = |id: &str| skins.iter().find(|s| s.id == id && level >= s.level);
+    usable(id)
+        .or_else(|| usable(fallback))
+        .and_then(|skin| skin.image.as_deref())
+}
+
+/// The texture for a skin image, from the sprite atlas once it is packed.
+fn skin_texture(
+    image: Option<&str>,
+    atlas: &SpriteAtlas,
+    asset_server: &AssetServer,
+) -> (Handle<Image>, Option<TextureAtlas>) {
+    match image {
+        Some(image) => atlas
+            .get(image)
+            .map(|(texture, section)| (texture, Some(section)))
+            .unwrap_or_else(|| (asset_server.load(image.to_string()), None)),
+        None => (Handle::default(), None),
+    }
+}
+
+fn set_texture(
+    commands: &mut Commands,
+    entity: Entity,
+    texture: &mut Handle<Image>,
+    (image, section): (Handle<Image>, Option<TextureAtlas>),
+) {
+    *texture = image;
+    match section {
+        Some(section) => commands.entity(entity).insert(section),
+        None => commands.entity(entity).remove::<TextureAtlas>(),
+    };
+}
+
+fn unlocked_choices(skins: &[Skin], level: u32) -> Vec<Choice> {
+    skins
+        .iter()
+        .filter(|skin| level >= skin.level)
+        .map(|skin| Choice {
+            id: skin.id.clone(),
+            name: skin.name.clone(),
+        })
+        .collect()
+}
+
+/// Chosen skin ids, one per player.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct PaddleSkins {
+    pub player1: String,
+    pub player2: String,
+}
+
+impl Default for PaddleSkins {
+    fn default() -> Self {
+        PaddleSkins {
+            player1: DEFAULT_PADDLE_SKIN.into(),
+            player2: DEFAULT_PADDLE_SKIN.into(),
+        }
+    }
+}
+
+impl PaddleSkins {
+    fn get(&self, player: Player) -> &str {
+        match player {
+            Player::Player1 => &self.player1,
+            Player::Player2 => &self.player2,
+        }
+    }
+}
+
+/// Chosen ball skin id.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BallSkin(pub String);
+
+impl Default for BallSkin {
+    fn default() -> Self {
+        BallSkin(DEFAULT_BALL_SKIN.into())
+    }
+}
+
+pub struct SkinsPlugin;
+
+impl Plugin for SkinsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_config::<SkinManifest>("skins/default.skins.json");
+        app.add_choice_row(ChoiceRow {
+            label: "setting-paddle-skin-p1",
+            list: PADDLE_SKINS,
+            selected: |s| &s.paddle_skins.player1,
+            select: |s, id| s.paddle_skins.player1 = id.to_string(),
+        });
+        app.add_choice_row(ChoiceRow {
+            label: "setting-paddle-skin-p2",
+            list: PADDLE_SKINS,
+            selected: |s| &s.paddle_skins.player2,
+            select: |s, id| s.paddle_skins.player2 = id.to_string(),
+        });
+        app.add_choice_row(ChoiceRow {
+            label: "setting-ball-skin",
+            list: BALL_SKINS,
+            selected: |s| &s.ball_skin.0,
+            select: |s, id| s.ball_skin = BallSkin(id.to_string()),
+        });
+        app.add_systems(
+            Update,
+            (
+                want_skin_images.run_if(resource_changed::<SkinManifest>),
+                list_unlocked_skins
+                    .run_if(resource_changed::<SkinManifest>.or_else(resource_changed::<Profile>)),
+                (apply_paddle_skins, apply_ball_skins).after(pack_atlas),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Locked skins are packed too, so unlocking one doesn't mean packing again.
+fn want_skin_images(
+    manifest: Res<SkinManifest>,
+    asset_server: Res<AssetServer>,
+    mut atlas: ResMut<SpriteAtlas>,
+) {
+    for skin in manifest.paddles.iter().chain(&manifest.balls) {
+        if let Some(image) = &skin.image {
+            atlas.want(image, &asset_server);
+        }
+    }
+}
+
+/// Only skins the profile has reached the level for are offered.
+fn list_unlocked_skins(
+    manifest: Res<SkinManifest>,
+    profile: Res<Profile>,
+    mut choices: ResMut<MenuChoices>,
+) {
+    let level = profile.level();
+    choices
+        .0
+        .insert(PADDLE_SKINS, unlocked_choices(&manifest.paddles, level));
+    choices
+        .0
+        .insert(BALL_SKINS, unlocked_choices(&manifest.balls, level));
+}
+
+fn apply_paddle_skins(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    manifest: Res<SkinManifest>,
+    profile: Res<Profile>,
+    atlas: Res<SpriteAtlas>,
+    asset_server: Res<AssetServer>,
+    mut paddles: Query<(Entity, Ref<Paddle>, &Player, &mut Handle<Image>)>,
+) {
+    let changed = settings.is_changed()
+        || manifest.is_changed()
+        || profile.is_changed()
+        || atlas.is_changed();
+    for (entity, paddle, player, mut texture) in &mut paddles {
+        if !changed && !paddle.is_added() {
+            continue;
+        }
+        let id = settings.paddle_skins.get(*player);
+        let image = skin_image(&manifest.paddles, id, DEFAULT_PADDLE_SKIN, profile.level());
+        let skin = skin_texture(image, &atlas, &asset_server);
+        set_texture(&mut commands, entity, &mut texture, skin);
+    }
+}
+
+/// Also picks up balls spawned mid-game, e.g. from the console.
+fn apply_ball_skins(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    manifest: Res<SkinManifest>,
+    profile: Res<Profile>,
+    atlas: Res<SpriteAtlas>,
+    asset_server: Res<AssetServer>,
+    mut balls: Query<(Entity, Ref<Ball>, &mut Handle<Image>)>,
+) {
+    let changed = settings.is_changed()
+        || manifest.is_changed()
+        || profile.is_changed()
+        || atlas.is_changed();
+    let image = skin_image(
+        &manifest.balls,
+        &settings.ball_skin.0,
+        DEFAULT_BALL_SKIN,
+        profile.level(),
+    );
+    for (entity, ball, mut texture) in &mut balls {
+        if !changed && !ball.is_added() {
+            continue;
+        }
+        let skin = skin_texture(image, &atlas, &asset_server);
+        set_texture(&mut commands, entity, &mut texture, skin);
+    }
+}
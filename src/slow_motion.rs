@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    MatchRules, Paddle, Player, Score,
+    ai::GameMode,
+    hits::{self, BallHitPaddle},
+    menu::{AppSettingsExt, SettingRow},
+    settings::Settings,
+    speed::TimeDips,
+};
+
+/// Half a paddle's height at normal size. Matches the collider.
+const PADDLE_HALF_HEIGHT: f32 = 75.0;
+/// A hit this far out from the paddle's centre, as a share of the way to its end, only
+/// just reached the ball.
+const EDGE_SHARE: f32 = 0.7;
+/// Real seconds the slow motion lasts.
+const DIP_SECONDS: f32 = 0.6;
+
+/// How far the game slows when a match point is saved at the last moment, chosen in the
+/// settings menu.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum SaveSlowMotion {
+    Off,
+    Light,
+    #[default]
+    Medium,
+    Heavy,
+}
+
+impl SaveSlowMotion {
+    const ALL: [SaveSlowMotion; 4] = [
+        SaveSlowMotion::Off,
+        SaveSlowMotion::Light,
+        SaveSlowMotion::Medium,
+        SaveSlowMotion::Heavy,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            SaveSlowMotion::Off => "setting-off",
+            SaveSlowMotion::Light => "slow-motion-light",
+            SaveSlowMotion::Medium => "slow-motion-medium",
+            SaveSlowMotion::Heavy => "slow-motion-heavy",
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|s| *s == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+
+    /// The game's speed during the slow motion.
+    fn factor(self) -> Option<f32> {
+        match self {
+            SaveSlowMotion::Off => None,
+            SaveSlowMotion::Light => Some(0.6),
+            SaveSlowMotion::Medium => Some(0.4),
+            SaveSlowMotion::Heavy => Some(0.25),
+        }
+    }
+}
+
+/// For drama: when a player facing match point only just gets the end of their paddle to
+/// a ball that was about to go in, the game drops into slow motion for a moment as it
+/// comes back off. How slow, or not at all, is a setting.
+pub struct SlowMotionPlugin;
+
+impl Plugin for SlowMotionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-save-slow-motion",
+            value: |s, l| l.text(s.save_slow_motion.name()),
+            change: |s, step| s.save_slow_motion = s.save_slow_motion.cycle(step),
+        });
+        app.add_systems(Update, slow_saves.after(hits::detect_hits));
+    }
+}
+
+/// Survival and co-op have no match point to save.
+fn slow_saves(
+    mut hits: EventReader<BallHitPaddle>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    score: Res<Score>,
+    rules: Res<MatchRules>,
+    paddles: Query<&Transform, With<Paddle>>,
+    mut dips: ResMut<TimeDips>,
+) {
+    let Some(factor) = settings.save_slow_motion.factor() else {
+        hits.clear();
+        return;
+    };
+    if matches!(*mode, GameMode::Survival | GameMode::Coop) {
+        hits.clear();
+        return;
+    }
+    let points = |player: Player| score.0.get(&player).copied().unwrap_or(0);
+    for hit in hits.read() {
+        if points(hit.player.opponent()) != rules.target_score - 1 {
+            continue;
+        }
+        let Ok(paddle) = paddles.get(hit.paddle) else {
+            continue;
+        };
+        let reach = PADDLE_HALF_HEIGHT * paddle.scale.y;
+        if (hit.contact.y - paddle.translation.y).abs() >= EDGE_SHARE * reach {
+            dips.dip(factor, DIP_SECONDS);
+        }
+    }
+}
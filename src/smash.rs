@@ -0,0 +1,197 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    BALL_RADIUS, Ball, GameState, Paddle, Player, ai::AiPaddle, hud::HudWidget, settings::Settings,
+    tilt::Tilt, tuning::GameplayConfig,
+};
+
+/// Seconds of holding for a full charge.
+const CHARGE_SECONDS: f32 = 1.0;
+/// Releasing with less than this much charge does nothing.
+const MIN_CHARGE: f32 = 0.2;
+/// A full smash leaves at this many times the ball's speed.
+const SMASH_BOOST: f32 = 2.2;
+/// Steepest aim, with up or down held, in radians.
+const AIM_ANGLE: f32 = 0.5;
+/// How close in front of the paddle the ball must be when the key is released.
+const REACH: f32 = BALL_RADIUS + 30.0;
+const COOLDOWN_SECONDS: f32 = 3.0;
+const METER_WIDTH: f32 = 140.0;
+const METER_COLOUR: Color = Color::srgba(0.1, 0.1, 0.1, 0.7);
+const COOLDOWN_COLOUR: Color = Color::srgb(0.45, 0.45, 0.45);
+
+/// A player's smash: charged while the key is held and the ball comes their way.
+#[derive(Component)]
+struct Smash {
+    charge: f32,
+    cooldown: Timer,
+}
+
+impl Default for Smash {
+    fn default() -> Self {
+        let mut cooldown = Timer::from_seconds(COOLDOWN_SECONDS, TimerMode::Once);
+        cooldown.tick(cooldown.duration());
+        Smash {
+            charge: 0.0,
+            cooldown,
+        }
+    }
+}
+
+/// The fill of a player's charge meter.
+#[derive(Component)]
+struct SmashMeter(Player);
+
+/// Hold the smash key as the ball comes in, and let go as it reaches the paddle to fire it
+/// back much faster, aimed up or down with the movement keys. It then needs a few seconds
+/// to recharge. Computer-controlled paddles don't smash.
+pub struct SmashPlugin;
+
+impl Plugin for SmashPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_meters);
+        app.add_systems(
+            Update,
+            (
+                add_smash,
+                charge_smash.run_if(in_state(GameState::Playing)),
+                draw_meters,
+            )
+                .chain(),
+        );
+    }
+}
+
+fn add_smash(mut commands: Commands, paddles: Query<Entity, Added<Paddle>>) {
+    for paddle in &paddles {
+        // Survival despawns Player 2's paddle the frame it appears.
+        commands.entity(paddle).try_insert(Smash::default());
+    }
+}
+
+fn spawn_meters(mut commands: Commands) {
+    for player in [Player::Player1, Player::Player2] {
+        let side = Val::Px(16.0);
+        let (left, right) = match player {
+            Player::Player1 => (side, Val::Auto),
+            Player::Player2 => (Val::Auto, side),
+        };
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        bottom: Val::Px(16.0),
+                        left,
+                        right,
+                        width: Val::Px(METER_WIDTH),
+                        height: Val::Px(8.0),
+                        // Player 2's meter fills from the right, towards the middle.
+                        justify_content: match player {
+                            Player::Player1 => JustifyContent::FlexStart,
+                            Player::Player2 => JustifyContent::FlexEnd,
+                        },
+                        ..Default::default()
+                    },
+                    background_color: METER_COLOUR.into(),
+                    ..Default::default()
+                },
+                HudWidget,
+            ))
+            .with_children(|p| {
+                p.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    SmashMeter(player),
+                ));
+            });
+    }
+}
+
+fn charge_smash(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    config: Res<GameplayConfig>,
+    mut paddles: Query<(&Transform, &Paddle, &Player, &Tilt, &mut Smash), Without<AiPaddle>>,
+    mut balls: Query<(&Transform, &mut Velocity, &mut Sprite), With<Ball>>,
+) {
+    for (paddle, controls, player, tilt, mut smash) in &mut paddles {
+        smash.cooldown.tick(time.delta());
+        if !smash.cooldown.finished() {
+            continue;
+        }
+        let key = settings.bindings.smash(*player);
+        let paddle_x = paddle.translation.x;
+        if keys.pressed(key) {
+            let incoming = balls
+                .iter()
+                .any(|(_, velocity, _)| velocity.linvel.x * paddle_x > 0.0);
+            if incoming {
+                smash.charge = (smash.charge + time.delta_seconds() / CHARGE_SECONDS).min(1.0);
+            }
+            continue;
+        }
+        if !keys.just_released(key) {
+            continue;
+        }
+        let charge = std::mem::take(&mut smash.charge);
+        if charge < MIN_CHARGE {
+            continue;
+        }
+        let half_height = 75.0 * paddle.scale.y;
+        let in_reach = |ball: &Transform| {
+            let offset = ball.translation.truncate() - paddle.translation.truncate();
+            offset.x.abs() < REACH && offset.y.abs() < half_height + BALL_RADIUS
+        };
+        let Some((_, mut velocity, mut sprite)) =
+            balls.iter_mut().find(|(ball, _, _)| in_reach(ball))
+        else {
+            continue;
+        };
+        let aim = match (
+            keys.pressed(controls.move_up),
+            keys.pressed(controls.move_down),
+        ) {
+            (true, false) => AIM_ANGLE,
+            (false, true) => -AIM_ANGLE,
+            _ => 0.0,
+        };
+        let speed = velocity.linvel.length().max(config.serve_speed);
+        let boost = 1.0 + (SMASH_BOOST - 1.0) * charge;
+        // Aiming adds to the way the paddle is tilted.
+        let direction = Tilt(tilt.0 + aim).normal(paddle_x);
+        velocity.linvel = direction * speed * boost;
+        sprite.color = settings.player_colour(*player);
+        smash.cooldown.reset();
+    }
+}
+
+/// Meters show the charge in the player's colour, and refill in grey while recharging.
+fn draw_meters(
+    settings: Res<Settings>,
+    paddles: Query<(&Player, &Smash)>,
+    mut meters: Query<(&SmashMeter, &mut Style, &mut BackgroundColor)>,
+) {
+    for (player, smash) in &paddles {
+        for (meter, mut style, mut background) in &mut meters {
+            if meter.0 != *player {
+                continue;
+            }
+            let (fill, colour) = if smash.cooldown.finished() {
+                (smash.charge, settings.player_colour(*player))
+            } else {
+                (smash.cooldown.fraction(), COOLDOWN_COLOUR)
+            };
+            style.width = Val::Percent(fill * 100.0);
+            background.0 = colour;
+        }
+    }
+}
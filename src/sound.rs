@@ -0,0 +1,121 @@
+use bevy::{
+    audio::{Pitch, Volume},
+    prelude::*,
+};
+use std::{collections::VecDeque, time::Duration};
+
+use crate::{
+    Player, PointScored,
+    hits::{BallHitPaddle, BallHitWall},
+};
+
+/// The fanfare's notes: seconds from its start, frequency in hertz and length in seconds.
+/// A rising C major arpeggio, held on the top note.
+const FANFARE: [(f32, f32, f32); 5] = [
+    (0.0, 523.25, 0.14),
+    (0.15, 659.25, 0.14),
+    (0.3, 783.99, 0.14),
+    (0.45, 1046.5, 0.2),
+    (0.7, 1046.5, 0.6),
+];
+const FANFARE_VOLUME: f32 = 0.3;
+
+/// Everything that makes (or will make) a sound. Audio playback and the visual cues for
+/// deaf and hard-of-hearing players both listen to this, so they never drift apart.
+#[derive(Event, Clone, Copy)]
+pub enum SoundEvent {
+    WallBounce {
+        position: Vec2,
+    },
+    PaddleHit {
+        player: Player,
+        position: Vec2,
+    },
+    Goal {
+        scorer: Player,
+    },
+    /// A match has been won.
+    Fanfare {
+        winner: Player,
+    },
+}
+
+/// Fanfare notes still to start, and how far into it we are.
+#[derive(Resource, Default)]
+struct Fanfare {
+    elapsed: f32,
+    notes: VecDeque<(f32, f32, f32)>,
+}
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SoundEvent>();
+        app.init_resource::<Fanfare>();
+        app.add_systems(Update, (hit_sounds, goal_sounds, play_fanfare));
+    }
+}
+
+fn hit_sounds(
+    mut paddle_hits: EventReader<BallHitPaddle>,
+    mut wall_hits: EventReader<BallHitWall>,
+    mut sounds: EventWriter<SoundEvent>,
+) {
+    for hit in paddle_hits.read() {
+        sounds.send(SoundEvent::PaddleHit {
+            player: hit.player,
+            position: hit.contact,
+        });
+    }
+    for hit in wall_hits.read() {
+        sounds.send(SoundEvent::WallBounce {
+            position: hit.contact,
+        });
+    }
+}
+
+fn goal_sounds(mut points: EventReader<PointScored>, mut sounds: EventWriter<SoundEvent>) {
+    for point in points.read() {
+        sounds.send(SoundEvent::Goal {
+            scorer: point.scorer,
+        });
+    }
+}
+
+/// The fanfare is made of generated tones, so it needs no sound files. Headless runs have
+/// no audio, and skip it.
+fn play_fanfare(
+    mut commands: Commands,
+    mut sounds: EventReader<SoundEvent>,
+    time: Res<Time<Real>>,
+    mut fanfare: ResMut<Fanfare>,
+    pitches: Option<ResMut<Assets<Pitch>>>,
+) {
+    if sounds
+        .read()
+        .any(|sound| matches!(sound, SoundEvent::Fanfare { .. }))
+    {
+        *fanfare = Fanfare {
+            elapsed: 0.0,
+            notes: FANFARE.into(),
+        };
+    }
+    let Some(mut pitches) = pitches else {
+        return;
+    };
+    if fanfare.notes.is_empty() {
+        return;
+    }
+    fanfare.elapsed += time.delta_seconds();
+    while let Some(&(start, frequency, length)) = fanfare.notes.front() {
+        if start > fanfare.elapsed {
+            break;
+        }
+        fanfare.notes.pop_front();
+        commands.spawn(PitchBundle {
+            source: pitches.add(Pitch::new(frequency, Duration::from_secs_f32(length))),
+            settings: PlaybackSettings::DESPAWN.with_volume(Volume::new(FANFARE_VOLUME)),
+        });
+    }
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    menu::{AppSettingsExt, SettingRow},
+    settings::Settings,
+};
+
+const MIN_PERCENT: u32 = 50;
+const MAX_PERCENT: u32 = 150;
+const STEP_PERCENT: u32 = 10;
+
+/// Whole-game speed as a percentage. Gameplay and physics run on virtual time, so scaling
+/// it slows the ball, the paddles and every timer together.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GameSpeed(u32);
+
+impl Default for GameSpeed {
+    fn default() -> Self {
+        GameSpeed(100)
+    }
+}
+
+impl GameSpeed {
+    fn step(self, step: i32) -> Self {
+        let percent = self.0 as i32 + step * STEP_PERCENT as i32;
+        GameSpeed((percent.max(0) as u32).clamp(MIN_PERCENT, MAX_PERCENT))
+    }
+
+    fn factor(self) -> f32 {
+        self.0.clamp(MIN_PERCENT, MAX_PERCENT) as f32 / 100.0
+    }
+}
+
+/// Brief drops in game speed for effect, such as slow motion on a save. Each is a speed
+/// factor and how many real seconds it has left, so it ends on time however slow it makes
+/// the game; the slowest one running wins. UI animation runs on real time and carries on
+/// at full speed.
+#[derive(Resource, Default)]
+pub struct TimeDips(Vec<(f32, f32)>);
+
+impl TimeDips {
+    pub fn dip(&mut self, factor: f32, seconds: f32) {
+        self.0.push((factor, seconds));
+    }
+
+    fn factor(&self) -> f32 {
+        self.0.iter().map(|(factor, _)| *factor).fold(1.0, f32::min)
+    }
+}
+
+pub struct SpeedPlugin;
+
+impl Plugin for SpeedPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-game-speed",
+            value: |s, l| l.format("setting-percent", &[("value", s.game_speed.0.into())]),
+            change: |s, step| s.game_speed = s.game_speed.step(step),
+        });
+        app.init_resource::<TimeDips>();
+        app.add_systems(PreUpdate, apply_game_speed);
+    }
+}
+
+fn apply_game_speed(
+    settings: Res<Settings>,
+    real_time: Res<Time<Real>>,
+    mut dips: ResMut<TimeDips>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if !dips.0.is_empty() {
+        let delta = real_time.delta_seconds();
+        dips.0.retain_mut(|(_, seconds)| {
+            *seconds -= delta;
+            *seconds > 0.0
+        });
+    }
+    let speed = settings.game_speed.factor() * dips.factor();
+    if time.relative_speed() != speed {
+        time.set_relative_speed(speed);
+    }
+}
@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Ball,
+    arena::Arena,
+    fonts::FontStack,
+    hud::HudWidget,
+    locale::Locale,
+    menu::{AppSettingsExt, SettingRow},
+    settings::Settings,
+};
+
+/// The court counts as a table-tennis table this long, for the units that need metres.
+const COURT_METRES: f32 = 2.74;
+/// Speed at which the bar is full, in pixels per second.
+const FULL_SCALE: f32 = 1500.0;
+/// How quickly the shown speed catches up with the ball, per second.
+const SMOOTHING: f32 = 8.0;
+const METER_WIDTH: f32 = 120.0;
+const METER_BACKGROUND: Color = Color::srgba(0.1, 0.1, 0.1, 0.7);
+const SLOW_COLOUR: Color = Color::srgb(0.9, 0.9, 0.9);
+const FAST_COLOUR: Color = Color::srgb(0.95, 0.3, 0.2);
+
+/// What the speed meter reads in, or whether it is there at all.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum SpeedUnits {
+    #[default]
+    Off,
+    PixelsPerSecond,
+    KilometresPerHour,
+    MilesPerHour,
+}
+
+impl SpeedUnits {
+    const ALL: [SpeedUnits; 4] = [
+        SpeedUnits::Off,
+        SpeedUnits::PixelsPerSecond,
+        SpeedUnits::KilometresPerHour,
+        SpeedUnits::MilesPerHour,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            SpeedUnits::Off => "setting-off",
+            SpeedUnits::PixelsPerSecond => "unit-px-per-second",
+            SpeedUnits::KilometresPerHour => "unit-kmh",
+            SpeedUnits::MilesPerHour => "unit-mph",
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|u| *u == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+
+    /// `speed` in pixels per second, in these units.
+    fn convert(self, speed: f32, arena: &Arena) -> f32 {
+        let metres_per_second = speed / arena.width * COURT_METRES;
+        match self {
+            SpeedUnits::Off | SpeedUnits::PixelsPerSecond => speed,
+            SpeedUnits::KilometresPerHour => metres_per_second * 3.6,
+            SpeedUnits::MilesPerHour => metres_per_second * 2.237,
+        }
+    }
+}
+
+/// The meter, with the smoothed speed it shows.
+#[derive(Component, Default)]
+struct Speedometer {
+    speed: f32,
+}
+
+#[derive(Component)]
+struct SpeedBar;
+
+#[derive(Component)]
+struct SpeedReadout;
+
+/// A gauge in the top-left corner showing how fast the ball is going, as a bar and a
+/// number. Off by default; the setting picks the units.
+pub struct SpeedometerPlugin;
+
+impl Plugin for SpeedometerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-speed-meter",
+            value: |s, l| l.text(s.speed_meter.name()),
+            change: |s, step| s.speed_meter = s.speed_meter.cycle(step),
+        });
+        app.add_systems(Startup, spawn_speedometer);
+        app.add_systems(Update, update_speedometer);
+    }
+}
+
+fn spawn_speedometer(mut commands: Commands, fonts: Res<FontStack>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(12.0),
+                    left: Val::Px(12.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..Default::default()
+                },
+                visibility: Visibility::Hidden,
+                ..Default::default()
+            },
+            Speedometer::default(),
+            HudWidget,
+        ))
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 18.), SpeedReadout));
+            p.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(METER_WIDTH),
+                    height: Val::Px(6.0),
+                    ..Default::default()
+                },
+                background_color: METER_BACKGROUND.into(),
+                ..Default::default()
+            })
+            .with_children(|p| {
+                p.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    SpeedBar,
+                ));
+            });
+        });
+}
+
+/// Follows the fastest ball, eased so bounces don't make the needle jump.
+#[allow(clippy::too_many_arguments)]
+fn update_speedometer(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    arena: Res<Arena>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    balls: Query<&Velocity, With<Ball>>,
+    mut meters: Query<(&mut Speedometer, &mut Visibility)>,
+    mut bars: Query<(&mut Style, &mut BackgroundColor), With<SpeedBar>>,
+    mut readouts: Query<&mut Text, With<SpeedReadout>>,
+) {
+    let Ok((mut meter, mut visibility)) = meters.get_single_mut() else {
+        return;
+    };
+    let units = settings.speed_meter;
+    if units == SpeedUnits::Off {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    }
+    visibility.set_if_neq(Visibility::Inherited);
+
+    let target = balls
+        .iter()
+        .map(|velocity| velocity.linvel.length())
+        .fold(0.0, f32::max);
+    let blend = 1.0 - (-SMOOTHING * time.delta_seconds()).exp();
+    meter.speed += (target - meter.speed) * blend;
+
+    let fill = (meter.speed / FULL_SCALE).clamp(0.0, 1.0);
+    for (mut style, mut background) in &mut bars {
+        style.width = Val::Percent(fill * 100.0);
+        background.0 = SLOW_COLOUR.mix(&FAST_COLOUR, fill);
+    }
+
+    let readout = format!(
+        "{:.0} {}",
+        units.convert(meter.speed, &arena),
+        locale.text(units.name())
+    );
+    for mut text in &mut readouts {
+        let shown: String = text.sections.iter().map(|s| s.value.as_str()).collect();
+        if shown != readout {
+            fonts.set(&mut text, &readout);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+
+use crate::{GameState, fonts::FontStack, locale::LocalizedText};
+
+const LOGO: &str = "bevy.png";
+const FADE_SECONDS: f32 = 0.5;
+const HOLD_SECONDS: f32 = 1.5;
+
+/// Seconds since the splash came up.
+#[derive(Resource, Default)]
+struct Shown(f32);
+
+#[derive(Component)]
+struct SplashScreen;
+
+/// Everything on the splash that fades in and out.
+#[derive(Component)]
+struct Faded;
+
+/// The first thing on screen: the Bevy logo and who made the game, fading in and out
+/// over a few seconds in [`GameState::Splash`] before loading carries on. Any key, click
+/// or tap skips it. Assets load behind it all the while, so it costs no time.
+pub struct SplashPlugin;
+
+impl Plugin for SplashPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Shown>();
+        app.add_systems(Startup, spawn_splash);
+        app.add_systems(
+            Update,
+            (fade_splash, skip_splash).run_if(in_state(GameState::Splash)),
+        );
+        app.add_systems(
+            OnExit(GameState::Splash),
+            |mut commands: Commands, screens: Query<Entity, With<SplashScreen>>| {
+                for screen in &screens {
+                    commands.entity(screen).despawn_recursive();
+                }
+            },
+        );
+    }
+}
+
+fn spawn_splash(mut commands: Commands, asset_server: Res<AssetServer>, fonts: Res<FontStack>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(16.0),
+                    ..Default::default()
+                },
+                background_color: Color::BLACK.into(),
+                z_index: ZIndex::Global(30),
+                ..Default::default()
+            },
+            SplashScreen,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                ImageBundle {
+                    style: Style {
+                        width: Val::Px(128.0),
+                        height: Val::Px(128.0),
+                        ..Default::default()
+                    },
+                    image: UiImage::new(asset_server.load(LOGO))
+                        .with_color(Color::WHITE.with_alpha(0.0)),
+                    ..Default::default()
+                },
+                Faded,
+            ));
+            p.spawn((
+                faded_text(&fonts, 28.0),
+                LocalizedText("splash-engine"),
+                Faded,
+            ));
+            p.spawn((
+                faded_text(&fonts, 20.0),
+                LocalizedText("splash-author"),
+                Faded,
+            ));
+        });
+}
+
+/// Text starts out see-through, so it can't flash up before the first fade.
+fn faded_text(fonts: &FontStack, font_size: f32) -> TextBundle {
+    TextBundle {
+        text: fonts.text(
+            "",
+            TextStyle {
+                font_size,
+                color: Color::WHITE.with_alpha(0.0),
+                ..Default::default()
+            },
+        ),
+        ..Default::default()
+    }
+}
+
+/// How opaque the splash is `seconds` in, and whether it is over.
+fn opacity(seconds: f32) -> Option<f32> {
+    let fade_out = seconds - FADE_SECONDS - HOLD_SECONDS;
+    if fade_out >= FADE_SECONDS {
+        return None;
+    }
+    Some(
+        (seconds / FADE_SECONDS)
+            .min(1.0)
+            .min(1.0 - fade_out / FADE_SECONDS)
+            .max(0.0),
+    )
+}
+
+/// Real time, so nothing else running at startup can hold it up.
+fn fade_splash(
+    time: Res<Time<Real>>,
+    mut shown: ResMut<Shown>,
+    mut next: ResMut<NextState<GameState>>,
+    mut images: Query<&mut UiImage, With<Faded>>,
+    mut texts: Query<&mut Text, With<Faded>>,
+) {
+    shown.0 += time.delta_seconds();
+    let Some(alpha) = opacity(shown.0) else {
+        next.set(GameState::Loading);
+        return;
+    };
+    for mut image in &mut images {
+        image.color.set_alpha(alpha);
+    }
+    for mut text in &mut texts {
+        for section in &mut text.sections {
+            section.style.color.set_alpha(alpha);
+        }
+    }
+}
+
+fn skip_splash(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    if keys.get_just_pressed().next().is_some()
+        || mouse.get_just_pressed().next().is_some()
+        || touches.any_just_pressed()
+    {
+        next.set(GameState::Loading);
+    }
+}
@@ -0,0 +1,211 @@
+use bevy::prelude::*;
+
+use crate::{
+    GameState, Paddle, Player,
+    hud::HudWidget,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    move_paddle,
+    settings::Settings,
+};
+
+const MAX_STAMINA: f32 = 100.0;
+/// Used up per second of moving.
+const MOVE_DRAIN: f32 = 20.0;
+/// Won back per second of standing still.
+const IDLE_REGEN: f32 = 35.0;
+const DASH_COST: f32 = 30.0;
+const DASH_SECONDS: f32 = 0.15;
+const DASH_FACTOR: f32 = 2.5;
+/// A second press of the same key within this long is a dash.
+const DOUBLE_TAP_SECONDS: f32 = 0.25;
+/// How fast an exhausted paddle moves.
+const TIRED_FACTOR: f32 = 0.5;
+const METER_BACKGROUND: Color = Color::srgba(0.1, 0.1, 0.1, 0.7);
+const TIRED_COLOUR: Color = Color::srgb(0.8, 0.25, 0.2);
+
+/// A paddle's stamina, only there while the setting is on.
+#[derive(Component)]
+pub struct Stamina {
+    value: f32,
+    dash: Timer,
+    /// The movement key last pressed, and how long ago.
+    last_tap: Option<(KeyCode, f32)>,
+}
+
+impl Default for Stamina {
+    fn default() -> Self {
+        let mut dash = Timer::from_seconds(DASH_SECONDS, TimerMode::Once);
+        dash.tick(dash.duration());
+        Stamina {
+            value: MAX_STAMINA,
+            dash,
+            last_tap: None,
+        }
+    }
+}
+
+impl Stamina {
+    /// Paddle speed relative to the usual one.
+    pub fn pace(&self) -> f32 {
+        if !self.dash.finished() {
+            DASH_FACTOR
+        } else if self.value <= 0.0 {
+            TIRED_FACTOR
+        } else {
+            1.0
+        }
+    }
+}
+
+/// A player's stamina bar, under their score.
+#[derive(Component)]
+struct StaminaMeter(Player);
+
+/// Optional stamina: moving wears it down and standing still brings it back. Double-tapping
+/// a movement key dashes for a moment at a cost; with none left the paddle crawls.
+pub struct StaminaPlugin;
+
+impl Plugin for StaminaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-stamina",
+            value: |s, l| on_off(l, s.stamina),
+            change: |s, _| s.stamina = !s.stamina,
+        });
+        app.add_systems(Startup, spawn_meters);
+        app.add_systems(
+            Update,
+            (
+                sync_stamina,
+                spend_stamina
+                    .run_if(in_state(GameState::Playing))
+                    .before(move_paddle),
+                draw_meters,
+            )
+                .chain(),
+        );
+    }
+}
+
+fn spawn_meters(mut commands: Commands) {
+    for player in [Player::Player1, Player::Player2] {
+        // The scoreboard takes up the middle 30% at the top, a fifth of the height down.
+        let side = Val::Percent(36.0);
+        let (left, right) = match player {
+            Player::Player1 => (side, Val::Auto),
+            Player::Player2 => (Val::Auto, side),
+        };
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Percent(21.0),
+                        left,
+                        right,
+                        width: Val::Percent(8.0),
+                        height: Val::Px(6.0),
+                        ..Default::default()
+                    },
+                    background_color: METER_BACKGROUND.into(),
+                    visibility: Visibility::Hidden,
+                    ..Default::default()
+                },
+                StaminaMeter(player),
+                HudWidget,
+            ))
+            .with_children(|p| {
+                p.spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+            });
+    }
+}
+
+fn sync_stamina(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    paddles: Query<(Entity, Has<Stamina>), With<Paddle>>,
+) {
+    for (paddle, has_stamina) in &paddles {
+        if settings.stamina && !has_stamina {
+            // Survival may despawn Player 2's paddle this same frame.
+            commands.entity(paddle).try_insert(Stamina::default());
+        } else if !settings.stamina && has_stamina {
+            commands.entity(paddle).remove::<Stamina>();
+        }
+    }
+}
+
+fn spend_stamina(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut paddles: Query<(&Paddle, &mut Stamina)>,
+) {
+    let delta = time.delta_seconds();
+    for (paddle, mut stamina) in &mut paddles {
+        stamina.dash.tick(time.delta());
+        if let Some((_, age)) = &mut stamina.last_tap {
+            *age += delta;
+        }
+
+        for key in [paddle.move_up, paddle.move_down] {
+            if !keys.just_pressed(key) {
+                continue;
+            }
+            let double_tap = stamina
+                .last_tap
+                .is_some_and(|(last, age)| last == key && age < DOUBLE_TAP_SECONDS);
+            if double_tap && stamina.value >= DASH_COST {
+                stamina.value -= DASH_COST;
+                stamina.dash.reset();
+                stamina.last_tap = None;
+            } else {
+                stamina.last_tap = Some((key, 0.0));
+            }
+        }
+
+        let moving = keys.any_pressed([paddle.move_up, paddle.move_down]);
+        stamina.value = if moving {
+            (stamina.value - MOVE_DRAIN * delta).max(0.0)
+        } else {
+            (stamina.value + IDLE_REGEN * delta).min(MAX_STAMINA)
+        };
+    }
+}
+
+/// Bars are in the player's colour, and red once empty.
+fn draw_meters(
+    settings: Res<Settings>,
+    paddles: Query<(&Player, Option<&Stamina>), With<Paddle>>,
+    mut meters: Query<(&StaminaMeter, &Children, &mut Visibility)>,
+    mut fills: Query<(&mut Style, &mut BackgroundColor)>,
+) {
+    for (player, stamina) in &paddles {
+        for (meter, children, mut visibility) in &mut meters {
+            if meter.0 != *player {
+                continue;
+            }
+            let Some(stamina) = stamina else {
+                visibility.set_if_neq(Visibility::Hidden);
+                continue;
+            };
+            visibility.set_if_neq(Visibility::Visible);
+            let colour = if stamina.value > 0.0 {
+                settings.player_colour(*player)
+            } else {
+                TIRED_COLOUR
+            };
+            let mut fill = fills.iter_many_mut(children);
+            while let Some((mut style, mut background)) = fill.fetch_next() {
+                style.width = Val::Percent(stamina.value / MAX_STAMINA * 100.0);
+                background.0 = colour;
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use bevy::{prelude::*, utils::SystemTime};
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::{
+    MatchOver, Player, PointScored, Score,
+    hits::BallHitPaddle,
+    locale::Locale,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    score,
+    settings::Settings,
+    storage::Storage,
+    toast::Toast,
+};
+
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MatchStats>();
+        app.add_setting_row(SettingRow {
+            label: "setting-export-matches",
+            value: |s, l| on_off(l, s.export_match_json),
+            change: |s, _| s.export_match_json = !s.export_match_json,
+        });
+        app.add_systems(
+            Update,
+            (count_paddle_hits, (export_match, reset_stats).chain()),
+        );
+        app.add_systems(PostUpdate, record_points.after(score));
+    }
+}
+
+/// Everything that happened in the current match, kept for the end-of-match report.
+#[derive(Resource, Default, Serialize)]
+pub struct MatchStats {
+    #[serde(skip)]
+    pub started_at: f32,
+    pub paddle_hits: HashMap<Player, u32>,
+    pub longest_rally: u32,
+    #[serde(skip)]
+    pub rally: u32,
+    pub points: Vec<PointRecord>,
+}
+
+/// One entry of the points timeline.
+#[derive(Clone, Serialize)]
+pub struct PointRecord {
+    pub time_secs: f32,
+    pub scorer: Player,
+    pub rally: u32,
+    /// In pixels per second, as it crossed the line.
+    pub ball_speed: f32,
+    pub score: HashMap<Player, i32>,
+}
+
+#[derive(Serialize)]
+struct MatchReport<'a> {
+    winner: Player,
+    duration_secs: f32,
+    #[serde(flatten)]
+    stats: &'a MatchStats,
+}
+
+fn count_paddle_hits(mut hits: EventReader<BallHitPaddle>, mut stats: ResMut<MatchStats>) {
+    for hit in hits.read() {
+        *stats.paddle_hits.entry(hit.player).or_default() += 1;
+        stats.rally += 1;
+        stats.longest_rally = stats.longest_rally.max(stats.rally);
+    }
+}
+
+fn record_points(
+    mut points: EventReader<PointScored>,
+    score: Res<Score>,
+    mut stats: ResMut<MatchStats>,
+) {
+    for point in points.read() {
+        let record = PointRecord {
+            time_secs: point.time_secs - stats.started_at,
+            scorer: point.scorer,
+            rally: point.rally,
+            ball_speed: point.ball_speed,
+            score: score.0.clone(),
+        };
+        stats.points.push(record);
+        stats.rally = 0;
+    }
+}
+
+fn export_match(
+    mut events: EventReader<MatchOver>,
+    settings: Res<Settings>,
+    storage: Res<Storage>,
+    stats: Res<MatchStats>,
+    time: Res<Time>,
+    locale: Res<Locale>,
+    mut toasts: EventWriter<Toast>,
+) {
+    for MatchOver(winner) in events.read() {
+        if !settings.export_match_json {
+            continue;
+        }
+
+        let report = MatchReport {
+            winner: *winner,
+            duration_secs: time.elapsed_seconds() - stats.started_at,
+            stats: &stats,
+        };
+        let key = format!("match-{}", unix_seconds());
+        match storage.try_save(&key, &report) {
+            Ok(()) => {
+                toasts.send(Toast(locale.format(
+                    "toast-match-saved",
+                    &[("path", storage.location(&key).into())],
+                )));
+            }
+            Err(err) => {
+                warn!("failed to export match: {err}");
+                toasts.send(Toast(locale.text("toast-match-export-failed")));
+            }
+        }
+    }
+}
+
+fn unix_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+fn reset_stats(mut events: EventReader<MatchOver>, time: Res<Time>, mut stats: ResMut<MatchStats>) {
+    if events.read().last().is_some() {
+        *stats = MatchStats {
+            started_at: time.elapsed_seconds(),
+            ..Default::default()
+        };
+    }
+}
@@ -0,0 +1,241 @@
+use bevy::prelude::*;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    collections::HashMap,
+    io,
+    sync::{Arc, Mutex},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+const SAVE_DIR: &str = "saves";
+
+/// Somewhere to keep small named blobs (settings, scores, replays) between runs.
+pub trait StorageBackend: Send + Sync + 'static {
+    fn read(&self, key: &str) -> io::Result<Option<String>>;
+    fn write(&self, key: &str, value: &str) -> io::Result<()>;
+
+    /// Where `key` is kept, for telling the player.
+    fn location(&self, key: &str) -> String {
+        key.to_string()
+    }
+}
+
+/// The active storage backend. Everything that persists data goes through this.
+#[derive(Resource, Clone)]
+pub struct Storage(Arc<dyn StorageBackend>);
+
+impl Storage {
+    pub fn new(backend: impl StorageBackend) -> Self {
+        Self(Arc::new(backend))
+    }
+
+    /// Loads and deserializes `key`, logging and returning `None` if it is missing or unreadable.
+    pub fn load<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let raw = match self.0.read(key) {
+            Ok(raw) => raw?,
+            Err(err) => {
+                warn!("failed to read {key}: {err}");
+                return None;
+            }
+        };
+        match serde_json::from_str(&raw) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                warn!("ignoring corrupt {key}: {err}");
+                None
+            }
+        }
+    }
+
+    pub fn save<T: Serialize>(&self, key: &str, value: &T) {
+        if let Err(err) = self.try_save(key, value) {
+            warn!("failed to save {key}: {err}");
+        }
+    }
+
+    /// Like [`Storage::save`], for callers that tell the player when it fails.
+    pub fn try_save<T: Serialize>(&self, key: &str, value: &T) -> io::Result<()> {
+        let raw = serde_json::to_string_pretty(value)?;
+        self.0.write(key, &raw)
+    }
+
+    /// The raw text under `key`, for data that isn't JSON or needs its own error handling.
+    pub fn read_text(&self, key: &str) -> io::Result<Option<String>> {
+        self.0.read(key)
+    }
+
+    pub fn write_text(&self, key: &str, value: &str) -> io::Result<()> {
+        self.0.write(key, value)
+    }
+
+    pub fn location(&self, key: &str) -> String {
+        self.0.location(key)
+    }
+}
+
+/// Files the player names on the command line, such as input recordings and arena layouts,
+/// keyed by their path. Kept apart from [`Storage`] so headless runs, which keep the saves
+/// in memory, still read and write the files they were asked for.
+#[derive(Resource, Clone, Deref)]
+pub struct UserFiles(pub Storage);
+
+impl Default for UserFiles {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn default() -> Self {
+        Self(Storage::new(PathStorage))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn default() -> Self {
+        Self(Storage::new(LocalStorage))
+    }
+}
+
+impl Default for Storage {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn default() -> Self {
+        Self::new(FileStorage::new(save_dir()))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn default() -> Self {
+        Self::new(LocalStorage)
+    }
+}
+
+/// Apps on a phone start somewhere they can't write to, so their saves go in the app's own
+/// data folder.
+#[cfg(target_os = "android")]
+fn save_dir() -> std::path::PathBuf {
+    bevy::winit::ANDROID_APP
+        .get()
+        .and_then(|app| app.internal_data_path())
+        .map_or_else(|| SAVE_DIR.into(), |data| data.join(SAVE_DIR))
+}
+
+#[cfg(target_os = "ios")]
+fn save_dir() -> std::path::PathBuf {
+    std::env::var_os("HOME").map_or_else(
+        || SAVE_DIR.into(),
+        |home| std::path::Path::new(&home).join("Documents").join(SAVE_DIR),
+    )
+}
+
+#[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+fn save_dir() -> std::path::PathBuf {
+    SAVE_DIR.into()
+}
+
+/// One JSON file per key inside a directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStorage {
+    root: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorage {
+    pub fn new(root: impl Into<std::path::PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path(&self, key: &str) -> std::path::PathBuf {
+        self.root.join(format!("{key}.json"))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl StorageBackend for FileStorage {
+    fn read(&self, key: &str) -> io::Result<Option<String>> {
+        match std::fs::read_to_string(self.path(key)) {
+            Ok(raw) => Ok(Some(raw)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn write(&self, key: &str, value: &str) -> io::Result<()> {
+        std::fs::create_dir_all(&self.root)?;
+        std::fs::write(self.path(key), value)
+    }
+
+    fn location(&self, key: &str) -> String {
+        self.path(key).display().to_string()
+    }
+}
+
+/// Each key is a file path, relative to the working directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct PathStorage;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl StorageBackend for PathStorage {
+    fn read(&self, key: &str) -> io::Result<Option<String>> {
+        match std::fs::read_to_string(key) {
+            Ok(raw) => Ok(Some(raw)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn write(&self, key: &str, value: &str) -> io::Result<()> {
+        if let Some(dir) = std::path::Path::new(key).parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(key, value)
+    }
+}
+
+/// The browser's `localStorage`, for the web build.
+#[cfg(target_arch = "wasm32")]
+pub struct LocalStorage;
+
+#[cfg(target_arch = "wasm32")]
+impl LocalStorage {
+    fn storage() -> io::Result<web_sys::Storage> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or_else(|| io::Error::other("localStorage is unavailable"))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl StorageBackend for LocalStorage {
+    fn read(&self, key: &str) -> io::Result<Option<String>> {
+        Self::storage()?
+            .get_item(&format!("pong.{key}"))
+            .map_err(|err| io::Error::other(format!("{err:?}")))
+    }
+
+    fn write(&self, key: &str, value: &str) -> io::Result<()> {
+        Self::storage()?
+            .set_item(&format!("pong.{key}"), value)
+            .map_err(|err| io::Error::other(format!("{err:?}")))
+    }
+}
+
+/// Keeps everything in memory; used by headless runs and tests so nothing touches disk.
+#[derive(Default)]
+pub struct MemoryStorage(Mutex<HashMap<String, String>>);
+
+impl StorageBackend for MemoryStorage {
+    fn read(&self, key: &str) -> io::Result<Option<String>> {
+        Ok(self.0.lock().unwrap().get(key).cloned())
+    }
+
+    fn write(&self, key: &str, value: &str) -> io::Result<()> {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+}
+
+pub struct StoragePlugin;
+
+impl Plugin for StoragePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Storage>();
+        app.init_resource::<UserFiles>();
+    }
+}
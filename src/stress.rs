@@ -0,0 +1,97 @@
+use bevy::{app::AppExit, ecs::system::RunSystemOnce, prelude::*, time::Real};
+use bevy_rapier2d::prelude::*;
+use rand::Rng;
+
+use crate::{Ball, Player, arena::Arena, rng::GameRng, spawn_ball};
+
+/// Frames left out of the percentiles while shaders compile and assets load.
+const WARMUP_FRAMES: usize = 60;
+
+/// `--stress [BALLS]`: fills the court with balls to see how much physics and rendering
+/// headroom there is. The goals become walls, so nothing scores and the balls never stop.
+/// Frame-time percentiles are printed when the window is closed.
+pub struct StressPlugin {
+    pub balls: u32,
+}
+
+#[derive(Resource)]
+struct StressBalls(u32);
+
+#[derive(Resource, Default)]
+struct FrameTimes(Vec<f32>);
+
+impl Plugin for StressPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(StressBalls(self.balls));
+        app.init_resource::<FrameTimes>();
+        app.add_systems(PostStartup, (spawn_stress_balls, close_goals));
+        app.add_systems(Last, (record_frame_time, print_frame_times).chain());
+    }
+}
+
+/// The usual ball is already there; this adds the rest and sends them all off in random
+/// directions from random places.
+fn spawn_stress_balls(world: &mut World) {
+    let count = world.resource::<StressBalls>().0;
+    for _ in 1..count {
+        world.run_system_once(spawn_ball);
+    }
+
+    let arena = *world.resource::<Arena>();
+    let mut rng = world.resource_mut::<GameRng>();
+    let placements: Vec<(Vec2, Vec2)> = (0..count)
+        .map(|_| {
+            let position = Vec2::new(
+                rng.gen_range(-0.8..=0.8) * arena.half_width(),
+                rng.gen_range(-0.8..=0.8) * arena.half_height(),
+            );
+            let velocity = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU))
+                * rng.gen_range(100.0..=400.0);
+            (position, velocity)
+        })
+        .collect();
+
+    let mut balls = world.query_filtered::<(&mut Transform, &mut Velocity), With<Ball>>();
+    for ((mut transform, mut velocity), (position, linvel)) in balls.iter_mut(world).zip(placements)
+    {
+        transform.translation = position.extend(transform.translation.z);
+        velocity.linvel = linvel;
+    }
+}
+
+/// Goal sensors become solid, so balls bounce off them instead of scoring.
+fn close_goals(mut commands: Commands, goals: Query<Entity, (With<Player>, With<Sensor>)>) {
+    for goal in &goals {
+        commands.entity(goal).remove::<Sensor>();
+    }
+}
+
+fn record_frame_time(time: Res<Time<Real>>, mut frames: ResMut<FrameTimes>) {
+    frames.0.push(time.delta_seconds() * 1000.0);
+}
+
+fn print_frame_times(
+    mut exits: EventReader<AppExit>,
+    frames: Res<FrameTimes>,
+    balls: Res<StressBalls>,
+) {
+    if exits.read().last().is_none() {
+        return;
+    }
+    let mut times: Vec<f32> = frames.0.iter().skip(WARMUP_FRAMES).copied().collect();
+    if times.is_empty() {
+        println!("stress: not enough frames to measure");
+        return;
+    }
+    times.sort_by(f32::total_cmp);
+    let percentile = |p: f32| times[((times.len() - 1) as f32 * p).round() as usize];
+    println!(
+        "stress: {} balls, {} frames, frame time p50 {:.2} ms, p90 {:.2} ms, p99 {:.2} ms, max {:.2} ms",
+        balls.0,
+        times.len(),
+        percentile(0.5),
+        percentile(0.9),
+        percentile(0.99),
+        times[times.len() - 1],
+    );
+}
@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    GameEvents, GameState, Paddle, Player, PointScored, ai::GameMode, arena::Arena, locale::Locale,
+    settings::Settings, storage::Storage, toast::Toast,
+};
+
+const BEST_RUN_KEY: &str = "survival-best";
+/// Paddle positions recorded per second of a run. Enough for a smooth ghost, and keeps
+/// the saved run small.
+const TRACK_RATE: f32 = 20.0;
+const GHOST_ALPHA: f32 = 0.3;
+
+/// The longest run so far, with its paddle movement for the ghost.
+#[derive(Resource, Default, Serialize, Deserialize)]
+struct BestRun {
+    seconds: f32,
+    /// Paddle height every `1 / TRACK_RATE` seconds from the serve.
+    track: Vec<f32>,
+}
+
+/// The run in progress. Only counts time spent playing, so pauses and replays don't.
+#[derive(Resource, Default)]
+struct CurrentRun {
+    seconds: f32,
+    track: Vec<f32>,
+}
+
+#[derive(Component)]
+struct GhostPaddle;
+
+/// A run has ended on a miss.
+#[derive(Event)]
+pub struct RunEnded {
+    pub seconds: f32,
+    /// It was the longest run so far.
+    pub best: bool,
+}
+
+/// `--mode survival`: Player 1 alone, against a solid far wall, keeping the ball in play for
+/// as long as they can. Every miss ends a run. The best run is saved and shown as a
+/// translucent ghost paddle to race against.
+pub struct SurvivalPlugin;
+
+impl Plugin for SurvivalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BestRun>();
+        app.init_resource::<CurrentRun>();
+        app.add_event::<RunEnded>();
+        app.add_systems(
+            PostStartup,
+            (load_best_run, close_far_goal, spawn_ghost).run_if(in_survival),
+        );
+        app.add_systems(
+            Update,
+            (
+                remove_second_paddle,
+                (record_run, move_ghost)
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+                .run_if(in_survival),
+        );
+        app.add_systems(
+            PostUpdate,
+            (end_run, announce_runs).chain().run_if(in_survival),
+        );
+    }
+}
+
+fn in_survival(mode: Res<GameMode>) -> bool {
+    *mode == GameMode::Survival
+}
+
+fn load_best_run(storage: Res<Storage>, mut best: ResMut<BestRun>) {
+    if let Some(saved) = storage.load(BEST_RUN_KEY) {
+        *best = saved;
+    }
+}
+
+fn remove_second_paddle(mut commands: Commands, paddles: Query<(Entity, &Player), Added<Paddle>>) {
+    for (entity, player) in &paddles {
+        if *player == Player::Player2 {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// The goal Player 1 would score on becomes a wall for the ball to come back from.
+fn close_far_goal(mut commands: Commands, goals: Query<(Entity, &Player), With<Sensor>>) {
+    for (entity, player) in &goals {
+        if *player == Player::Player1 {
+            commands.entity(entity).remove::<Sensor>();
+        }
+    }
+}
+
+fn spawn_ghost(mut commands: Commands, settings: Res<Settings>, arena: Res<Arena>) {
+    commands.spawn((
+        SpriteBundle {
+            transform: Transform::from_translation(Vec3::new(
+                arena.paddle_x(Player::Player1),
+                0.0,
+                -0.5,
+            )),
+            sprite: Sprite {
+                color: settings
+                    .player_colour(Player::Player1)
+                    .with_alpha(GHOST_ALPHA),
+                custom_size: Some(Vec2::new(10.0, 150.0)),
+                ..Default::default()
+            },
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        },
+        GhostPaddle,
+    ));
+}
+
+fn record_run(
+    time: Res<Time>,
+    paddles: Query<(&Transform, &Player), With<Paddle>>,
+    mut run: ResMut<CurrentRun>,
+) {
+    run.seconds += time.delta_seconds();
+    let due = (run.seconds * TRACK_RATE) as usize + 1;
+    let Some((paddle, _)) = paddles.iter().find(|(_, p)| **p == Player::Player1) else {
+        return;
+    };
+    while run.track.len() < due {
+        run.track.push(paddle.translation.y);
+    }
+}
+
+/// Follows the best run's paddle at the same point in the run. Gone once the run has
+/// lasted longer than the best one.
+fn move_ghost(
+    run: Res<CurrentRun>,
+    best: Res<BestRun>,
+    arena: Res<Arena>,
+    paddles: Query<&Transform, (With<Paddle>, Without<GhostPaddle>)>,
+    mut ghosts: Query<(&mut Transform, &mut Visibility), With<GhostPaddle>>,
+) {
+    let at = run.seconds * TRACK_RATE;
+    let index = at as usize;
+    let y = match (best.track.get(index), best.track.get(index + 1)) {
+        (Some(a), Some(b)) => Some(a + (b - a) * at.fract()),
+        (Some(a), None) if run.seconds <= best.seconds => Some(*a),
+        _ => None,
+    };
+    // Player 1's is the only paddle left; the ghost copies its size assist.
+    let scale = paddles.iter().next().map_or(Vec3::ONE, |t| t.scale);
+    for (mut transform, mut visibility) in &mut ghosts {
+        let Some(y) = y else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        *visibility = Visibility::Visible;
+        transform.translation.x = arena.paddle_x(Player::Player1);
+        transform.translation.y = y;
+        transform.scale = scale;
+    }
+}
+
+/// A miss ends the run; the reset that follows starts the next one.
+fn end_run(
+    mut points: EventReader<PointScored>,
+    mut events: EventReader<GameEvents>,
+    storage: Res<Storage>,
+    mut run: ResMut<CurrentRun>,
+    mut best: ResMut<BestRun>,
+    mut ended: EventWriter<RunEnded>,
+) {
+    for _ in points.read() {
+        let is_best = run.seconds > best.seconds;
+        ended.send(RunEnded {
+            seconds: run.seconds,
+            best: is_best,
+        });
+        if is_best {
+            *best = BestRun {
+                seconds: run.seconds,
+                track: std::mem::take(&mut run.track),
+            };
+            storage.save(BEST_RUN_KEY, &*best);
+        }
+    }
+    if events.read().last().is_some() {
+        *run = CurrentRun::default();
+    }
+}
+
+fn announce_runs(
+    mut ended: EventReader<RunEnded>,
+    locale: Res<Locale>,
+    mut toasts: EventWriter<Toast>,
+) {
+    for run in ended.read() {
+        let id = if run.best {
+            "toast-survival-best"
+        } else {
+            "toast-survival-run"
+        };
+        let seconds = format!("{:.1}", run.seconds);
+        toasts.send(Toast(locale.format(id, &[("seconds", seconds.into())])));
+    }
+}
@@ -0,0 +1,381 @@
+use bevy::{
+    app::AppExit,
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, time::Duration};
+
+use crate::{
+    GameState, PointScored,
+    ai::GameMode,
+    fonts::FontStack,
+    locale::Locale,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    settings::Settings,
+    storage::Storage,
+};
+
+const PENDING_KEY: &str = "telemetry-pending";
+const CURRENT_KEY: &str = "telemetry-current";
+/// Still `true` if the last session never closed properly, so it crashed.
+const SESSION_KEY: &str = "telemetry-session-open";
+const TIMEOUT: Duration = Duration::from_secs(10);
+/// How often the numbers so far are closed off into a batch and sent.
+const BATCH_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// How long to wait after the server couldn't be reached before sending again.
+const RETRY: Duration = Duration::from_secs(60);
+/// Batches shown in full on the viewer.
+const SHOWN: usize = 3;
+
+/// A batch of totals: everything that is sent, and nothing more. There are no names, ids
+/// or addresses in it, and nothing that could tell one player from another. The body of
+/// `POST {url}/telemetry`.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct Report {
+    /// Sessions started in each mode, by its `--mode` name.
+    sessions_by_mode: BTreeMap<String, u32>,
+    /// Sessions that have ended, and how many of them closed normally rather than crashing.
+    sessions_ended: u32,
+    crash_free_sessions: u32,
+    /// Points played, and the paddle hits in their rallies, for the average rally length.
+    points: u32,
+    rally_hits: u32,
+}
+
+impl Report {
+    fn is_empty(&self) -> bool {
+        *self == Report::default()
+    }
+}
+
+#[derive(Resource)]
+struct Telemetry {
+    url: String,
+    agent: ureq::Agent,
+    /// Totals since the last batch was closed off.
+    current: Report,
+    /// Closed batches the server hasn't taken yet, saved until it does.
+    pending: Vec<Report>,
+    /// Batches from the front of `pending` are being sent.
+    sending: bool,
+    /// Whether this session is being counted, which starts with opting in.
+    counting: bool,
+    /// Real time at which `current` is next closed off.
+    batch_at: Duration,
+    /// Real time after which sending may be tried again.
+    retry_at: Duration,
+    /// The viewer is showing, over the pause menu.
+    open: bool,
+}
+
+impl Telemetry {
+    fn save(&self, storage: &Storage) {
+        storage.save(CURRENT_KEY, &self.current);
+        storage.save(PENDING_KEY, &self.pending);
+    }
+
+    fn close_batch(&mut self, storage: &Storage) {
+        if !self.current.is_empty() {
+            self.pending.push(std::mem::take(&mut self.current));
+            self.save(storage);
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct Requests(Vec<Task<Option<usize>>>);
+
+#[derive(Component)]
+struct ViewerRoot;
+
+#[derive(Component)]
+struct ViewerText;
+
+/// `--telemetry URL`: with Share anonymous stats turned on in the settings menu, keeps
+/// running totals of which modes are played, how long rallies last and how many sessions
+/// end without a crash, and sends them to the server every ten minutes. Nothing is kept
+/// or sent until then, and turning it off throws away anything not yet sent. I, while
+/// paused, shows exactly what is waiting to go. Batches the server can't be reached for
+/// are saved and sent later.
+pub struct TelemetryPlugin {
+    /// Base address of the server, such as `https://example.com/pong`.
+    pub url: String,
+}
+
+impl Plugin for TelemetryPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Telemetry {
+            url: self.url.trim_end_matches('/').to_string(),
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+            current: Report::default(),
+            pending: Vec::new(),
+            sending: false,
+            counting: false,
+            batch_at: BATCH_INTERVAL,
+            retry_at: Duration::ZERO,
+            open: false,
+        });
+        app.init_resource::<Requests>();
+        app.add_setting_row(SettingRow {
+            label: "setting-share-stats",
+            value: |s, l| on_off(l, s.share_stats),
+            change: |s, _| s.share_stats = !s.share_stats,
+        });
+        app.add_systems(Startup, (load_reports, spawn_viewer));
+        app.add_systems(
+            Update,
+            (
+                follow_opt_in.run_if(resource_changed::<Settings>),
+                count_points,
+                send_batches,
+                receive_replies,
+                toggle_viewer,
+                draw_viewer,
+            )
+                .chain(),
+        );
+        app.add_systems(
+            OnExit(GameState::Paused),
+            |mut telemetry: ResMut<Telemetry>| {
+                telemetry.open = false;
+            },
+        );
+        app.add_systems(Last, end_session);
+    }
+}
+
+fn load_reports(storage: Res<Storage>, mut telemetry: ResMut<Telemetry>) {
+    if let Some(current) = storage.load(CURRENT_KEY) {
+        telemetry.current = current;
+    }
+    if let Some(pending) = storage.load(PENDING_KEY) {
+        telemetry.pending = pending;
+    }
+}
+
+/// Opting in starts counting this session, and settles how the last counted one ended.
+/// Opting out forgets everything not yet sent.
+fn follow_opt_in(
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    storage: Res<Storage>,
+    mut telemetry: ResMut<Telemetry>,
+) {
+    if settings.share_stats == telemetry.counting {
+        return;
+    }
+    telemetry.counting = settings.share_stats;
+    if !telemetry.counting {
+        telemetry.current = Report::default();
+        telemetry.pending.clear();
+        telemetry.save(&storage);
+        storage.save(SESSION_KEY, &false);
+        return;
+    }
+    if storage.load::<bool>(SESSION_KEY) == Some(true) {
+        telemetry.current.sessions_ended += 1;
+    }
+    if let Some(mode) = mode.to_possible_value() {
+        *telemetry
+            .current
+            .sessions_by_mode
+            .entry(mode.get_name().to_string())
+            .or_default() += 1;
+    }
+    telemetry.save(&storage);
+    storage.save(SESSION_KEY, &true);
+}
+
+fn count_points(mut points: EventReader<PointScored>, mut telemetry: ResMut<Telemetry>) {
+    if !telemetry.counting {
+        points.clear();
+        return;
+    }
+    for point in points.read() {
+        telemetry.current.points += 1;
+        telemetry.current.rally_hits += point.rally;
+    }
+}
+
+/// Closing normally is what makes a session crash-free. What is left goes into a batch,
+/// sent next time.
+fn end_session(
+    mut exits: EventReader<AppExit>,
+    storage: Res<Storage>,
+    mut telemetry: ResMut<Telemetry>,
+) {
+    if exits.read().last().is_none() || !telemetry.counting {
+        return;
+    }
+    telemetry.current.sessions_ended += 1;
+    telemetry.current.crash_free_sessions += 1;
+    telemetry.close_batch(&storage);
+    storage.save(SESSION_KEY, &false);
+}
+
+/// One request at a time, so nothing is sent twice.
+fn send_batches(
+    time: Res<Time<Real>>,
+    storage: Res<Storage>,
+    mut telemetry: ResMut<Telemetry>,
+    mut requests: ResMut<Requests>,
+) {
+    if !telemetry.counting {
+        return;
+    }
+    if time.elapsed() >= telemetry.batch_at {
+        telemetry.batch_at = time.elapsed() + BATCH_INTERVAL;
+        telemetry.close_batch(&storage);
+    }
+    if telemetry.pending.is_empty() || telemetry.sending || time.elapsed() < telemetry.retry_at {
+        return;
+    }
+    telemetry.sending = true;
+    let (agent, url, batches) = (
+        telemetry.agent.clone(),
+        telemetry.url.clone(),
+        telemetry.pending.clone(),
+    );
+    requests.0.push(IoTaskPool::get().spawn(async move {
+        let sent = batches
+            .iter()
+            .take_while(|report| post(&agent, &url, report).is_ok())
+            .count();
+        (sent > 0).then_some(sent)
+    }));
+}
+
+fn post(agent: &ureq::Agent, url: &str, report: &Report) -> Result<(), String> {
+    let body = serde_json::to_string(report).map_err(|err| err.to_string())?;
+    agent
+        .post(&format!("{url}/telemetry"))
+        .set("Content-Type", "application/json")
+        .send_string(&body)
+        .map_err(|err| err.to_string())?;
+    Ok(())
+}
+
+/// A batch sent after opting out and back in again has already been forgotten.
+fn receive_replies(
+    time: Res<Time<Real>>,
+    storage: Res<Storage>,
+    mut requests: ResMut<Requests>,
+    mut telemetry: ResMut<Telemetry>,
+) {
+    let mut finished = Vec::new();
+    requests
+        .0
+        .retain_mut(|task| match block_on(future::poll_once(task)) {
+            Some(reply) => {
+                finished.push(reply);
+                false
+            }
+            None => true,
+        });
+    for reply in finished {
+        telemetry.sending = false;
+        match reply {
+            Some(sent) => {
+                let sent = sent.min(telemetry.pending.len());
+                telemetry.pending.drain(..sent);
+                telemetry.save(&storage);
+            }
+            None => {
+                warn!("couldn't send anonymous stats, trying again later");
+                telemetry.retry_at = time.elapsed() + RETRY;
+            }
+        }
+    }
+}
+
+/// I, while paused, opens or closes the viewer.
+fn toggle_viewer(
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut telemetry: ResMut<Telemetry>,
+) {
+    if *state.get() == GameState::Paused && keys.just_pressed(KeyCode::KeyI) {
+        telemetry.open = !telemetry.open;
+    }
+}
+
+fn spawn_viewer(mut commands: Commands, fonts: Res<FontStack>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.9).into(),
+                // Over the pause menu.
+                z_index: ZIndex::Global(6),
+                visibility: Visibility::Hidden,
+                ..Default::default()
+            },
+            ViewerRoot,
+        ))
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 18.), ViewerText));
+        });
+}
+
+/// The batches are shown as the JSON that goes to the server, word for word.
+fn draw_viewer(
+    telemetry: Res<Telemetry>,
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    mut roots: Query<&mut Visibility, With<ViewerRoot>>,
+    mut texts: Query<&mut Text, With<ViewerText>>,
+) {
+    if !(telemetry.is_changed() || settings.is_changed() || locale.is_changed()) {
+        return;
+    }
+    for mut visibility in &mut roots {
+        visibility.set_if_neq(if telemetry.open {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        });
+    }
+    if !telemetry.open {
+        return;
+    }
+
+    let mut lines = vec![locale.text("telemetry-title"), String::new()];
+    if !settings.share_stats {
+        lines.push(locale.text("telemetry-opt-in"));
+    } else {
+        let batches: Vec<&Report> = telemetry
+            .pending
+            .iter()
+            .chain((!telemetry.current.is_empty()).then_some(&telemetry.current))
+            .collect();
+        if batches.is_empty() {
+            lines.push(locale.text("telemetry-empty"));
+        }
+        for report in batches.iter().take(SHOWN) {
+            lines.push(serde_json::to_string_pretty(report).unwrap_or_default());
+        }
+        if batches.len() > SHOWN {
+            lines.push(locale.format(
+                "telemetry-more",
+                &[("count", (batches.len() - SHOWN).into())],
+            ));
+        }
+    }
+    lines.push(String::new());
+    lines.push(locale.text("telemetry-hint"));
+    for mut text in &mut texts {
+        fonts.set(&mut text, &lines.join("\n"));
+    }
+}
@@ -0,0 +1,346 @@
+use bevy::{
+    ecs::event::ManualEventReader, prelude::*, scene::ScenePlugin, state::app::StatesPlugin,
+    window::ExitCondition,
+};
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    Ball, MatchOver, MatchRules, Paddle, Player, Score, WINNING_SCORE, add_match,
+    arena::Arena,
+    headless,
+    recording::court_checksum,
+    serve::Serving,
+    storage::{MemoryStorage, Storage},
+};
+
+/// Which of a paddle's two keys to press.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+/// The keys each player holds on one frame of a [`simulate`] run.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct FrameInput {
+    pub player1: Option<Direction>,
+    pub player2: Option<Direction>,
+}
+
+/// Plays a match from `seed`, one frame per input, and gives a hash of where it ended up:
+/// the score and the positions of the ball and paddles. Nothing else goes in, so the same
+/// seed and inputs always give the same hash.
+pub fn simulate(seed: u64, inputs: &[FrameInput]) -> u64 {
+    simulate_with(seed, inputs, |_| {})
+}
+
+/// [`simulate`], calling `check` after every frame, for property tests of what must hold
+/// throughout a match.
+pub fn simulate_with(seed: u64, inputs: &[FrameInput], mut check: impl FnMut(&TestGame)) -> u64 {
+    let mut game = TestGame::builder().seed(seed).build();
+    for input in inputs {
+        game.hold(*input);
+        game.step(1);
+        check(&game);
+    }
+    game.state_hash()
+}
+
+pub struct TestGameBuilder {
+    target_score: i32,
+    seed: u64,
+}
+
+impl TestGameBuilder {
+    pub fn target_score(mut self, target_score: i32) -> Self {
+        self.target_score = target_score;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn build(self) -> TestGame {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            HierarchyPlugin,
+            AssetPlugin::default(),
+            ScenePlugin,
+            StatesPlugin,
+            WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                ..Default::default()
+            },
+        ));
+        app.init_asset::<Mesh>();
+        // Keys are pressed by the test rather than read from a keyboard.
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.insert_resource(Storage::new(MemoryStorage::default()));
+        add_match(
+            &mut app,
+            MatchRules {
+                target_score: self.target_score,
+            },
+            Some(self.seed),
+        );
+        headless::fixed_frames(&mut app);
+        app.finish();
+        app.cleanup();
+        // The first frame takes no time, and spawns the court.
+        app.update();
+
+        let world = app.world_mut();
+        let ball = world.query_filtered::<Entity, With<Ball>>().single(world);
+        let paddles = world
+            .query_filtered::<(Entity, &Player), With<Paddle>>()
+            .iter(world)
+            .map(|(entity, player)| (*player, entity))
+            .collect();
+        let goals = world
+            .query_filtered::<(Entity, &Player), With<Sensor>>()
+            .iter(world)
+            .map(|(entity, player)| (*player, entity))
+            .collect();
+        TestGame {
+            app,
+            ball,
+            paddles,
+            goals,
+            match_over: ManualEventReader::default(),
+            collisions: ManualEventReader::default(),
+            winners: Vec::new(),
+            goals_touched: Vec::new(),
+        }
+    }
+}
+
+/// The match on its own, with no window, rendering, sound, menus or AI, for integration
+/// tests of the rules: scoring, serves and resets, and winning. Both paddles are played
+/// by the test through [`press`](TestGame::press) and [`release`](TestGame::release),
+/// and every [`step`](TestGame::step) is a fixed 1/60 s, so a test plays out the same
+/// way every time.
+///
+/// ```no_run
+/// use pong::{Player, testing::{Direction, TestGame}};
+///
+/// let mut game = TestGame::builder().target_score(1).build();
+/// // Player 2 leaves their goal open for the opening serve.
+/// game.press(Player::Player2, Direction::Up);
+/// game.step(900);
+/// assert_eq!(game.winners(), [Player::Player1]);
+/// ```
+pub struct TestGame {
+    app: App,
+    ball: Entity,
+    paddles: Vec<(Player, Entity)>,
+    /// Goal lines, by who scores on them.
+    goals: Vec<(Player, Entity)>,
+    match_over: ManualEventReader<MatchOver>,
+    collisions: ManualEventReader<CollisionEvent>,
+    winners: Vec<Player>,
+    goals_touched: Vec<Player>,
+}
+
+impl Default for TestGame {
+    fn default() -> Self {
+        TestGame::builder().build()
+    }
+}
+
+impl TestGame {
+    /// A first-to-11 match with seed 0, unless told otherwise.
+    pub fn builder() -> TestGameBuilder {
+        TestGameBuilder {
+            target_score: WINNING_SCORE,
+            seed: 0,
+        }
+    }
+
+    /// Holds `player`'s key for `direction` from the next step until it is released.
+    pub fn press(&mut self, player: Player, direction: Direction) {
+        let key = self.key(player, direction);
+        self.app
+            .world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+    }
+
+    pub fn release(&mut self, player: Player, direction: Direction) {
+        let key = self.key(player, direction);
+        self.app
+            .world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(key);
+    }
+
+    /// Holds exactly the keys in `input`, releasing any others.
+    pub fn hold(&mut self, input: FrameInput) {
+        for (player, held) in [
+            (Player::Player1, input.player1),
+            (Player::Player2, input.player2),
+        ] {
+            for direction in [Direction::Up, Direction::Down] {
+                if held == Some(direction) {
+                    self.press(player, direction);
+                } else {
+                    self.release(player, direction);
+                }
+            }
+        }
+    }
+
+    /// Presses and releases Space, which serves again from Player 1.
+    pub fn tap_reset(&mut self) {
+        self.app
+            .world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        self.step(1);
+        self.app
+            .world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::Space);
+    }
+
+    /// Plays `frames` frames.
+    pub fn step(&mut self, frames: u32) {
+        self.goals_touched.clear();
+        for _ in 0..frames {
+            self.app.update();
+            let world = self.app.world();
+            self.winners.extend(
+                self.match_over
+                    .read(world.resource::<Events<MatchOver>>())
+                    .map(|MatchOver(winner)| *winner),
+            );
+            for collision in self
+                .collisions
+                .read(world.resource::<Events<CollisionEvent>>())
+            {
+                let CollisionEvent::Started(a, b, _) = collision else {
+                    continue;
+                };
+                let touched = self.goals.iter().find(|(_, goal)| {
+                    (*a, *b) == (self.ball, *goal) || (*b, *a) == (self.ball, *goal)
+                });
+                if let Some((scorer, _)) = touched {
+                    self.goals_touched.push(*scorer);
+                }
+            }
+            // Keys count as just pressed for one frame, as they do with a keyboard.
+            self.app
+                .world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .clear();
+        }
+    }
+
+    /// Player 1's points, then Player 2's. Back to nothing once a match is won.
+    pub fn score(&self) -> (i32, i32) {
+        let score = &self.app.world().resource::<Score>().0;
+        let points = |player| score.get(&player).copied().unwrap_or(0);
+        (points(Player::Player1), points(Player::Player2))
+    }
+
+    /// Who won each match finished so far, in order.
+    pub fn winners(&self) -> &[Player] {
+        &self.winners
+    }
+
+    /// Who scores on each goal line the ball touched during the last [`step`](Self::step).
+    /// A point follows on the frame after the touch.
+    pub fn goals_touched(&self) -> &[Player] {
+        &self.goals_touched
+    }
+
+    /// Half the width and height of the court.
+    pub fn court_half_size(&self) -> Vec2 {
+        let arena = self.app.world().resource::<Arena>();
+        Vec2::new(arena.half_width(), arena.half_height())
+    }
+
+    /// The same hash [`simulate`] gives, for where the match is now.
+    pub fn state_hash(&self) -> u64 {
+        let world = self.app.world();
+        let tracked = self
+            .paddles
+            .iter()
+            .map(|(_, entity)| *entity)
+            .chain([self.ball])
+            .filter_map(|entity| Some((entity, world.get::<Transform>(entity)?)));
+        court_checksum(world.resource::<Score>(), tracked)
+    }
+
+    pub fn ball(&self) -> Vec2 {
+        self.position(self.ball)
+    }
+
+    pub fn ball_velocity(&self) -> Vec2 {
+        self.app
+            .world()
+            .get::<Velocity>(self.ball)
+            .map_or(Vec2::ZERO, |velocity| velocity.linvel)
+    }
+
+    /// Who the ball is waiting in front of, if it is waiting to be served.
+    pub fn server(&self) -> Option<Player> {
+        self.app
+            .world()
+            .get::<Serving>(self.ball)
+            .map(|serving| serving.server)
+    }
+
+    pub fn paddle(&self, player: Player) -> Vec2 {
+        self.position(self.paddle_entity(player))
+    }
+
+    /// Puts the ball at `position`, heading off at `velocity`, instead of waiting for
+    /// any serve.
+    pub fn throw_ball(&mut self, position: Vec2, velocity: Vec2) {
+        let mut ball = self.app.world_mut().entity_mut(self.ball);
+        ball.remove::<Serving>();
+        ball.insert(Velocity::linear(velocity));
+        if let Some(mut transform) = ball.get_mut::<Transform>() {
+            transform.translation = position.extend(transform.translation.z);
+        }
+    }
+
+    /// The whole app, for anything the helpers don't cover.
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    fn key(&self, player: Player, direction: Direction) -> KeyCode {
+        let paddle = self
+            .app
+            .world()
+            .get::<Paddle>(self.paddle_entity(player))
+            .expect("paddle is gone");
+        match direction {
+            Direction::Up => paddle.move_up,
+            Direction::Down => paddle.move_down,
+        }
+    }
+
+    fn paddle_entity(&self, player: Player) -> Entity {
+        self.paddles
+            .iter()
+            .find(|(owner, _)| *owner == player)
+            .map(|(_, entity)| *entity)
+            .expect("every player has a paddle")
+    }
+
+    fn position(&self, entity: Entity) -> Vec2 {
+        self.app
+            .world()
+            .get::<Transform>(entity)
+            .expect("entity is gone")
+            .translation
+            .truncate()
+    }
+}
@@ -0,0 +1,593 @@
+use bevy::{
+    color::palettes::css::{DARK_GRAY, GREEN, RED, YELLOW},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    MatchOver, Paddle, Player, ScoreBoard,
+    arena::Arena,
+    locale::Locale,
+    menu::{AppSettingsExt, Choice, ChoiceRow, MenuChoices, SettingRow, on_off},
+    settings::Settings,
+    tuning::AppConfigExt,
+};
+
+/// Player colour sets. Everything except `Classic` avoids red/green or blue/yellow pairings.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Palette {
+    #[default]
+    Classic,
+    /// Safe for deuteranopia and protanopia (Okabe-Ito orange and blue).
+    RedGreenSafe,
+    /// Safe for tritanopia (vermillion and sky blue).
+    BlueYellowSafe,
+}
+
+impl Palette {
+    const ALL: [Palette; 3] = [
+        Palette::Classic,
+        Palette::RedGreenSafe,
+        Palette::BlueYellowSafe,
+    ];
+
+    pub fn colour(self, player: Player) -> Color {
+        match (self, player) {
+            (Palette::Classic, Player::Player1) => RED.into(),
+            (Palette::Classic, Player::Player2) => GREEN.into(),
+            (Palette::RedGreenSafe, Player::Player1) => Color::srgb_u8(230, 159, 0),
+            (Palette::RedGreenSafe, Player::Player2) => Color::srgb_u8(0, 114, 178),
+            (Palette::BlueYellowSafe, Player::Player1) => Color::srgb_u8(213, 94, 0),
+            (Palette::BlueYellowSafe, Player::Player2) => Color::srgb_u8(86, 180, 233),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Palette::Classic => "palette-classic",
+            Palette::RedGreenSafe => "palette-red-green-safe",
+            Palette::BlueYellowSafe => "palette-blue-yellow-safe",
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|p| *p == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+}
+
+/// A paddle colour a player can pick instead of the palette's. All stand out on a dark court.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum PaddleColour {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Purple,
+    Pink,
+    White,
+}
+
+impl PaddleColour {
+    const ALL: [PaddleColour; 9] = [
+        PaddleColour::Red,
+        PaddleColour::Orange,
+        PaddleColour::Yellow,
+        PaddleColour::Green,
+        PaddleColour::Cyan,
+        PaddleColour::Blue,
+        PaddleColour::Purple,
+        PaddleColour::Pink,
+        PaddleColour::White,
+    ];
+
+    fn colour(self) -> Color {
+        match self {
+            PaddleColour::Red => Color::srgb(0.9, 0.2, 0.2),
+            PaddleColour::Orange => Color::srgb(1.0, 0.55, 0.1),
+            PaddleColour::Yellow => Color::srgb(1.0, 0.9, 0.2),
+            PaddleColour::Green => Color::srgb(0.2, 0.8, 0.3),
+            PaddleColour::Cyan => Color::srgb(0.2, 0.85, 0.9),
+            PaddleColour::Blue => Color::srgb(0.25, 0.45, 1.0),
+            PaddleColour::Purple => Color::srgb(0.65, 0.35, 0.95),
+            PaddleColour::Pink => Color::srgb(1.0, 0.45, 0.75),
+            PaddleColour::White => Color::srgb(0.95, 0.95, 0.95),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            PaddleColour::Red => "colour-red",
+            PaddleColour::Orange => "colour-orange",
+            PaddleColour::Yellow => "colour-yellow",
+            PaddleColour::Green => "colour-green",
+            PaddleColour::Cyan => "colour-cyan",
+            PaddleColour::Blue => "colour-blue",
+            PaddleColour::Purple => "colour-purple",
+            PaddleColour::Pink => "colour-pink",
+            PaddleColour::White => "colour-white",
+        }
+    }
+}
+
+/// Each player's own paddle colour. `None` takes it from the palette.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddleColours {
+    pub player1: Option<PaddleColour>,
+    pub player2: Option<PaddleColour>,
+}
+
+impl PaddleColours {
+    fn get(&self, player: Player) -> Option<PaddleColour> {
+        match player {
+            Player::Player1 => self.player1,
+            Player::Player2 => self.player2,
+        }
+    }
+
+    fn get_mut(&mut self, player: Player) -> &mut Option<PaddleColour> {
+        match player {
+            Player::Player1 => &mut self.player1,
+            Player::Player2 => &mut self.player2,
+        }
+    }
+}
+
+/// Minimum WCAG contrast ratio between a paddle and the background, as for UI graphics.
+const MIN_BACKGROUND_CONTRAST: f32 = 3.0;
+/// Minimum distance in Oklab between the two paddles, or a paddle and a chroma key, so
+/// they differ in hue even when they are equally bright.
+const MIN_COLOUR_DISTANCE: f32 = 0.2;
+
+fn contrast(a: Color, b: Color) -> f32 {
+    let luminance = |colour: Color| {
+        let linear = colour.to_linear();
+        0.2126 * linear.red + 0.7152 * linear.green + 0.0722 * linear.blue
+    };
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+fn distinct(a: Color, b: Color) -> bool {
+    let (a, b) = (Oklaba::from(a), Oklaba::from(b));
+    Vec3::new(a.lightness - b.lightness, a.a - b.a, a.b - b.b).length() >= MIN_COLOUR_DISTANCE
+}
+
+/// The next colour for `player` that is easy to see on the court and easy to tell from the
+/// opponent's, going through the palette's own colour on the way round. Every shipped
+/// theme is dark, so the default background stands in for them.
+fn cycle_paddle_colour(settings: &mut Settings, player: Player, step: i32) {
+    let opponent = settings.player_colour(player.opponent());
+    let keyed = settings.chroma_key.colour();
+    let background = keyed.unwrap_or(ThemeConfig::default().background);
+    let usable = |choice: &Option<PaddleColour>| match choice {
+        None => true,
+        Some(choice) => {
+            let colour = choice.colour();
+            distinct(colour, opponent)
+                && contrast(colour, background) >= MIN_BACKGROUND_CONTRAST
+                && keyed.is_none_or(|key| distinct(colour, key))
+        }
+    };
+    let all: Vec<Option<PaddleColour>> = std::iter::once(None)
+        .chain(PaddleColour::ALL.map(Some))
+        .collect();
+    let current = settings.paddle_colours.get(player);
+    let mut index = all.iter().position(|c| *c == current).unwrap_or(0) as i32;
+    for _ in 0..all.len() {
+        index = (index + step.signum()).rem_euclid(all.len() as i32);
+        if usable(&all[index as usize]) {
+            break;
+        }
+    }
+    *settings.paddle_colours.get_mut(player) = all[index as usize];
+}
+
+fn paddle_colour_name(settings: &Settings, player: Player, locale: &Locale) -> String {
+    locale.text(
+        settings
+            .paddle_colours
+            .get(player)
+            .map_or("colour-palette", PaddleColour::name),
+    )
+}
+
+/// A flat background for keying the game out in streaming software such as OBS.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ChromaKey {
+    #[default]
+    Off,
+    Green,
+    Magenta,
+}
+
+impl ChromaKey {
+    const ALL: [ChromaKey; 3] = [ChromaKey::Off, ChromaKey::Green, ChromaKey::Magenta];
+
+    /// The background to key out, if any. Pure colours, so no paddle or ball matches them.
+    pub fn colour(self) -> Option<Color> {
+        match self {
+            ChromaKey::Off => None,
+            ChromaKey::Green => Some(Color::srgb(0.0, 1.0, 0.0)),
+            ChromaKey::Magenta => Some(Color::srgb(1.0, 0.0, 1.0)),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ChromaKey::Off => "chroma-key-off",
+            ChromaKey::Green => "chroma-key-green",
+            ChromaKey::Magenta => "chroma-key-magenta",
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|c| *c == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+}
+
+const HIGH_CONTRAST_LINE_WIDTH: f32 = 6.0;
+const THEME_PACKS: &str = "theme-packs";
+const DEFAULT_THEME_PACK: &str = "classic";
+
+/// The arena look in use: the theme pack picked for this match, which modes like the
+/// campaign may recolour. High contrast overrides the colours and wall sprites.
+#[derive(Resource, Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub background: Color,
+    pub court_lines: Color,
+    pub court_line_width: f32,
+    pub scoreboard: Color,
+    /// Drawn along the top and bottom walls instead of a line, tinted with `court_lines`.
+    /// Relative to `assets`.
+    pub wall_image: Option<String>,
+    pub wall_thickness: f32,
+    /// Looped for as long as the pack is in use. Relative to `assets`.
+    pub music: Option<String>,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        ThemeConfig {
+            background: ClearColor::default().0,
+            court_lines: Color::srgba(1.0, 1.0, 1.0, 0.3),
+            court_line_width: 2.0,
+            scoreboard: DARK_GRAY.into(),
+            wall_image: None,
+            wall_thickness: 16.0,
+            music: None,
+        }
+    }
+}
+
+/// A named arena look from the theme manifest.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ThemePack {
+    pub id: String,
+    pub name: String,
+    #[serde(flatten)]
+    pub theme: ThemeConfig,
+}
+
+/// Every theme pack, read from `assets/themes/default.themes.json`.
+#[derive(Asset, Resource, TypePath, Clone, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct ThemePacks(pub Vec<ThemePack>);
+
+/// Chosen theme pack id.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ArenaTheme(pub String);
+
+impl Default for ArenaTheme {
+    fn default() -> Self {
+        ArenaTheme(DEFAULT_THEME_PACK.into())
+    }
+}
+
+impl Settings {
+    /// The colour a player's paddle, ball hits and cues use: their own pick, or else the
+    /// palette's. High contrast overrides both.
+    pub fn player_colour(&self, player: Player) -> Color {
+        if !self.high_contrast {
+            return self
+                .paddle_colours
+                .get(player)
+                .map_or(self.palette.colour(player), PaddleColour::colour);
+        }
+        match player {
+            Player::Player1 => Color::WHITE,
+            Player::Player2 => YELLOW.into(),
+        }
+    }
+}
+
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-palette",
+            value: |s, l| l.text(s.palette.name()),
+            change: |s, step| s.palette = s.palette.cycle(step),
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-colour-p1",
+            value: |s, l| paddle_colour_name(s, Player::Player1, l),
+            change: |s, step| cycle_paddle_colour(s, Player::Player1, step),
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-colour-p2",
+            value: |s, l| paddle_colour_name(s, Player::Player2, l),
+            change: |s, step| cycle_paddle_colour(s, Player::Player2, step),
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-paddle-patterns",
+            value: |s, l| on_off(l, s.paddle_patterns),
+            change: |s, _| s.paddle_patterns = !s.paddle_patterns,
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-high-contrast",
+            value: |s, l| on_off(l, s.high_contrast),
+            change: |s, _| s.high_contrast = !s.high_contrast,
+        });
+        app.add_setting_row(SettingRow {
+            label: "setting-chroma-key",
+            value: |s, l| l.text(s.chroma_key.name()),
+            change: |s, step| s.chroma_key = s.chroma_key.cycle(step),
+        });
+        app.add_choice_row(ChoiceRow {
+            label: "setting-arena-theme",
+            list: THEME_PACKS,
+            selected: |s| &s.arena_theme.0,
+            select: |s, id| s.arena_theme = ArenaTheme(id.to_string()),
+        });
+        app.init_resource::<ThemeConfig>();
+        app.add_config::<ThemePacks>("themes/default.themes.json");
+        app.add_systems(Startup, spawn_court_lines);
+        app.add_systems(
+            Update,
+            (apply_palette, apply_paddle_patterns).run_if(resource_changed::<Settings>),
+        );
+        app.add_systems(
+            Update,
+            (
+                list_theme_packs.run_if(resource_changed::<ThemePacks>),
+                apply_theme_pack,
+                play_theme_music.run_if(resource_changed::<ThemeConfig>),
+            )
+                .chain(),
+        );
+        app.add_systems(
+            Update,
+            apply_contrast.run_if(
+                resource_changed::<Settings>
+                    .or_else(resource_changed::<Arena>)
+                    .or_else(resource_changed::<ThemeConfig>),
+            ),
+        );
+    }
+}
+
+/// Notches drawn across a paddle so the two sides differ by shape as well as colour.
+#[derive(Component)]
+struct PatternMark;
+
+/// Visible markings for the walls and the half-way line.
+#[derive(Component)]
+struct CourtLine {
+    horizontal: bool,
+}
+
+/// The looping music of the theme pack in use, with its path.
+#[derive(Component)]
+pub struct ThemeMusic(String);
+
+/// Lines are sized and placed by `apply_contrast`, which also follows the arena.
+fn spawn_court_lines(mut commands: Commands) {
+    let lines = [(1.0, true), (-1.0, true), (0.0, false)];
+    for (side, horizontal) in lines {
+        commands.spawn((
+            SpriteBundle {
+                transform: Transform::from_translation(Vec3::new(0.0, side, -1.0)),
+                ..Default::default()
+            },
+            CourtLine { horizontal },
+        ));
+    }
+}
+
+fn list_theme_packs(packs: Res<ThemePacks>, mut choices: ResMut<MenuChoices>) {
+    let list = packs
+        .0
+        .iter()
+        .map(|pack| Choice {
+            id: pack.id.clone(),
+            name: pack.name.clone(),
+        })
+        .collect();
+    choices.0.insert(THEME_PACKS, list);
+}
+
+/// Picking a pack in the menu takes effect when the next match starts, so a match never
+/// changes look halfway through. The first match uses it as soon as the manifest loads.
+fn apply_theme_pack(
+    mut events: EventReader<MatchOver>,
+    packs: Res<ThemePacks>,
+    settings: Res<Settings>,
+    mut theme: ResMut<ThemeConfig>,
+) {
+    let match_over = events.read().last().is_some();
+    if !match_over && !packs.is_changed() {
+        return;
+    }
+    let find = |id: &str| packs.0.iter().find(|pack| pack.id == id);
+    let pack = find(&settings.arena_theme.0)
+        .or_else(|| find(DEFAULT_THEME_PACK))
+        .or(packs.0.first());
+    if let Some(pack) = pack {
+        *theme = pack.theme.clone();
+    }
+}
+
+/// Keeps playing across matches while the track stays the same.
+fn play_theme_music(
+    mut commands: Commands,
+    theme: Res<ThemeConfig>,
+    asset_server: Res<AssetServer>,
+    playing: Query<(Entity, &ThemeMusic)>,
+) {
+    if playing
+        .iter()
+        .any(|(_, music)| Some(&music.0) == theme.music.as_ref())
+    {
+        return;
+    }
+    for (entity, _) in &playing {
+        commands.entity(entity).despawn();
+    }
+    if let Some(music) = &theme.music {
+        commands.spawn((
+            AudioBundle {
+                source: asset_server.load(music.clone()),
+                settings: PlaybackSettings::LOOP,
+            },
+            ThemeMusic(music.clone()),
+        ));
+    }
+}
+
+fn apply_palette(
+    settings: Res<Settings>,
+    mut paddles: Query<(&mut Sprite, &Player), With<Paddle>>,
+) {
+    for (mut sprite, player) in &mut paddles {
+        sprite.color = settings.player_colour(*player);
+    }
+}
+
+type CourtLineQuery<'a> = (
+    Entity,
+    &'a mut Sprite,
+    &'a mut Transform,
+    &'a mut Handle<Image>,
+    &'a mut Visibility,
+    &'a CourtLine,
+);
+
+#[allow(clippy::too_many_arguments)]
+fn apply_contrast(
+    settings: Res<Settings>,
+    arena: Res<Arena>,
+    theme: Res<ThemeConfig>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+    mut clear_colour: ResMut<ClearColor>,
+    mut lines: Query<CourtLineQuery>,
+    mut boards: Query<&mut BackgroundColor, With<ScoreBoard>>,
+) {
+    let (width, line_colour) = if settings.high_contrast {
+        (HIGH_CONTRAST_LINE_WIDTH, Color::WHITE)
+    } else {
+        (theme.court_line_width, theme.court_lines)
+    };
+
+    // A keyed background leaves only the gameplay, so the court lines go too.
+    let keyed = settings.chroma_key.colour();
+    *clear_colour = if let Some(key) = keyed {
+        ClearColor(key)
+    } else if settings.high_contrast {
+        ClearColor(Color::BLACK)
+    } else {
+        ClearColor(theme.background)
+    };
+    let shown = if keyed.is_some() {
+        Visibility::Hidden
+    } else {
+        Visibility::Inherited
+    };
+
+    let wall_image = theme
+        .wall_image
+        .as_ref()
+        .filter(|_| !settings.high_contrast);
+
+    for (entity, mut sprite, mut transform, mut texture, mut visibility, line) in &mut lines {
+        visibility.set_if_neq(shown);
+        sprite.color = line_colour;
+        // Walls sit on the window edge, so only half of a horizontal line is visible.
+        sprite.custom_size = Some(match (line.horizontal, wall_image) {
+            (true, Some(_)) => Vec2::new(arena.width, theme.wall_thickness * 2.0),
+            (true, None) => Vec2::new(arena.width, width * 2.0),
+            (false, _) => Vec2::new(width, arena.height),
+        });
+        // Wall images repeat along the wall at their own size rather than stretching.
+        match wall_image.filter(|_| line.horizontal) {
+            Some(image) => {
+                *texture = asset_server.load(image.clone());
+                commands.entity(entity).insert(ImageScaleMode::Tiled {
+                    tile_x: true,
+                    tile_y: false,
+                    stretch_value: 1.0,
+                });
+            }
+            None => {
+                *texture = Handle::default();
+                commands.entity(entity).remove::<ImageScaleMode>();
+            }
+        }
+        transform.translation.y = arena.half_height() * transform.translation.y.signum();
+    }
+
+    for mut background in &mut boards {
+        background.0 = if settings.minimal_hud || keyed.is_some() {
+            Color::NONE
+        } else if settings.high_contrast {
+            Color::BLACK
+        } else {
+            theme.scoreboard
+        };
+    }
+}
+
+fn apply_paddle_patterns(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    paddles: Query<(Entity, &Player), With<Paddle>>,
+    marks: Query<Entity, With<PatternMark>>,
+) {
+    for mark in &marks {
+        commands.entity(mark).despawn_recursive();
+    }
+    if !settings.paddle_patterns {
+        return;
+    }
+
+    for (paddle, player) in &paddles {
+        // Player 1 stays solid; Player 2 gets three dark notches.
+        if *player != Player::Player2 {
+            continue;
+        }
+        commands.entity(paddle).with_children(|p| {
+            for y in [-45.0, 0.0, 45.0] {
+                p.spawn((
+                    SpriteBundle {
+                        transform: Transform::from_translation(Vec3::new(0.0, y, 0.1)),
+                        sprite: Sprite {
+                            color: Color::BLACK,
+                            custom_size: Some(Vec2::new(10.0, 8.0)),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    PatternMark,
+                ));
+            }
+        });
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use crate::{GameState, Paddle, Player, ai::AiPaddle, settings::Settings};
+
+/// The furthest a paddle turns either way, 30 degrees.
+pub const MAX_TILT: f32 = std::f32::consts::FRAC_PI_6;
+/// Radians per second, both when tilting and when springing back.
+const TILT_SPEED: f32 = 3.0;
+
+/// How far a paddle's face is turned: positive faces up, negative faces down.
+#[derive(Component, Default)]
+pub struct Tilt(pub f32);
+
+impl Tilt {
+    /// The direction the paddle's face points, for a paddle at `paddle_x`.
+    pub fn normal(&self, paddle_x: f32) -> Vec2 {
+        let away = -paddle_x.signum();
+        Vec2::new(away * self.0.cos(), self.0.sin())
+    }
+}
+
+/// Holding a tilt key turns the paddle's face up or down, and it springs back straight when
+/// let go. The collider turns with the sprite, so the ball comes off at an angle; smashes
+/// leave along the face too.
+pub struct TiltPlugin;
+
+impl Plugin for TiltPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (add_tilt, tilt_paddles.run_if(in_state(GameState::Playing))).chain(),
+        );
+    }
+}
+
+fn add_tilt(mut commands: Commands, paddles: Query<Entity, Added<Paddle>>) {
+    for paddle in &paddles {
+        // Survival mode may have already despawned this paddle.
+        commands.entity(paddle).try_insert(Tilt::default());
+    }
+}
+
+fn tilt_paddles(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    mut paddles: Query<(&mut Transform, &Player, &mut Tilt), Without<AiPaddle>>,
+) {
+    for (mut transform, player, mut tilt) in &mut paddles {
+        let [up, down] = settings.bindings.tilt(*player);
+        let target = match (keys.pressed(up), keys.pressed(down)) {
+            (true, false) => MAX_TILT,
+            (false, true) => -MAX_TILT,
+            _ => 0.0,
+        };
+        let step = TILT_SPEED * time.delta_seconds();
+        tilt.0 += (target - tilt.0).clamp(-step, step);
+        // Player 2 faces the other way, so the same tilt turns it the other way round.
+        let facing = -transform.translation.x.signum();
+        transform.rotation = Quat::from_rotation_z(tilt.0 * facing);
+    }
+}
@@ -0,0 +1,83 @@
+use bevy::{color::palettes::css::DARK_GRAY, prelude::*};
+
+use crate::fonts::FontStack;
+
+const TOAST_SECONDS: f32 = 3.0;
+
+/// Short on-screen notification shown at the bottom of the window.
+#[derive(Event)]
+pub struct Toast(pub String);
+
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Toast>();
+        app.add_systems(Startup, spawn_toast_root);
+        app.add_systems(Update, (show_toasts, expire_toasts));
+    }
+}
+
+#[derive(Component)]
+struct ToastRoot;
+
+#[derive(Component)]
+struct ToastTimer(Timer);
+
+fn spawn_toast_root(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(20.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            z_index: ZIndex::Global(10),
+            ..Default::default()
+        },
+        ToastRoot,
+    ));
+}
+
+fn show_toasts(
+    mut commands: Commands,
+    mut toasts: EventReader<Toast>,
+    root: Query<Entity, With<ToastRoot>>,
+    fonts: Res<FontStack>,
+) {
+    let Ok(root) = root.get_single() else {
+        return;
+    };
+
+    for Toast(message) in toasts.read() {
+        info!("{message}");
+        commands.entity(root).with_children(|p| {
+            p.spawn((
+                fonts
+                    .text_bundle(message, 24.)
+                    .with_style(Style {
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                        ..Default::default()
+                    })
+                    .with_background_color(DARK_GRAY.into()),
+                ToastTimer(Timer::from_seconds(TOAST_SECONDS, TimerMode::Once)),
+            ));
+        });
+    }
+}
+
+fn expire_toasts(
+    mut commands: Commands,
+    mut toasts: Query<(Entity, &mut ToastTimer)>,
+    time: Res<Time<Real>>,
+) {
+    for (entity, mut timer) in &mut toasts {
+        if timer.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,408 @@
+use bevy::{
+    input::{
+        ButtonState, InputSystem,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    GameEvents, GameState, MatchOver, Player, fonts::FontStack, locale::Locale,
+    narration::Announcement, storage::Storage, toast::Toast,
+};
+
+const TOURNAMENT_KEY: &str = "tournament";
+const MIN_ENTRANTS: usize = 4;
+const MAX_ENTRANTS: usize = 8;
+const MAX_NAME_CHARS: usize = 16;
+
+/// One match of the bracket. An empty slot is a bye, which the other entrant wins.
+#[derive(Clone, Serialize, Deserialize)]
+struct Pairing {
+    entrants: [Option<usize>; 2],
+    winner: Option<usize>,
+}
+
+/// A single-elimination bracket, saved after every change so it survives the game closing.
+#[derive(Resource, Default, Serialize, Deserialize)]
+struct Tournament {
+    names: Vec<String>,
+    /// Every round drawn so far, first round first. Entrants are indices into `names`.
+    rounds: Vec<Vec<Pairing>>,
+}
+
+impl Tournament {
+    fn started(&self) -> bool {
+        !self.rounds.is_empty()
+    }
+
+    /// Draws the first round. Short of a power of two, the earliest entrants get byes.
+    fn start(&mut self) {
+        let size = self.names.len().next_power_of_two();
+        let first = (0..size / 2)
+            .map(|i| {
+                let entrant = |n: usize| (n < self.names.len()).then_some(n);
+                Pairing {
+                    entrants: [entrant(i), entrant(size - 1 - i)],
+                    winner: None,
+                }
+            })
+            .collect();
+        self.rounds = vec![first];
+        self.advance();
+    }
+
+    /// The first undecided match: its round and index.
+    fn next_match(&self) -> Option<(usize, usize)> {
+        let round = self.rounds.len().checked_sub(1)?;
+        let index = self.rounds[round].iter().position(|p| p.winner.is_none())?;
+        Some((round, index))
+    }
+
+    fn record(&mut self, winner: Player) {
+        let Some((round, index)) = self.next_match() else {
+            return;
+        };
+        let pairing = &mut self.rounds[round][index];
+        pairing.winner = match winner {
+            Player::Player1 => pairing.entrants[0],
+            Player::Player2 => pairing.entrants[1],
+        };
+        self.advance();
+    }
+
+    /// Settles byes and draws the next round once the current one is over.
+    fn advance(&mut self) {
+        let Some(round) = self.rounds.last_mut() else {
+            return;
+        };
+        for pairing in round.iter_mut() {
+            if let [Some(only), None] | [None, Some(only)] = pairing.entrants {
+                pairing.winner = Some(only);
+            }
+        }
+        if round.len() < 2 || round.iter().any(|p| p.winner.is_none()) {
+            return;
+        }
+        let next = round
+            .chunks(2)
+            .map(|pair| Pairing {
+                entrants: [pair[0].winner, pair[1].winner],
+                winner: None,
+            })
+            .collect();
+        self.rounds.push(next);
+    }
+
+    fn champion(&self) -> Option<&str> {
+        match self.rounds.last()?.as_slice() {
+            [last] => last.winner.map(|w| self.names[w].as_str()),
+            _ => None,
+        }
+    }
+
+    fn name(&self, entrant: Option<usize>) -> Option<&str> {
+        entrant.map(|e| self.names[e].as_str())
+    }
+}
+
+/// Which tournament screen is up, if any.
+#[derive(Resource, Default, PartialEq, Eq, Clone, Copy)]
+enum Screen {
+    #[default]
+    None,
+    Entry,
+    Bracket,
+    Champion,
+}
+
+#[derive(Resource, Default)]
+struct NameInput(String);
+
+#[derive(Component)]
+struct TournamentRoot;
+
+#[derive(Component)]
+struct TournamentText;
+
+/// `--tournament`: 4 to 8 local players in a single-elimination bracket. Players type their
+/// names, then play their matches in turn, with the bracket shown between them. Resumes
+/// an unfinished tournament.
+pub struct TournamentPlugin;
+
+impl Plugin for TournamentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Tournament>();
+        app.init_resource::<Screen>();
+        app.init_resource::<NameInput>();
+        app.add_systems(Startup, (spawn_screen, open_tournament));
+        app.add_systems(PreUpdate, enter_names.after(InputSystem));
+        app.add_systems(Update, (record_result, handle_enter, draw_screen).chain());
+    }
+}
+
+fn spawn_screen(mut commands: Commands, fonts: Res<FontStack>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.85).into(),
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(5),
+                ..Default::default()
+            },
+            TournamentRoot,
+        ))
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 32.), TournamentText));
+        });
+}
+
+fn open_tournament(
+    storage: Res<Storage>,
+    mut tournament: ResMut<Tournament>,
+    mut screen: ResMut<Screen>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    let unfinished = storage
+        .load::<Tournament>(TOURNAMENT_KEY)
+        .filter(|saved| saved.started() && saved.champion().is_none());
+    if let Some(saved) = unfinished {
+        *tournament = saved;
+    }
+    *screen = if tournament.started() {
+        Screen::Bracket
+    } else {
+        Screen::Entry
+    };
+    next.set(GameState::Intermission);
+}
+
+fn enter_names(
+    mut keys: EventReader<KeyboardInput>,
+    screen: Res<Screen>,
+    mut input: ResMut<NameInput>,
+    tournament: Res<Tournament>,
+) {
+    if *screen != Screen::Entry {
+        keys.clear();
+        return;
+    }
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed {
+            continue;
+        }
+        match &key.logical_key {
+            Key::Backspace => {
+                input.0.pop();
+            }
+            Key::Space if !input.0.is_empty() => input.0.push(' '),
+            Key::Character(text) if tournament.names.len() < MAX_ENTRANTS => {
+                let room = MAX_NAME_CHARS.saturating_sub(input.0.chars().count());
+                input
+                    .0
+                    .extend(text.chars().filter(|c| !c.is_control()).take(room));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Enter adds the typed name, or starts the tournament on an empty line; between matches
+/// it starts the next one, and after the final it ends the tournament.
+#[allow(clippy::too_many_arguments)]
+fn handle_enter(
+    keys: Res<ButtonInput<KeyCode>>,
+    storage: Res<Storage>,
+    locale: Res<Locale>,
+    mut screen: ResMut<Screen>,
+    mut input: ResMut<NameInput>,
+    mut tournament: ResMut<Tournament>,
+    mut next: ResMut<NextState<GameState>>,
+    mut game_events: EventWriter<GameEvents>,
+    mut toasts: EventWriter<Toast>,
+) {
+    if !keys.just_pressed(KeyCode::Enter) {
+        return;
+    }
+    match *screen {
+        Screen::None => {}
+        Screen::Entry => {
+            let name = input.0.trim().to_string();
+            input.0.clear();
+            if !name.is_empty() && tournament.names.len() < MAX_ENTRANTS {
+                tournament.names.push(name);
+            } else if name.is_empty() && tournament.names.len() >= MIN_ENTRANTS {
+                tournament.start();
+                storage.save(TOURNAMENT_KEY, &*tournament);
+                *screen = Screen::Bracket;
+            }
+        }
+        Screen::Bracket => {
+            let Some((round, index)) = tournament.next_match() else {
+                return;
+            };
+            let [a, b] = tournament.rounds[round][index].entrants;
+            toasts.send(Toast(locale.format(
+                "tournament-versus",
+                &[
+                    (
+                        "left",
+                        tournament.name(a).unwrap_or_default().to_string().into(),
+                    ),
+                    (
+                        "right",
+                        tournament.name(b).unwrap_or_default().to_string().into(),
+                    ),
+                ],
+            )));
+            *screen = Screen::None;
+            game_events.send(GameEvents::ResetBall(Player::Player1));
+            next.set(GameState::Playing);
+        }
+        Screen::Champion => {
+            // Nothing left to resume.
+            *tournament = Tournament::default();
+            storage.save(TOURNAMENT_KEY, &*tournament);
+            *screen = Screen::Entry;
+        }
+    }
+}
+
+/// Reads the result a frame late, in `Update`, so it overrides any goal replay started for
+/// the winning point.
+fn record_result(
+    mut events: EventReader<MatchOver>,
+    storage: Res<Storage>,
+    locale: Res<Locale>,
+    mut tournament: ResMut<Tournament>,
+    mut screen: ResMut<Screen>,
+    mut next: ResMut<NextState<GameState>>,
+    mut announcements: EventWriter<Announcement>,
+) {
+    let Some(MatchOver(winner)) = events.read().last() else {
+        return;
+    };
+    if *screen != Screen::None || !tournament.started() {
+        return;
+    }
+    tournament.record(*winner);
+    storage.save(TOURNAMENT_KEY, &*tournament);
+    *screen = match tournament.champion() {
+        Some(name) => {
+            announcements.send(Announcement(
+                locale.format("tournament-champion", &[("name", name.to_string().into())]),
+            ));
+            Screen::Champion
+        }
+        None => Screen::Bracket,
+    };
+    next.set(GameState::Intermission);
+}
+
+fn draw_screen(
+    screen: Res<Screen>,
+    input: Res<NameInput>,
+    tournament: Res<Tournament>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    mut roots: Query<&mut Visibility, With<TournamentRoot>>,
+    mut texts: Query<&mut Text, With<TournamentText>>,
+) {
+    if !(screen.is_changed()
+        || input.is_changed()
+        || tournament.is_changed()
+        || locale.is_changed())
+    {
+        return;
+    }
+    for mut visibility in &mut roots {
+        *visibility = if *screen == Screen::None {
+            Visibility::Hidden
+        } else {
+            Visibility::Visible
+        };
+    }
+
+    let mut lines = vec![locale.text("tournament-title"), String::new()];
+    match *screen {
+        Screen::None => {}
+        Screen::Entry => {
+            lines.push(locale.format(
+                "tournament-entry-hint",
+                &[("min", MIN_ENTRANTS.into()), ("max", MAX_ENTRANTS.into())],
+            ));
+            lines.extend(
+                tournament
+                    .names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| format!("{}. {name}", i + 1)),
+            );
+            if tournament.names.len() < MAX_ENTRANTS {
+                lines.push(format!("> {}_", input.0));
+            }
+            if tournament.names.len() >= MIN_ENTRANTS {
+                lines.push(String::new());
+                lines.push(locale.text("tournament-start-hint"));
+            }
+        }
+        Screen::Bracket | Screen::Champion => {
+            let bye = locale.text("tournament-bye");
+            for (number, round) in tournament.rounds.iter().enumerate() {
+                lines.push(if round.len() == 1 {
+                    locale.text("tournament-final")
+                } else {
+                    locale.format("tournament-round", &[("round", (number + 1).into())])
+                });
+                for pairing in round {
+                    let [a, b] = pairing.entrants.map(|e| tournament.name(e).unwrap_or(&bye));
+                    let result = match tournament.name(pairing.winner) {
+                        Some(winner) => format!("  {a} – {b}: {winner}"),
+                        None => format!("  {a} – {b}"),
+                    };
+                    lines.push(result);
+                }
+            }
+            lines.push(String::new());
+            match (tournament.champion(), tournament.next_match()) {
+                (Some(name), _) => {
+                    lines.push(
+                        locale.format("tournament-champion", &[("name", name.to_string().into())]),
+                    );
+                    lines.push(locale.text("tournament-finish-hint"));
+                }
+                (None, Some((round, index))) => {
+                    let [a, b] = tournament.rounds[round][index].entrants;
+                    lines.push(locale.format(
+                        "tournament-next",
+                        &[
+                            (
+                                "left",
+                                tournament.name(a).unwrap_or_default().to_string().into(),
+                            ),
+                            (
+                                "right",
+                                tournament.name(b).unwrap_or_default().to_string().into(),
+                            ),
+                        ],
+                    ));
+                }
+                (None, None) => {}
+            }
+        }
+    }
+    let value = lines.join("\n");
+    for mut text in &mut texts {
+        fonts.set(&mut text, &value);
+    }
+}
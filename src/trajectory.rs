@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    BALL_RADIUS, Ball, GameState, Player,
+    ai::GameMode,
+    arena::Arena,
+    menu::{AppSettingsExt, SettingRow, on_off},
+    mutators::ActiveMutators,
+    serve::Serving,
+    settings::Settings,
+};
+
+/// How far ahead the path is drawn, in seconds.
+const LOOKAHEAD_SECONDS: f32 = 1.5;
+/// Simulation step, and how many steps apart the dots are.
+const STEP_SECONDS: f32 = 1.0 / 120.0;
+const STEPS_PER_DOT: usize = 6;
+const DOT_RADIUS: f32 = 3.0;
+const DOT_COLOUR: Color = Color::srgba(1.0, 1.0, 1.0, 0.45);
+/// A change in velocity smaller than this, in pixels per second, keeps the path as it is.
+const VELOCITY_TOLERANCE: f32 = 1.0;
+
+/// A ball's predicted path: the velocity it was worked out from, and the points along it
+/// with the time the ball should reach each.
+#[derive(Component)]
+struct Prediction {
+    velocity: Vec2,
+    elapsed: f32,
+    dots: Vec<(f32, Vec2)>,
+}
+
+/// A training aid: in practice modes, where nobody else is playing, a dotted line shows
+/// where the ball will go over the next second and a half, bouncing off the walls, so new
+/// players learn to get into position early. It ends at the paddles' line. Gravity is
+/// accounted for; wind, blocks and portals are not, so those still surprise.
+pub struct TrajectoryPlugin;
+
+impl Plugin for TrajectoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_setting_row(SettingRow {
+            label: "setting-trajectory-line",
+            value: |s, l| on_off(l, s.assist.trajectory),
+            change: |s, _| s.assist.trajectory = !s.assist.trajectory,
+        });
+        app.add_systems(
+            Update,
+            (predict, draw_predictions)
+                .chain()
+                .run_if(in_state(GameState::Playing).and_then(shown)),
+        );
+    }
+}
+
+/// Only 1p and survival count as practice, so it never helps one side of a two-player match.
+fn shown(settings: Res<Settings>, mode: Res<GameMode>) -> bool {
+    settings.assist.trajectory && matches!(*mode, GameMode::OnePlayer | GameMode::Survival)
+}
+
+type PredictedBall<'a> = (
+    Entity,
+    &'a Transform,
+    &'a Velocity,
+    Option<&'a mut Prediction>,
+);
+
+/// Works the path out again only when the ball's velocity has changed, such as after a hit,
+/// rather than every frame.
+fn predict(
+    mut commands: Commands,
+    time: Res<Time>,
+    arena: Res<Arena>,
+    mutators: Res<ActiveMutators>,
+    mut balls: Query<PredictedBall, (With<Ball>, Without<Serving>)>,
+) {
+    for (entity, transform, velocity, prediction) in &mut balls {
+        if let Some(mut prediction) = prediction
+            && prediction.velocity.distance(velocity.linvel) < VELOCITY_TOLERANCE
+        {
+            prediction.elapsed += time.delta_seconds();
+            continue;
+        }
+        let radius = BALL_RADIUS * transform.scale.x;
+        let dots = trace(
+            &arena,
+            transform.translation.truncate(),
+            velocity.linvel,
+            radius,
+            mutators.gravity() * arena.scale,
+        );
+        commands.entity(entity).insert(Prediction {
+            velocity: velocity.linvel,
+            elapsed: 0.0,
+            dots,
+        });
+    }
+}
+
+/// Steps the ball forward, reflecting it off the top and bottom walls, until it reaches
+/// either paddle's line or runs out of time.
+fn trace(
+    arena: &Arena,
+    mut position: Vec2,
+    mut velocity: Vec2,
+    radius: f32,
+    gravity: f32,
+) -> Vec<(f32, Vec2)> {
+    let top = (arena.half_height() - radius).max(0.0);
+    let end = arena.paddle_x(Player::Player2).abs();
+    let steps = (LOOKAHEAD_SECONDS / STEP_SECONDS) as usize;
+    let mut dots = Vec::new();
+    for step in 1..=steps {
+        velocity.y -= gravity * STEP_SECONDS;
+        position += velocity * STEP_SECONDS;
+        if position.y.abs() > top {
+            position.y = position.y.signum() * top * 2.0 - position.y;
+            velocity.y = -velocity.y;
+        }
+        if position.x.abs() >= end {
+            break;
+        }
+        if step % STEPS_PER_DOT == 0 {
+            dots.push((step as f32 * STEP_SECONDS, position));
+        }
+    }
+    dots
+}
+
+/// Dots the ball has already passed are dropped, so the line shrinks towards the paddle.
+fn draw_predictions(
+    mut gizmos: Gizmos,
+    predictions: Query<&Prediction, (With<Ball>, Without<Serving>)>,
+) {
+    for prediction in &predictions {
+        for (at, point) in &prediction.dots {
+            if *at > prediction.elapsed {
+                gizmos.circle_2d(*point, DOT_RADIUS, DOT_COLOUR);
+            }
+        }
+    }
+}
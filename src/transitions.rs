@@ -0,0 +1,258 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use crate::{
+    GameState, MatchOver, Player, PointScored, Score,
+    ai::GameMode,
+    check_winner,
+    fonts::FontStack,
+    locale::Locale,
+    motion::MotionPreferences,
+    score,
+    serve::{Serving, launch_serves},
+};
+
+const BANNER_SECONDS: f32 = 1.6;
+const WIPE_SECONDS: f32 = 1.8;
+/// Share of a step spent sliding in, and the same again sliding out.
+const SLIDE_SHARE: f32 = 0.2;
+const BANNER_COLOUR: Color = Color::srgba(0.0, 0.0, 0.0, 0.75);
+const WIPE_COLOUR: Color = Color::srgb(0.05, 0.05, 0.08);
+
+/// One transition: a banner with the score after a point, or a wipe across the court with
+/// a card naming the next match.
+enum Step {
+    Banner(String),
+    Wipe(String),
+}
+
+impl Step {
+    fn seconds(&self) -> f32 {
+        match self {
+            Step::Banner(_) => BANNER_SECONDS,
+            Step::Wipe(_) => WIPE_SECONDS,
+        }
+    }
+
+    fn layer(&self) -> Layer {
+        match self {
+            Step::Banner(_) => Layer::Banner,
+            Step::Wipe(_) => Layer::Wipe,
+        }
+    }
+}
+
+/// Transitions play one after another, each to the end, so a match point's banner is
+/// followed by the wipe into the next match rather than cut off by it.
+#[derive(Resource, Default)]
+struct Sequence {
+    queue: VecDeque<Step>,
+    playing: Option<(Step, Timer)>,
+    /// Matches finished this session, to number the next one.
+    matches: u32,
+}
+
+impl Sequence {
+    /// A newer score makes any banner still waiting out of date.
+    fn push(&mut self, step: Step) {
+        if matches!(step, Step::Banner(_)) {
+            self.queue
+                .retain(|queued| !matches!(queued, Step::Banner(_)));
+        }
+        self.queue.push_back(step);
+    }
+}
+
+/// The node a step slides across the screen.
+#[derive(Component, PartialEq, Eq)]
+enum Layer {
+    Banner,
+    Wipe,
+}
+
+/// The text on a banner or card.
+#[derive(Component)]
+struct Caption;
+
+/// Short animations between points and matches, so the game flows from one to the next
+/// instead of jumping: after each point a banner with the score slides across, and when a
+/// match is won the court is wiped over and a card names the match coming up. They only
+/// run while the game does, and with reduced motion they appear in place instead of
+/// sliding.
+pub struct TransitionsPlugin;
+
+impl Plugin for TransitionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Sequence>();
+        app.add_systems(Startup, spawn_transitions);
+        app.add_systems(
+            PostUpdate,
+            (queue_banners.after(score), queue_wipes.after(check_winner)),
+        );
+        app.add_systems(
+            Update,
+            hold_serves
+                .before(launch_serves)
+                .run_if(in_state(GameState::Playing)),
+        );
+        app.add_systems(
+            Update,
+            play_sequence.run_if(in_state(GameState::Playing).or_else(in_state(GameState::Replay))),
+        );
+    }
+}
+
+fn spawn_transitions(mut commands: Commands, fonts: Res<FontStack>) {
+    let layer = |top| Style {
+        position_type: PositionType::Absolute,
+        top,
+        width: Val::Percent(100.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..Default::default()
+    };
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    height: Val::Percent(100.0),
+                    ..layer(Val::ZERO)
+                },
+                background_color: WIPE_COLOUR.into(),
+                visibility: Visibility::Hidden,
+                // Over the court and the scoreboard, under menus and toasts.
+                z_index: ZIndex::Global(4),
+                ..Default::default()
+            },
+            Layer::Wipe,
+        ))
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 72.0), Caption));
+        });
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    padding: UiRect::axes(Val::Px(32.0), Val::Px(12.0)),
+                    ..layer(Val::Percent(38.0))
+                },
+                background_color: BANNER_COLOUR.into(),
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(4),
+                ..Default::default()
+            },
+            Layer::Banner,
+        ))
+        .with_children(|p| {
+            p.spawn((fonts.text_bundle("", 40.0), Caption));
+        });
+}
+
+/// Survival has its own toasts for misses, so it gets no banners.
+fn queue_banners(
+    mut points: EventReader<PointScored>,
+    mode: Res<GameMode>,
+    score: Res<Score>,
+    locale: Res<Locale>,
+    mut sequence: ResMut<Sequence>,
+) {
+    let Some(point) = points.read().last() else {
+        return;
+    };
+    if *mode == GameMode::Survival {
+        return;
+    }
+    let points = |player| score.0.get(&player).copied().unwrap_or(0);
+    let score = format!("{}–{}", points(Player::Player1), points(Player::Player2));
+    sequence.push(Step::Banner(locale.format(
+        "transition-point",
+        &[
+            ("player", locale.player(point.scorer).into()),
+            ("score", score.into()),
+        ],
+    )));
+}
+
+fn queue_wipes(
+    mut events: EventReader<MatchOver>,
+    locale: Res<Locale>,
+    mut sequence: ResMut<Sequence>,
+) {
+    for _ in events.read() {
+        sequence.matches += 1;
+        let number = sequence.matches + 1;
+        sequence.push(Step::Wipe(
+            locale.format("transition-match", &[("number", number.into())]),
+        ));
+    }
+}
+
+/// Nobody has to play blind: the next serve waits until the wipe has gone.
+fn hold_serves(sequence: Res<Sequence>, mut serving: Query<&mut Serving>) {
+    if !matches!(sequence.playing, Some((Step::Wipe(_), _))) {
+        return;
+    }
+    for mut serving in &mut serving {
+        serving.restart_delay();
+    }
+}
+
+/// Eases in over the first part of a step and out over the last: from -1 (off to the
+/// left) through 0 (in place) to 1 (off to the right).
+fn slide(progress: f32) -> f32 {
+    let ease = |t: f32| t * t * (3.0 - 2.0 * t);
+    if progress < SLIDE_SHARE {
+        ease(progress / SLIDE_SHARE) - 1.0
+    } else if progress > 1.0 - SLIDE_SHARE {
+        ease((progress - (1.0 - SLIDE_SHARE)) / SLIDE_SHARE)
+    } else {
+        0.0
+    }
+}
+
+/// Real time, so slow motion doesn't hold transitions up.
+fn play_sequence(
+    time: Res<Time<Real>>,
+    motion: Res<MotionPreferences>,
+    fonts: Res<FontStack>,
+    mut sequence: ResMut<Sequence>,
+    mut layers: Query<(&Layer, &mut Style, &mut Visibility)>,
+    mut captions: Query<&mut Text, With<Caption>>,
+) {
+    let finished = match &mut sequence.playing {
+        Some((_, timer)) => timer.tick(time.delta()).finished(),
+        None => true,
+    };
+    if finished {
+        sequence.playing = sequence.queue.pop_front().map(|step| {
+            // Only one layer shows at a time, so both captions can say the same.
+            let (Step::Banner(text) | Step::Wipe(text)) = &step;
+            for mut caption in &mut captions {
+                fonts.set(&mut caption, text);
+            }
+            let timer = Timer::from_seconds(step.seconds(), TimerMode::Once);
+            (step, timer)
+        });
+    }
+
+    let playing = sequence
+        .playing
+        .as_ref()
+        .map(|(step, timer)| (step.layer(), timer.fraction()));
+    for (layer, mut style, mut visibility) in &mut layers {
+        let progress = playing
+            .as_ref()
+            .filter(|(playing, _)| playing == layer)
+            .map(|(_, progress)| *progress);
+        visibility.set_if_neq(if progress.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+        let offset = match progress {
+            Some(progress) if !motion.reduced => slide(progress),
+            _ => 0.0,
+        };
+        style.left = Val::Percent(offset * 100.0);
+    }
+}
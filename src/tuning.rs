@@ -0,0 +1,150 @@
+use bevy::{
+    asset::{AssetLoader, AsyncReadExt, LoadContext, io::Reader},
+    prelude::*,
+};
+use bevy_rapier2d::prelude::*;
+use serde::{Deserialize, de::DeserializeOwned};
+use std::marker::PhantomData;
+
+use crate::Ball;
+
+/// Gameplay numbers, read from `assets/config/default.gameplay.json`.
+#[derive(Asset, Resource, TypePath, Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct GameplayConfig {
+    /// Paddle speed in pixels per second.
+    pub paddle_speed: f32,
+    /// Ball speed right after a serve, in pixels per second.
+    pub serve_speed: f32,
+    /// Largest angle off horizontal a serve can take, in radians.
+    pub max_serve_angle: f32,
+    /// Above 1 the ball speeds up with every bounce.
+    pub ball_restitution: f32,
+}
+
+impl Default for GameplayConfig {
+    fn default() -> Self {
+        GameplayConfig {
+            paddle_speed: 100.0,
+            serve_speed: 100.0,
+            max_serve_angle: 0.35,
+            ball_restitution: 1.2,
+        }
+    }
+}
+
+/// A tuning file under `assets/config`. The resource holds the values in use: the
+/// defaults until the file has loaded, then the file's. With `--features dev` the
+/// files are watched, so saving one updates the running game.
+///
+/// Each kind of file gets its own extension (`.gameplay.json`, `.theme.json`, ...), since a
+/// reloaded file is matched to its loader by extension alone.
+pub trait AppConfigExt {
+    fn add_config<T>(&mut self, path: &'static str) -> &mut Self
+    where
+        T: Asset + Resource + Clone + Default + DeserializeOwned;
+}
+
+impl AppConfigExt for App {
+    fn add_config<T>(&mut self, path: &'static str) -> &mut Self
+    where
+        T: Asset + Resource + Clone + Default + DeserializeOwned,
+    {
+        self.init_resource::<T>();
+        self.init_asset::<T>();
+        let file_name = path.rsplit('/').next().unwrap_or(path);
+        let extension = file_name.split_once('.').map_or(file_name, |(_, ext)| ext);
+        self.register_asset_loader(JsonLoader::<T> {
+            extension: [extension],
+            asset: PhantomData,
+        });
+        let handle = self.world().resource::<AssetServer>().load::<T>(path);
+        self.world_mut()
+            .get_resource_or_insert_with(ConfigFiles::default)
+            .0
+            .push(handle.clone().untyped());
+        self.insert_resource(ConfigHandle(handle));
+        self.add_systems(PreUpdate, apply_config::<T>.in_set(ApplyConfig))
+    }
+}
+
+/// Every config file asked for, so the loading screen can wait for them.
+#[derive(Resource, Default)]
+pub struct ConfigFiles(pub Vec<UntypedHandle>);
+
+/// Where tuning files are copied into their resources, for systems that adjust the result.
+#[derive(SystemSet, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ApplyConfig;
+
+/// Keeps the file loaded, and watched.
+#[derive(Resource)]
+struct ConfigHandle<T: Asset>(Handle<T>);
+
+struct JsonLoader<T> {
+    extension: [&'static str; 1],
+    asset: PhantomData<fn() -> T>,
+}
+
+impl<T: Asset + DeserializeOwned> AssetLoader for JsonLoader<T> {
+    type Asset = T;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<T, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &self.extension
+    }
+}
+
+fn apply_config<T: Asset + Resource + Clone>(
+    mut events: EventReader<AssetEvent<T>>,
+    assets: Res<Assets<T>>,
+    handle: Res<ConfigHandle<T>>,
+    mut config: ResMut<T>,
+) {
+    for event in events.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = event
+        else {
+            continue;
+        };
+        if *id != handle.0.id() {
+            continue;
+        }
+        if let Some(loaded) = assets.get(*id) {
+            *config = loaded.clone();
+            info!(
+                "applied {}",
+                handle.0.path().map_or_else(String::new, |p| p.to_string())
+            );
+        }
+    }
+}
+
+pub struct TuningPlugin;
+
+impl Plugin for TuningPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_config::<GameplayConfig>("config/default.gameplay.json");
+        app.add_systems(
+            Update,
+            apply_restitution.run_if(resource_changed::<GameplayConfig>),
+        );
+    }
+}
+
+/// Restitution is set when the ball spawns, so a new value has to be pushed to it.
+fn apply_restitution(config: Res<GameplayConfig>, mut balls: Query<&mut Restitution, With<Ball>>) {
+    for mut restitution in &mut balls {
+        restitution.coefficient = config.ball_restitution;
+    }
+}
@@ -0,0 +1,299 @@
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, BufReader, Write},
+    net::TcpStream,
+    sync::{
+        Mutex,
+        mpsc::{self, Receiver, Sender},
+    },
+    time::Duration,
+};
+
+use crate::{
+    GameState,
+    fonts::FontStack,
+    locale::Locale,
+    mutators::{ActiveMutators, Mutator},
+    rng::GameRng,
+    toast::Toast,
+};
+
+/// Twitch's chat server, without TLS. Reading chat needs no account.
+const IRC_ADDRESS: &str = "irc.chat.twitch.tv:6667";
+/// Anonymous, read-only logins are any `justinfan` name.
+const IRC_NICK: &str = "justinfan31415";
+/// Twitch pings about every five minutes, so a longer silence means the connection is gone.
+const READ_TIMEOUT: Duration = Duration::from_secs(6 * 60);
+const RECONNECT_DELAY: Duration = Duration::from_secs(10);
+/// Seconds of play from one vote closing to the next, and how long each is open for.
+const ROUND_SECONDS: f32 = 180.0;
+const VOTE_SECONDS: f32 = 30.0;
+/// Mutators on the ballot each round.
+const CHOICES: usize = 3;
+
+/// One chat message that was a vote, for the option numbered `choice` from 1.
+struct Vote {
+    user: String,
+    choice: usize,
+}
+
+/// Votes from the chat thread, waiting to be counted.
+#[derive(Resource)]
+struct ChatVotes(Mutex<Receiver<Vote>>);
+
+/// The round's ballot and everyone's latest vote on it.
+#[derive(Resource, Default)]
+struct VoteTally {
+    choices: Vec<Mutator>,
+    votes: HashMap<String, usize>,
+}
+
+impl VoteTally {
+    fn counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.choices.len()];
+        for choice in self.votes.values() {
+            counts[*choice] += 1;
+        }
+        counts
+    }
+
+    /// The most votes wins; a tie goes to the option listed first. No votes, no winner.
+    fn winner(&self) -> Option<Mutator> {
+        let counts = self.counts();
+        let most = counts.iter().copied().max().filter(|most| *most > 0)?;
+        let index = counts.iter().position(|count| *count == most)?;
+        self.choices.get(index).copied()
+    }
+}
+
+#[derive(Resource)]
+struct Round {
+    timer: Timer,
+    /// The vote is open, rather than chat's last pick playing out.
+    voting: bool,
+    /// Chat's last pick, if it wasn't already on.
+    applied: Option<Mutator>,
+}
+
+#[derive(Component)]
+struct VotePanel;
+
+/// `--twitch CHANNEL`: a streamer mode where the channel's chat picks the mutators. Every
+/// three minutes of play, three mutators go on the ballot for thirty seconds and chat
+/// votes by typing 1, 2 or 3; the winner is switched on straight away, in place of the
+/// last one, until the next vote closes. Chat is read anonymously from Twitch's IRC
+/// server, on a thread of its own.
+pub struct TwitchPlugin {
+    pub channel: String,
+}
+
+impl Plugin for TwitchPlugin {
+    fn build(&self, app: &mut App) {
+        let (sender, receiver) = mpsc::channel();
+        let channel = self.channel.trim_start_matches('#').to_lowercase();
+        let spawned = std::thread::Builder::new()
+            .name("twitch-chat".into())
+            .spawn(move || read_chat(&channel, &sender));
+        if let Err(err) = spawned {
+            error!("couldn't start reading Twitch chat: {err}");
+            return;
+        }
+        app.insert_resource(ChatVotes(Mutex::new(receiver)));
+        app.init_resource::<VoteTally>();
+        app.insert_resource(Round {
+            timer: Timer::from_seconds(ROUND_SECONDS - VOTE_SECONDS, TimerMode::Once),
+            voting: false,
+            applied: None,
+        });
+        app.add_systems(Startup, spawn_panel);
+        app.add_systems(
+            Update,
+            (
+                count_votes,
+                run_rounds.run_if(in_state(GameState::Playing)),
+                show_panel,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Runs on the chat thread until the game closes, reconnecting whenever the connection
+/// drops.
+fn read_chat(channel: &str, votes: &Sender<Vote>) {
+    loop {
+        match listen(channel, votes) {
+            Ok(()) => return,
+            Err(err) => warn!("lost Twitch chat, reconnecting: {err}"),
+        }
+        std::thread::sleep(RECONNECT_DELAY);
+    }
+}
+
+/// Gives `Ok` only once the game has stopped listening.
+fn listen(channel: &str, votes: &Sender<Vote>) -> io::Result<()> {
+    let stream = TcpStream::connect(IRC_ADDRESS)?;
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut writer = stream.try_clone()?;
+    write!(writer, "NICK {IRC_NICK}\r\nJOIN #{channel}\r\n")?;
+    info!("reading Twitch chat in #{channel}");
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if let Some(server) = line.strip_prefix("PING ") {
+            write!(writer, "PONG {server}\r\n")?;
+            continue;
+        }
+        let Some(vote) = parse_vote(&line) else {
+            continue;
+        };
+        if votes.send(vote).is_err() {
+            return Ok(());
+        }
+    }
+    Err(io::ErrorKind::UnexpectedEof.into())
+}
+
+/// A chat line such as `:name!name@name.tmi.twitch.tv PRIVMSG #channel :2` is a vote for
+/// option 2; so is `!vote 2`.
+fn parse_vote(line: &str) -> Option<Vote> {
+    let (prefix, rest) = line.strip_prefix(':')?.split_once(' ')?;
+    let (_, text) = rest.strip_prefix("PRIVMSG ")?.split_once(" :")?;
+    let text = text.trim();
+    let choice = text
+        .strip_prefix("!vote")
+        .unwrap_or(text)
+        .trim()
+        .parse()
+        .ok()?;
+    let user = prefix.split('!').next()?;
+    (1..=CHOICES).contains(&choice).then(|| Vote {
+        user: user.to_string(),
+        choice,
+    })
+}
+
+/// Only votes for an option on the ballot count, and only while it is open; a viewer
+/// voting again changes their vote.
+fn count_votes(chat: Res<ChatVotes>, round: Res<Round>, mut tally: ResMut<VoteTally>) {
+    let Ok(receiver) = chat.0.lock() else {
+        return;
+    };
+    for vote in receiver.try_iter() {
+        if round.voting && vote.choice <= tally.choices.len() {
+            tally.votes.insert(vote.user, vote.choice - 1);
+        }
+    }
+}
+
+/// The ballot leaves out whatever is already on. The mutators put to the vote come from
+/// the seeded generator, so a seeded run plays out the same with streamer mode on.
+fn run_rounds(
+    time: Res<Time>,
+    locale: Res<Locale>,
+    mut rng: ResMut<GameRng>,
+    mut round: ResMut<Round>,
+    mut tally: ResMut<VoteTally>,
+    mut mutators: ResMut<ActiveMutators>,
+    mut toasts: EventWriter<Toast>,
+) {
+    if !round.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    if !round.voting {
+        let candidates: Vec<Mutator> = Mutator::ALL
+            .into_iter()
+            .filter(|mutator| !mutators.has(*mutator) || round.applied == Some(*mutator))
+            .collect();
+        *tally = VoteTally {
+            choices: candidates
+                .choose_multiple(&mut **rng, CHOICES)
+                .copied()
+                .collect(),
+            votes: HashMap::new(),
+        };
+        round.voting = true;
+        round.timer = Timer::from_seconds(VOTE_SECONDS, TimerMode::Once);
+        toasts.send(Toast(locale.text("twitch-vote-open")));
+        return;
+    }
+
+    if let Some(previous) = round.applied.take() {
+        mutators.0.retain(|mutator| *mutator != previous);
+    }
+    let message = match tally.winner() {
+        Some(winner) => {
+            if !mutators.has(winner) {
+                mutators.0.push(winner);
+                round.applied = Some(winner);
+            }
+            locale.format(
+                "twitch-picked",
+                &[("mutator", locale.text(winner.name()).into())],
+            )
+        }
+        None => locale.text("twitch-no-votes"),
+    };
+    toasts.send(Toast(message));
+    tally.choices.clear();
+    round.voting = false;
+    round.timer = Timer::from_seconds(ROUND_SECONDS - VOTE_SECONDS, TimerMode::Once);
+}
+
+fn spawn_panel(mut commands: Commands, fonts: Res<FontStack>) {
+    commands.spawn((
+        fonts
+            .text_bundle("", 18.)
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(12.0),
+                left: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                ..Default::default()
+            })
+            .with_background_color(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        VotePanel,
+    ));
+}
+
+fn show_panel(
+    round: Res<Round>,
+    tally: Res<VoteTally>,
+    locale: Res<Locale>,
+    fonts: Res<FontStack>,
+    mut shown: Local<String>,
+    mut panels: Query<&mut Text, With<VotePanel>>,
+) {
+    let seconds = round.timer.remaining_secs().ceil() as i32;
+    let mut lines = Vec::new();
+    if round.voting {
+        lines.push(locale.format("twitch-vote", &[("seconds", seconds.into())]));
+        for (index, (mutator, votes)) in tally.choices.iter().zip(tally.counts()).enumerate() {
+            lines.push(locale.format(
+                "twitch-option",
+                &[
+                    ("number", (index + 1).into()),
+                    ("mutator", locale.text(mutator.name()).into()),
+                    ("votes", votes.into()),
+                ],
+            ));
+        }
+    } else {
+        if let Some(applied) = round.applied {
+            lines.push(locale.format(
+                "twitch-playing",
+                &[("mutator", locale.text(applied.name()).into())],
+            ));
+        }
+        lines.push(locale.format("twitch-next", &[("seconds", seconds.into())]));
+    }
+    let value = lines.join("\n");
+    if *shown == value {
+        return;
+    }
+    for mut text in &mut panels {
+        fonts.set(&mut text, &value);
+    }
+    *shown = value;
+}
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    Ball,
+    arena::Arena,
+    layout::{ArenaLayout, GRID_CELL},
+    motion::MotionPreferences,
+};
+
+const STREAK_COLOUR: Color = Color::srgba(0.7, 0.9, 1.0, 0.35);
+const STREAK_LENGTH: f32 = 14.0;
+/// How fast streaks drift for every unit of push, so stronger winds look stronger.
+const STREAK_DRIFT: f32 = 0.25;
+
+type BallForce<'a> = (
+    Option<&'a ReadMassProperties>,
+    Option<&'a mut ExternalForce>,
+);
+
+/// A wind zone in the court. A sensor, so the ball passes through it.
+#[derive(Component)]
+struct WindZone {
+    bounds: Rect,
+    push: Vec2,
+}
+
+/// Wind zones from the arena file. While a ball overlaps any, their pushes add up into an
+/// external force on it.
+pub struct WindPlugin;
+
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                build_zones
+                    .run_if(resource_changed::<ArenaLayout>.or_else(resource_changed::<Arena>)),
+                blow,
+                draw_streaks,
+            ),
+        );
+    }
+}
+
+fn build_zones(
+    mut commands: Commands,
+    layout: Res<ArenaLayout>,
+    zones: Query<Entity, With<WindZone>>,
+) {
+    for zone in &zones {
+        commands.entity(zone).despawn();
+    }
+    for wind in &layout.winds {
+        let bounds = wind.bounds();
+        commands.spawn((
+            SpatialBundle::from_transform(Transform::from_translation(bounds.center().extend(0.0))),
+            RigidBody::Fixed,
+            Collider::cuboid(bounds.half_size().x, bounds.half_size().y),
+            Sensor,
+            WindZone {
+                bounds,
+                push: wind.push,
+            },
+        ));
+    }
+}
+
+/// Sets each ball's force from the zones it is in. Balls get their force and mass
+/// components the first time they meet wind.
+fn blow(
+    mut commands: Commands,
+    zones: Query<&WindZone>,
+    mut balls: Query<(Entity, &CollidingEntities, BallForce), With<Ball>>,
+) {
+    for (ball, hits, (mass, force)) in &mut balls {
+        let push: Vec2 = hits
+            .iter()
+            .filter_map(|hit| zones.get(hit).ok())
+            .map(|zone| zone.push)
+            .sum();
+        match (mass, force) {
+            (Some(mass), Some(mut force)) => {
+                let wanted = push * mass.get().mass;
+                if force.force != wanted {
+                    force.force = wanted;
+                }
+            }
+            _ if push != Vec2::ZERO => {
+                commands
+                    .entity(ball)
+                    .insert((ReadMassProperties::default(), ExternalForce::default()));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Short lines drifting downwind across each zone. With reduced motion they stay still.
+fn draw_streaks(
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    motion: Res<MotionPreferences>,
+    zones: Query<&WindZone>,
+) {
+    let elapsed = if motion.reduced {
+        0.0
+    } else {
+        time.elapsed_seconds()
+    };
+    for zone in &zones {
+        let Some(direction) = zone.push.try_normalize() else {
+            continue;
+        };
+        let size = zone.bounds.size();
+        let drift = direction * zone.push.length() * STREAK_DRIFT * elapsed;
+        let columns = (size.x / GRID_CELL).round().max(1.0) as i32;
+        let rows = (size.y / GRID_CELL).round().max(1.0) as i32;
+        for column in 0..columns {
+            for row in 0..rows {
+                // Stagger the streaks so they don't march in a grid.
+                let start = Vec2::new(
+                    (column as f32 + 0.3 + 0.4 * (row % 2) as f32) * GRID_CELL,
+                    (row as f32 + 0.5) * GRID_CELL,
+                );
+                let head = zone.bounds.min + (start + drift).rem_euclid(size);
+                let tail =
+                    (head - direction * STREAK_LENGTH).clamp(zone.bounds.min, zone.bounds.max);
+                gizmos.line_2d(tail, head, STREAK_COLOUR);
+            }
+        }
+    }
+}
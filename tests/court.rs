@@ -0,0 +1,124 @@
+use bevy::{prelude::*, scene::ScenePlugin, time::TimeUpdateStrategy, utils::Duration};
+use bevy_rapier2d::prelude::*;
+use pong::court;
+
+const FRAME_SECONDS: f32 = 1.0 / 60.0;
+const HALF_WIDTH: f32 = 640.0;
+const HALF_HEIGHT: f32 = 360.0;
+/// Faster than any serve or speed-up in the game gets.
+const FAST: f32 = 3000.0;
+
+/// Just physics and the court's walls and goal lines, stepped 1/60 s at a time.
+fn court_app(sensor_goals: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        HierarchyPlugin,
+        AssetPlugin::default(),
+        ScenePlugin,
+    ));
+    app.init_asset::<Mesh>();
+    app.add_plugins(RapierPhysicsPlugin::<NoUserData>::default());
+    app.insert_resource(RapierConfiguration {
+        gravity: Vec2::ZERO,
+        ..RapierConfiguration::new(1.0)
+    });
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        FRAME_SECONDS,
+    )));
+    app.insert_resource(TimestepMode::Fixed {
+        dt: FRAME_SECONDS,
+        substeps: 1,
+    });
+    app.finish();
+    app.cleanup();
+
+    let world = app.world_mut();
+    for side in [1.0, -1.0] {
+        let (transform, collider) = court::wall(HALF_WIDTH, HALF_HEIGHT, side);
+        world.spawn((
+            TransformBundle::from_transform(transform),
+            RigidBody::Fixed,
+            collider,
+        ));
+        let (transform, collider) = court::goal_line(HALF_WIDTH, HALF_HEIGHT, side);
+        let mut goal = world.spawn((
+            TransformBundle::from_transform(transform),
+            RigidBody::Fixed,
+            collider,
+        ));
+        if sensor_goals {
+            goal.insert(Sensor);
+        }
+    }
+    app
+}
+
+fn spawn_ball(app: &mut App, position: Vec2, velocity: Vec2) -> Entity {
+    app.world_mut()
+        .spawn((
+            TransformBundle::from_transform(Transform::from_translation(position.extend(0.0))),
+            court::ball_body(),
+            Velocity::linear(velocity),
+            Restitution {
+                coefficient: 1.0,
+                combine_rule: CoefficientCombineRule::Max,
+            },
+            Friction::coefficient(0.0),
+            ActiveEvents::COLLISION_EVENTS,
+        ))
+        .id()
+}
+
+fn position(app: &App, ball: Entity) -> Vec2 {
+    app.world()
+        .get::<Transform>(ball)
+        .expect("ball is gone")
+        .translation
+        .truncate()
+}
+
+#[test]
+fn fast_ball_stays_in_a_closed_court() {
+    for angle in [0.1, 0.4, 0.8, 1.2, 1.5] {
+        let mut app = court_app(false);
+        let ball = spawn_ball(&mut app, Vec2::ZERO, Vec2::from_angle(angle) * FAST);
+        for frame in 0..600 {
+            app.update();
+            let at = position(&app, ball);
+            assert!(
+                at.x.abs() < HALF_WIDTH && at.y.abs() < HALF_HEIGHT,
+                "ball at angle {angle} escaped to {at} on frame {frame}"
+            );
+        }
+    }
+}
+
+#[test]
+fn fast_ball_never_skips_a_goal_line() {
+    // Every starting offset within one frame's travel, so the ball meets the goal line
+    // at every point of a physics step.
+    let step = FAST * FRAME_SECONDS;
+    for offset in 0..10 {
+        let mut app = court_app(true);
+        let start = Vec2::new(-(offset as f32) * step / 10.0, 0.0);
+        let ball = spawn_ball(&mut app, start, Vec2::new(FAST, 0.0));
+        let mut scored = false;
+        for _ in 0..60 {
+            app.update();
+            let events = app.world().resource::<Events<CollisionEvent>>();
+            scored |= events
+                .iter_current_update_events()
+                .any(|event| matches!(event, CollisionEvent::Started(..)));
+            if scored || position(&app, ball).x > HALF_WIDTH + 2.0 * court::BOUNDARY_HALF_THICKNESS
+            {
+                break;
+            }
+        }
+        assert!(
+            scored,
+            "ball starting at {start} went through the goal line unseen"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use pong::{
+    Player,
+    testing::{Direction, TestGame},
+};
+
+/// Fast enough to reach a goal line within a few frames from anywhere on the court.
+const SHOT_SPEED: f32 = 1500.0;
+/// Above where the paddles start, so a shot down the wing goes past them.
+const WING: f32 = 300.0;
+
+/// Steps until `done` or `frames` run out, whichever comes first, and says which.
+fn step_until(game: &mut TestGame, frames: u32, done: impl Fn(&TestGame) -> bool) -> bool {
+    for _ in 0..frames {
+        if done(game) {
+            return true;
+        }
+        game.step(1);
+    }
+    done(game)
+}
+
+/// Puts the ball past the paddles and sends it into the goal `scorer` scores on.
+fn shoot(game: &mut TestGame, scorer: Player) {
+    let towards = match scorer {
+        Player::Player1 => 1.0,
+        Player::Player2 => -1.0,
+    };
+    game.throw_ball(
+        Vec2::new(towards * 400.0, WING),
+        Vec2::new(towards * SHOT_SPEED, 0.0),
+    );
+    let before = game.score();
+    assert!(
+        step_until(game, 60, |game| game.score() != before),
+        "shot for {scorer:?} did not score"
+    );
+}
+
+#[test]
+fn held_key_moves_the_paddle_until_the_wall() {
+    let mut game = TestGame::default();
+    game.press(Player::Player1, Direction::Up);
+    game.step(30);
+    let moving = game.paddle(Player::Player1).y;
+    assert!(moving > 0.0, "paddle did not move up");
+
+    game.step(600);
+    let stopped = game.paddle(Player::Player1).y;
+    assert!(stopped > moving);
+    game.step(60);
+    let after = game.paddle(Player::Player1).y;
+    assert!(
+        (after - stopped).abs() < 0.01,
+        "paddle went through the wall"
+    );
+
+    game.release(Player::Player1, Direction::Up);
+    game.press(Player::Player1, Direction::Down);
+    game.step(30);
+    assert!(game.paddle(Player::Player1).y < stopped);
+}
+
+#[test]
+fn opening_serve_scores_past_an_empty_goal() {
+    let mut game = TestGame::default();
+    game.press(Player::Player2, Direction::Up);
+    assert!(step_until(&mut game, 1200, |game| game.score() != (0, 0)));
+    assert_eq!(game.score(), (1, 0));
+}
+
+#[test]
+fn each_goal_scores_for_the_player_attacking_it() {
+    let mut game = TestGame::default();
+    shoot(&mut game, Player::Player1);
+    assert_eq!(game.score(), (1, 0));
+    shoot(&mut game, Player::Player2);
+    assert_eq!(game.score(), (1, 1));
+    shoot(&mut game, Player::Player2);
+    assert_eq!(game.score(), (1, 2));
+}
+
+#[test]
+fn scorer_serves_after_a_goal() {
+    let mut game = TestGame::default();
+    shoot(&mut game, Player::Player2);
+    game.step(1);
+    assert_eq!(game.server(), Some(Player::Player2));
+    assert_eq!(game.ball_velocity(), Vec2::ZERO);
+    // In front of Player 2's paddle, on the right.
+    assert!(game.ball().x > 0.0 && game.ball().x < game.paddle(Player::Player2).x);
+
+    assert!(step_until(&mut game, 600, |game| game.server().is_none()));
+    assert!(game.ball_velocity().x < 0.0, "serve went the wrong way");
+    assert_eq!(game.score(), (0, 1), "the serve scored");
+}
+
+#[test]
+fn space_serves_again_from_player_one() {
+    let mut game = TestGame::default();
+    game.step(30);
+    game.tap_reset();
+    game.step(1);
+    assert_eq!(game.server(), Some(Player::Player1));
+    assert_eq!(game.score(), (0, 0));
+}
+
+#[test]
+fn match_is_won_at_the_target_score_and_starts_again() {
+    let mut game = TestGame::builder().target_score(3).build();
+    shoot(&mut game, Player::Player1);
+    shoot(&mut game, Player::Player2);
+    shoot(&mut game, Player::Player1);
+    game.step(1);
+    assert!(
+        game.winners().is_empty(),
+        "match ended at {:?}",
+        game.score()
+    );
+
+    shoot(&mut game, Player::Player1);
+    game.step(2);
+    assert_eq!(game.winners(), [Player::Player1]);
+    assert_eq!(game.score(), (0, 0));
+
+    for _ in 0..3 {
+        shoot(&mut game, Player::Player2);
+    }
+    game.step(2);
+    assert_eq!(game.winners(), [Player::Player1, Player::Player2]);
+}
@@ -0,0 +1,108 @@
+use pong::{
+    Player,
+    court::BOUNDARY_HALF_THICKNESS,
+    testing::{Direction, FrameInput, TestGame, simulate, simulate_with},
+};
+use proptest::prelude::*;
+
+/// 20 seconds of play, long enough for a few goals.
+const FRAMES: usize = 1200;
+
+/// Each case plays a whole match segment, so fewer than proptest's usual 256 by default.
+/// `PROPTEST_CASES=5000 cargo test --release --test simulation` for a thorough run.
+fn config() -> ProptestConfig {
+    let cases = std::env::var("PROPTEST_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(32);
+    ProptestConfig::with_cases(cases)
+}
+
+fn held() -> impl Strategy<Value = Option<Direction>> {
+    prop_oneof![
+        Just(None),
+        Just(Some(Direction::Up)),
+        Just(Some(Direction::Down)),
+    ]
+}
+
+/// Both players changing what they hold every so often, as people do.
+fn inputs() -> impl Strategy<Value = Vec<FrameInput>> {
+    prop::collection::vec((held(), held(), 1..90usize), 1..60).prop_map(|spells| {
+        spells
+            .into_iter()
+            .flat_map(|(player1, player2, frames)| {
+                std::iter::repeat_n(FrameInput { player1, player2 }, frames)
+            })
+            .chain(std::iter::repeat(FrameInput::default()))
+            .take(FRAMES)
+            .collect()
+    })
+}
+
+fn points(score: (i32, i32), player: Player) -> i32 {
+    match player {
+        Player::Player1 => score.0,
+        Player::Player2 => score.1,
+    }
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn same_seed_and_inputs_give_the_same_match(seed: u64, inputs in inputs()) {
+        prop_assert_eq!(simulate(seed, &inputs), simulate(seed, &inputs));
+    }
+
+    #[test]
+    fn ball_never_leaves_the_court(seed: u64, inputs in inputs()) {
+        let mut escaped = None;
+        simulate_with(seed, &inputs, |game: &TestGame| {
+            let (ball, court) = (game.ball(), game.court_half_size());
+            // A scoring ball is past its goal line until the next frame serves it again,
+            // but it never gets through the far side.
+            let inside = ball.is_finite()
+                && ball.y.abs() <= court.y
+                && ball.x.abs() < court.x + 2.0 * BOUNDARY_HALF_THICKNESS;
+            if !inside && escaped.is_none() {
+                escaped = Some(ball);
+            }
+        });
+        prop_assert!(escaped.is_none(), "ball escaped to {:?}", escaped);
+    }
+
+    #[test]
+    fn score_only_changes_through_the_goal_lines(seed: u64, inputs in inputs()) {
+        let mut last = (0, 0);
+        let mut matches_won = 0;
+        let mut just_won = false;
+        // Goal lines touched since the last point.
+        let mut touched: Vec<Player> = Vec::new();
+        let mut broken = None;
+        simulate_with(seed, &inputs, |game: &TestGame| {
+            let score = game.score();
+            touched.extend(game.goals_touched());
+            // A won match starts again from nothing on the next frame.
+            let restarted = score == (0, 0) && just_won;
+            just_won = game.winners().len() > matches_won;
+            matches_won = game.winners().len();
+            if score == last || restarted {
+                last = score;
+                return;
+            }
+            for player in [Player::Player1, Player::Player2] {
+                let gained = points(score, player) - points(last, player);
+                let allowed = if touched.contains(&player) { 0..=1 } else { 0..=0 };
+                if !allowed.contains(&gained) && broken.is_none() {
+                    broken = Some(format!(
+                        "score went from {last:?} to {score:?} with goal lines touched for {touched:?}"
+                    ));
+                }
+            }
+            touched.clear();
+            last = score;
+        });
+        prop_assert!(broken.is_none(), "{}", broken.unwrap_or_default());
+    }
+}